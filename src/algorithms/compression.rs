@@ -0,0 +1,167 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashMap;
+
+use crate::data_structures::binary_heap::BinaryHeap;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// A node of the Huffman tree, children are indices into the same arena
+enum Tree {
+    Leaf(char),
+    Branch(usize, usize),
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns an optimal prefix code for symbols with the given frequencies
+/// (Huffman coding)
+///
+/// No code is a prefix of another, so encoded text can be read back without
+/// separators, and more frequent symbols get shorter (or equal) codes, which
+/// makes the total encoded length as small as possible.
+///
+/// Inputs:
+/// - `freqs: &HashMap<char, u64>` How often each symbol shows up
+///
+/// Output:
+/// - `HashMap<char, String>` The code for each symbol, as a string of `0`s
+///   and `1`s. A lone symbol gets `"0"`, since an empty code couldn't be
+///   told apart from nothing at all.
+///
+/// Side-effects: N/A
+///
+/// Time complexity: O(n log(n)), n = the number of symbols
+///
+pub fn huffman(freqs: &HashMap<char, u64>) -> HashMap<char, String> {
+    /*
+    Start with every symbol as its own tree, weighted by its frequency.
+    Take the two lightest trees out and join them under a new branch, which
+    weighs as much as both of them together. Once there's only one tree
+    left, each symbol's code is the path from the root down to it (0 for
+    left, 1 for right).
+
+    The rarest symbols get merged first, so they end up deepest in the tree
+    with the longest codes.
+    */
+
+    // sorted so ties between equal frequencies always break the same way
+    let mut symbols: Vec<(char, u64)> = freqs.iter().map(|(c, f)| (*c, *f)).collect();
+    symbols.sort();
+
+    let mut arena: Vec<Tree> = vec![];
+
+    // (weight, index into the arena)
+    let mut heap = BinaryHeap::new();
+
+    for (symbol, freq) in symbols {
+        heap.insert((freq, arena.len()));
+        arena.push(Tree::Leaf(symbol));
+    }
+
+    if heap.is_empty() {
+        return HashMap::new();
+    }
+
+    while heap.len() > 1 {
+        let (left_weight, left) = heap.extract_min();
+        let (right_weight, right) = heap.extract_min();
+
+        heap.insert((left_weight + right_weight, arena.len()));
+        arena.push(Tree::Branch(left, right));
+    }
+
+    let (_, root) = heap.extract_min();
+
+    let mut codes = HashMap::new();
+
+    // (node, the path to it)
+    let mut stack = vec![(root, String::new())];
+
+    while let Some((node, path)) = stack.pop() {
+        match arena[node] {
+            Tree::Leaf(symbol) => {
+                let code = if path.is_empty() {
+                    "0".to_string()
+                } else {
+                    path
+                };
+                codes.insert(symbol, code);
+            }
+            Tree::Branch(left, right) => {
+                stack.push((left, path.clone() + "0"));
+                stack.push((right, path + "1"));
+            }
+        }
+    }
+
+    codes
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn is_prefix_free(codes: &HashMap<char, String>) -> bool {
+        codes.iter().all(|(a, code_a)| {
+            codes
+                .iter()
+                .all(|(b, code_b)| a == b || !code_b.starts_with(code_a.as_str()))
+        })
+    }
+
+    #[test]
+    fn optimal_code() {
+        // the example from CLRS
+        let freqs = HashMap::from([
+            ('a', 45),
+            ('b', 13),
+            ('c', 12),
+            ('d', 16),
+            ('e', 9),
+            ('f', 5),
+        ]);
+
+        let codes = huffman(&freqs);
+
+        assert_eq!(codes.len(), 6);
+        assert!(is_prefix_free(&codes));
+        assert!(codes
+            .values()
+            .all(|code| !code.is_empty() && code.chars().all(|c| c == '0' || c == '1')));
+
+        // more frequent never means a longer code
+        for (a, freq_a) in &freqs {
+            for (b, freq_b) in &freqs {
+                if freq_a > freq_b {
+                    assert!(codes[a].len() <= codes[b].len(), "{} vs {}", a, b);
+                }
+            }
+        }
+
+        let cost: u64 = freqs
+            .iter()
+            .map(|(symbol, freq)| freq * codes[symbol].len() as u64)
+            .sum();
+        assert_eq!(cost, 224);
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(huffman(&HashMap::new()), HashMap::new());
+        assert_eq!(
+            huffman(&HashMap::from([('x', 7)])),
+            HashMap::from([('x', "0".to_string())])
+        );
+
+        let codes = huffman(&HashMap::from([('x', 1), ('y', 1)]));
+        let mut values: Vec<&String> = codes.values().collect();
+        values.sort();
+        assert_eq!(values, vec!["0", "1"]);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
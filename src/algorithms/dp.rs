@@ -0,0 +1,483 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+Dynamic programming
+
+Every problem here can be split into smaller versions of themselves (the same
+question about shorter prefixes), and those smaller answers overlap a lot.
+Rather than recomputing them over and over recursively, we fill in a table of
+every prefix pair's answer, smallest first, so each one is only worked out
+once.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use crate::algorithms::search::binary_search::binary_search_upper_bound;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns a longest common subsequence of `a` and `b`
+///
+/// A subsequence keeps the order of the elements but doesn't need them to be
+/// next to each other, e.g. "BCAB" is a subsequence of "ABCBDAB".
+///
+/// - Inputs:
+///     - `a: &[T]`
+///     - `b: &[T]`
+/// - Output: `Vec<T>`
+///     - A longest sequence that's a subsequence of both (there may be
+///       several of the same length, this returns one of them)
+/// - Side-effects: N/A
+/// - Time complexity: O(nm)
+///     - `n = a.len()`, `m = b.len()`
+/// - Space complexity: O(nm)
+pub fn lcs<T: Eq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    /*
+    table[i][j] is the length of the LCS of a[..i] and b[..j]
+
+    - If either prefix is empty, there's nothing in common (0)
+    - If the last elements match, they can end the subsequence, so it's one
+      longer than the LCS without them
+    - Otherwise at least one of the last elements isn't used, so take the
+      better of dropping either
+    */
+    let (n, m) = (a.len(), b.len());
+    let mut table = vec![vec![0; m + 1]; n + 1];
+
+    for i in 1..=n {
+        for j in 1..=m {
+            table[i][j] = if a[i - 1] == b[j - 1] {
+                table[i - 1][j - 1] + 1
+            } else {
+                table[i - 1][j].max(table[i][j - 1])
+            };
+        }
+    }
+
+    // walk back from the full answer, retracing the choices made above
+    let mut res = Vec::with_capacity(table[n][m]);
+    let (mut i, mut j) = (n, m);
+
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            res.push(a[i - 1].clone());
+            i -= 1;
+            j -= 1;
+        } else if table[i - 1][j] >= table[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    res.reverse();
+    res
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the Levenshtein distance between `a` and `b`
+///
+/// That's the fewest single character insertions, deletions and
+/// substitutions needed to turn `a` into `b`.
+///
+/// - Inputs:
+///     - `a: &str`
+///     - `b: &str`
+/// - Output: `usize`
+///     - The edit distance, compared by `char`
+/// - Side-effects: N/A
+/// - Time complexity: O(nm)
+///     - `n`, `m` = the number of chars in `a` and `b`
+/// - Space complexity: O(nm)
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    /*
+    table[i][j] is the distance between a[..i] and b[..j]
+
+    - Turning a prefix into an empty string (or back) takes one edit per
+      character
+    - If the last characters match, they cost nothing
+    - Otherwise the last step was one of:
+        - deleting the last character of a  -> table[i - 1][j] + 1
+        - inserting the last character of b -> table[i][j - 1] + 1
+        - substituting one for the other    -> table[i - 1][j - 1] + 1
+    */
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (n, m) = (a.len(), b.len());
+
+    let mut table = vec![vec![0; m + 1]; n + 1];
+
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=n {
+        for j in 1..=m {
+            table[i][j] = if a[i - 1] == b[j - 1] {
+                table[i - 1][j - 1]
+            } else {
+                1 + table[i - 1][j]
+                    .min(table[i][j - 1])
+                    .min(table[i - 1][j - 1])
+            };
+        }
+    }
+
+    table[n][m]
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Picks the non-overlapping intervals with the most total weight
+///
+/// Intervals are half-open, so one ending at 5 and another starting at 5
+/// don't overlap.
+///
+/// - Inputs:
+///     - `intervals: &[(u64, u64, i64)]`
+///       `(start, end, weight)` for each interval, with `start <= end`
+/// - Output: `(i64, Vec<usize>)`
+///     - The best total weight, and the indices (into `intervals`, sorted) of
+///       the intervals that make it up. Intervals with negative weight are
+///       never picked, so with none to pick the total is 0.
+/// - Side-effects: N/A
+/// - Time complexity: O(n log(n))
+/// - Space complexity: O(n)
+pub fn weighted_interval_schedule(intervals: &[(u64, u64, i64)]) -> (i64, Vec<usize>) {
+    /*
+    Sort the intervals by when they end.
+
+    best[i] is the most weight we can get out of the first i intervals.
+    The i-th interval (0-indexed i - 1) is either:
+        - left out                      -> best[i - 1]
+        - picked, which rules out everything that ends after it starts,
+          leaving the first p intervals -> best[p] + weight
+
+    where p is the number of intervals ending at or before it starts.
+    Ends are sorted, so p can be binary searched.
+    */
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    order.sort_by_key(|&i| intervals[i].1);
+
+    let ends: Vec<u64> = order.iter().map(|&i| intervals[i].1).collect();
+
+    // a zero length interval counts as ending before it starts, so it has to
+    // be kept from picking itself
+    let compatible: Vec<usize> = order
+        .iter()
+        .enumerate()
+        .map(|(i, &index)| binary_search_upper_bound(&ends, &intervals[index].0).min(i))
+        .collect();
+
+    let mut best = vec![0; order.len() + 1];
+
+    for (i, &index) in order.iter().enumerate() {
+        best[i + 1] = best[i].max(best[compatible[i]] + intervals[index].2);
+    }
+
+    // walk back through the table to see which choice was made at each step
+    let mut chosen = vec![];
+    let mut i = order.len();
+
+    while i > 0 {
+        if best[i] == best[i - 1] {
+            i -= 1;
+        } else {
+            chosen.push(order[i - 1]);
+            i = compatible[i - 1];
+        }
+    }
+
+    chosen.sort();
+
+    (best[order.len()], chosen)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the cheapest order to multiply a chain of matrices in
+///
+/// Matrix multiplication is associative, so any order gives the same result,
+/// but the number of scalar multiplications can differ a lot.
+///
+/// - Inputs:
+///     - `dims: &[usize]`
+///       Matrix `Ai` (counting from 1) is `dims[i - 1]` by `dims[i]`, so
+///       there's one less matrix than there are dimensions
+/// - Output: `(usize, String)`
+///     - The fewest scalar multiplications, and where to put the brackets,
+///       e.g. `((A1(A2A3))A4)`. A single matrix is just `A1`, and with no
+///       matrices at all the string is empty.
+/// - Side-effects: N/A
+/// - Time complexity: O(n^3)
+///     - `n = dims.len() - 1`, the number of matrices
+/// - Space complexity: O(n^2)
+pub fn matrix_chain_order(dims: &[usize]) -> (usize, String) {
+    /*
+    cost[i][j] is the cheapest way to multiply Ai through Aj.
+
+    Whatever the order, the last multiplication joins (Ai..Ak) with
+    (Ak+1..Aj) for some k. That costs what it took to build each side, plus
+    dims[i - 1] * dims[k] * dims[j] for the final multiplication, so try
+    every k and keep the cheapest (remembering it in split[i][j]).
+
+    Fill in shorter chains first, since longer ones are built out of them.
+    */
+    let n = dims.len().saturating_sub(1);
+
+    if n == 0 {
+        return (0, String::new());
+    }
+
+    let mut cost = vec![vec![0; n + 1]; n + 1];
+    let mut split = vec![vec![0; n + 1]; n + 1];
+
+    for len in 2..=n {
+        for i in 1..=n - len + 1 {
+            let j = i + len - 1;
+
+            let (best, k) = (i..j)
+                .map(|k| {
+                    (
+                        cost[i][k] + cost[k + 1][j] + dims[i - 1] * dims[k] * dims[j],
+                        k,
+                    )
+                })
+                .min()
+                .expect("chains of 2 or more can be split");
+
+            cost[i][j] = best;
+            split[i][j] = k;
+        }
+    }
+
+    fn brackets(split: &[Vec<usize>], i: usize, j: usize) -> String {
+        if i == j {
+            format!("A{}", i)
+        } else {
+            let k = split[i][j];
+            format!("({}{})", brackets(split, i, k), brackets(split, k + 1, j))
+        }
+    }
+
+    (cost[1][n], brackets(&split, 1, n))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the most valuable set of items that fits in the knapsack
+/// (0/1 knapsack, each item is either taken whole or left behind)
+///
+/// - Inputs:
+///     - `weights: &[u64]`
+///     - `values: &[i64]`
+///       Item `i` weighs `weights[i]` and is worth `values[i]`, panics if
+///       the lengths differ
+///     - `capacity: u64`
+///       The most weight the knapsack can hold
+/// - Output: `(i64, Vec<usize>)`
+///     - The best total value, and the indices (sorted) of the items that
+///       make it up. Items worth nothing or less are never taken.
+/// - Side-effects: N/A
+/// - Time complexity: O(n * capacity)
+///     - `n = weights.len()`
+/// - Space complexity: O(n * capacity)
+pub fn knapsack_01(weights: &[u64], values: &[i64], capacity: u64) -> (i64, Vec<usize>) {
+    /*
+    table[i][c] is the most value we can fit in capacity c using only the
+    first i items.
+
+    Item i (0-indexed i - 1) is either:
+        - left out                      -> table[i - 1][c]
+        - taken, if it fits, leaving less
+          room for the others           -> table[i - 1][c - weight] + value
+
+    The capacity is a table dimension, so this is only quick for smallish
+    capacities (it's pseudo-polynomial).
+    */
+    assert_eq!(
+        weights.len(),
+        values.len(),
+        "every item needs both a weight and a value"
+    );
+
+    let n = weights.len();
+    let capacity = capacity as usize;
+
+    let mut table = vec![vec![0i64; capacity + 1]; n + 1];
+
+    for i in 1..=n {
+        let (weight, value) = (weights[i - 1] as usize, values[i - 1]);
+
+        for c in 0..=capacity {
+            table[i][c] = table[i - 1][c];
+
+            if weight <= c {
+                table[i][c] = table[i][c].max(table[i - 1][c - weight] + value);
+            }
+        }
+    }
+
+    // walk back up the table, an item was taken wherever the value changed
+    let mut chosen = vec![];
+    let mut c = capacity;
+
+    for i in (1..=n).rev() {
+        if table[i][c] != table[i - 1][c] {
+            chosen.push(i - 1);
+            c -= weights[i - 1] as usize;
+        }
+    }
+
+    chosen.reverse();
+
+    (table[n][capacity], chosen)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn is_subsequence(sub: &[u8], of: &[u8]) -> bool {
+        let mut of = of.iter();
+        sub.iter().all(|item| of.any(|other| other == item))
+    }
+
+    #[test]
+    fn test_lcs() {
+        let (a, b) = (b"ABCBDAB", b"BDCAB");
+        let res = lcs(a, b);
+
+        assert_eq!(res.len(), 4);
+        assert!(is_subsequence(&res, a));
+        assert!(is_subsequence(&res, b));
+
+        assert_eq!(lcs(b"", b"ABC"), Vec::<u8>::new());
+        assert_eq!(lcs(b"ABC", b"DEF"), Vec::<u8>::new());
+        assert_eq!(lcs(b"ABC", b"ABC"), b"ABC".to_vec());
+        assert_eq!(lcs(&[1, 2, 3, 4, 5], &[5, 1, 3, 5]), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("flaw", "lawn"), 2);
+        assert_eq!(edit_distance("same", "same"), 0);
+        assert_eq!(edit_distance("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn test_weighted_interval_schedule() {
+        let intervals = [
+            (1, 4, 5),
+            (3, 5, 1),
+            (0, 6, 8),
+            (4, 7, 4),
+            (3, 9, 6),
+            (5, 9, 3),
+            (6, 10, 2),
+            (8, 11, 4),
+        ];
+
+        // (1, 4) then (4, 7) then (8, 11), touching ends don't overlap
+        assert_eq!(weighted_interval_schedule(&intervals), (13, vec![0, 3, 7]));
+
+        // one heavy interval beats the two it overlaps
+        assert_eq!(
+            weighted_interval_schedule(&[(0, 2, 3), (1, 5, 10), (4, 6, 3)]),
+            (10, vec![1])
+        );
+
+        assert_eq!(weighted_interval_schedule(&[]), (0, vec![]));
+        assert_eq!(weighted_interval_schedule(&[(0, 1, -5)]), (0, vec![]));
+        assert_eq!(
+            weighted_interval_schedule(&[(2, 2, 1), (0, 3, 1), (2, 2, 1)]),
+            (2, vec![0, 2])
+        );
+    }
+
+    #[test]
+    fn test_matrix_chain_order() {
+        assert_eq!(
+            matrix_chain_order(&[40, 20, 30, 10, 30]),
+            (26000, "((A1(A2A3))A4)".to_string())
+        );
+
+        // CLRS, same cost however ties are broken
+        let (cost, order) = matrix_chain_order(&[30, 35, 15, 5, 10, 20, 25]);
+        assert_eq!(cost, 15125);
+        assert_eq!(order, "((A1(A2A3))((A4A5)A6))");
+
+        assert_eq!(matrix_chain_order(&[10, 20]), (0, "A1".to_string()));
+        assert_eq!(
+            matrix_chain_order(&[10, 20, 30]),
+            (6000, "(A1A2)".to_string())
+        );
+        assert_eq!(matrix_chain_order(&[10]), (0, String::new()));
+        assert_eq!(matrix_chain_order(&[]), (0, String::new()));
+    }
+
+    #[test]
+    fn test_knapsack_01() {
+        let (weights, values) = ([1, 3, 4, 5], [1, 4, 5, 7]);
+
+        assert_eq!(knapsack_01(&weights, &values, 7), (9, vec![1, 2]));
+        assert_eq!(knapsack_01(&weights, &values, 12), (16, vec![1, 2, 3]));
+        assert_eq!(knapsack_01(&weights, &values, 0), (0, vec![]));
+        assert_eq!(knapsack_01(&[], &[], 10), (0, vec![]));
+
+        // free items are always worth taking, worthless ones never are
+        assert_eq!(knapsack_01(&[0, 2, 1], &[3, -1, 0], 5), (3, vec![0]));
+    }
+
+    #[test]
+    fn knapsack_01_fits() {
+        let mut rng = crate::meta::rng::XorShift::new(37);
+
+        for _ in 0..200 {
+            let n = rng.next_index(10);
+            let weights: Vec<u64> = (0..n).map(|_| rng.next_index(20) as u64).collect();
+            let values: Vec<i64> = (0..n).map(|_| rng.next_index(50) as i64 - 10).collect();
+            let capacity = rng.next_index(60) as u64;
+
+            let (value, chosen) = knapsack_01(&weights, &values, capacity);
+
+            assert!(chosen.iter().map(|i| weights[*i]).sum::<u64>() <= capacity);
+            assert_eq!(chosen.iter().map(|i| values[*i]).sum::<i64>(), value);
+
+            // every subset, to check nothing better was missed
+            let best = (0..1u32 << n)
+                .filter(|set| {
+                    (0..n)
+                        .filter(|i| set & (1 << i) != 0)
+                        .map(|i| weights[i])
+                        .sum::<u64>()
+                        <= capacity
+                })
+                .map(|set| {
+                    (0..n)
+                        .filter(|i| set & (1 << i) != 0)
+                        .map(|i| values[i])
+                        .sum::<i64>()
+                })
+                .max()
+                .unwrap();
+
+            assert_eq!(value, best);
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
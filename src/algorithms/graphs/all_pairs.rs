@@ -0,0 +1,227 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::HashMap, ops::Sub};
+
+use crate::{
+    algorithms::graphs::{
+        bellman_ford::{potentials, NegativeCycle},
+        dijkstras::dijkstras_all,
+    },
+    data_structures::graphs::{
+        weighted_graph::WeightedGraph, IDefiniteGraph, IGraphMut, IWeightedGraph,
+    },
+};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// The shortest distance between every pair of connected nodes
+pub type AllPairs<N, W> = HashMap<(N, N), W>;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the shortest distance between every pair of nodes, allowing
+/// negative edge weights (Floyd-Warshall)
+///
+/// Inputs:
+/// - `graph: &T` The graph to search through
+///
+/// Output:
+/// - If the graph has no negative cycles
+///     - `Ok(AllPairs)` Maps: (from, to) -> shortest distance, for every pair
+///       with a path between them (including each node to itself)
+/// - Else
+///     - `Err(NegativeCycle)`
+///
+/// Side-effects: N/A
+///
+/// Time complexity: O(V^3), see `johnsons` for sparse graphs
+///
+pub fn floyd_warshall<T: IWeightedGraph + IDefiniteGraph>(
+    graph: &T,
+) -> Result<AllPairs<T::Node, T::Weight>, NegativeCycle> {
+    let mut nodes = graph.get_all();
+    nodes.sort();
+
+    let mut dist: AllPairs<T::Node, T::Weight> = HashMap::new();
+
+    for node in &nodes {
+        dist.insert((node.clone(), node.clone()), T::Weight::from(0));
+
+        for (adj, weight) in graph.get_adj_weighted(node) {
+            let key = (node.clone(), adj);
+
+            if dist.get(&key).is_none_or(|old| weight < *old) {
+                dist.insert(key, weight);
+            }
+        }
+    }
+
+    // only allow paths through the first k nodes, one more each round
+    for k in &nodes {
+        for i in &nodes {
+            let Some(to_k) = dist.get(&(i.clone(), k.clone())).cloned() else {
+                continue;
+            };
+
+            for j in &nodes {
+                let Some(from_k) = dist.get(&(k.clone(), j.clone())) else {
+                    continue;
+                };
+
+                let through = to_k.clone() + from_k.clone();
+                let key = (i.clone(), j.clone());
+
+                if dist.get(&key).is_none_or(|old| through < *old) {
+                    dist.insert(key, through);
+                }
+            }
+        }
+    }
+
+    // a node with a shorter path back to itself than staying put is on a
+    // negative cycle
+    let zero = T::Weight::from(0);
+    if nodes
+        .iter()
+        .any(|node| dist[&(node.clone(), node.clone())] < zero)
+    {
+        return Err(NegativeCycle);
+    }
+
+    Ok(dist)
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the shortest distance between every pair of nodes, allowing
+/// negative edge weights (Johnson's algorithm)
+///
+/// Inputs:
+/// - `graph: &T` The graph to search through
+///
+/// Output:
+/// - Same as `floyd_warshall`
+///
+/// Side-effects: N/A
+///
+/// Time complexity: O(V (V + E) log(V))
+/// - O(V E) for Bellman-Ford, then V runs of Dijkstra's at O((V + E) log(V))
+///   each, which is faster than `floyd_warshall` unless the graph is dense
+///
+pub fn johnsons<T: IWeightedGraph + IDefiniteGraph>(
+    graph: &T,
+) -> Result<AllPairs<T::Node, T::Weight>, NegativeCycle>
+where
+    T::Weight: Sub<T::Weight, Output = T::Weight>,
+{
+    /*
+    Reweighting ---
+
+        Dijkstra's can't handle negative edges, so first we give every node a
+        potential h (its distance from a virtual source connected to every
+        node, found with Bellman-Ford) and reweight each edge u -> v as
+
+            w(u, v) + h(u) - h(v)
+
+        Since h(v) <= h(u) + w(u, v), that's never negative.
+        Along any path from a to b the potentials cancel out except at the
+        ends, so every path from a to b changes by the same h(a) - h(b), and
+        the shortest paths stay the shortest.
+        We then run Dijkstra's from each node and undo the change.
+
+    */
+
+    let h = potentials(graph)?;
+
+    let mut reweighted = WeightedGraph::new();
+
+    for node in graph.get_all() {
+        reweighted.insert_node(node);
+    }
+
+    for node in graph.get_all() {
+        reweighted.extend(
+            graph
+                .get_adj_weighted(&node)
+                .into_iter()
+                .map(|(adj, weight)| {
+                    let weight = weight + h[&node].clone() - h[&adj].clone();
+                    (node.clone(), adj, weight)
+                }),
+        );
+    }
+
+    let mut dist = HashMap::new();
+
+    for from in graph.get_all() {
+        let (reached, _) = dijkstras_all(&reweighted, &from);
+
+        for (to, weight) in reached {
+            let weight = weight - h[&from].clone() + h[&to].clone();
+            dist.insert((from.clone(), to), weight);
+        }
+    }
+
+    Ok(dist)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use crate::data_structures::graphs::weighted_graph::WeightedGraph;
+
+    use super::*;
+
+    fn sample() -> WeightedGraph<&'static str, i32> {
+        [
+            ("A", "B", 3),
+            ("A", "C", 8),
+            ("A", "E", -4),
+            ("B", "D", 1),
+            ("B", "E", 7),
+            ("C", "B", 4),
+            ("D", "A", 2),
+            ("D", "C", -5),
+            ("E", "D", 6),
+            ("F", "A", 1),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn johnsons_matches_floyd_warshall() {
+        let graph = sample();
+
+        let expected = floyd_warshall(&graph).unwrap();
+        let dist = johnsons(&graph).unwrap();
+
+        assert_eq!(dist, expected);
+
+        assert_eq!(dist[&("A", "C")], -3);
+        assert_eq!(dist[&("E", "B")], 5);
+        assert_eq!(dist[&("F", "F")], 0);
+        assert!(!dist.contains_key(&("A", "F")));
+
+        // reversing every edge flips every distance around
+        let transposed = johnsons(&graph.transpose()).unwrap();
+
+        assert_eq!(transposed.len(), dist.len());
+        for ((from, to), weight) in &dist {
+            assert_eq!(transposed[&(*to, *from)], *weight);
+        }
+    }
+
+    #[test]
+    fn negative_cycle() {
+        let mut graph = sample();
+        graph.extend([("C", "D", 1)]);
+
+        assert_eq!(floyd_warshall(&graph), Err(NegativeCycle));
+        assert_eq!(johnsons(&graph), Err(NegativeCycle));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
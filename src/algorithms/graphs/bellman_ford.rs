@@ -0,0 +1,169 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashMap;
+
+use crate::{
+    algorithms::graphs::{dijkstras::ShortestPaths, relax},
+    data_structures::graphs::{IDefiniteGraph, IWeightedGraph},
+};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returned when a graph has a cycle of negative total weight, since going
+/// around it again always gives a shorter path and no shortest path exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeCycle;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the shortest distances and predecessors of every node reachable
+/// from `origin`, allowing negative edge weights
+///
+/// Slower than Dijkstra's, but doesn't assume paths never get shorter by
+/// adding edges.
+///
+/// Inputs:
+/// - `graph: &T` The graph to search through
+/// - `origin: &T::Node` The node to start from
+///
+/// Output:
+/// - If no negative cycle is reachable from origin
+///     - `Ok((dist, prev))` Same as `dijkstras_all`
+/// - Else
+///     - `Err(NegativeCycle)`
+///
+/// Side-effects: N/A
+///
+/// Time complexity: O(V * E)
+///
+pub fn bellman_ford<T: IWeightedGraph + IDefiniteGraph>(
+    graph: &T,
+    origin: &T::Node,
+) -> Result<ShortestPaths<T::Node, T::Weight>, NegativeCycle> {
+    let mut dist = HashMap::new();
+    let mut prev = HashMap::new();
+
+    if graph.contains(origin) {
+        dist.insert(origin.clone(), T::Weight::from(0));
+    }
+
+    relax_all(graph, &mut dist, &mut prev)?;
+
+    Ok((dist, prev))
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the shortest distance to every node from a virtual source with a
+/// zero weight edge to every node
+///
+/// These are the potentials Johnson's algorithm reweights the graph with:
+/// every node starts at 0 and only ever goes down, so no node is left out.
+///
+/// Output:
+/// - If the graph has no negative cycles
+///     - `Ok(HashMap<T::Node, T::Weight>)` Maps: Node -> potential (<= 0)
+/// - Else
+///     - `Err(NegativeCycle)`
+///
+pub fn potentials<T: IWeightedGraph + IDefiniteGraph>(
+    graph: &T,
+) -> Result<HashMap<T::Node, T::Weight>, NegativeCycle> {
+    let mut dist = graph
+        .get_all()
+        .into_iter()
+        .map(|node| (node, T::Weight::from(0)))
+        .collect();
+
+    relax_all(graph, &mut dist, &mut HashMap::new())?;
+
+    Ok(dist)
+}
+
+//---------------------------------------------------------------------------//
+
+/// Relaxes every edge until nothing changes, giving up with `NegativeCycle`
+/// if distances are still shrinking after V rounds
+///
+/// A shortest path without cycles has at most V - 1 edges, so without a
+/// negative cycle every distance is final after V - 1 rounds. The one extra
+/// round covers the virtual source `potentials` uses.
+fn relax_all<T: IWeightedGraph + IDefiniteGraph>(
+    graph: &T,
+    dist: &mut HashMap<T::Node, T::Weight>,
+    prev: &mut HashMap<T::Node, T::Node>,
+) -> Result<(), NegativeCycle> {
+    let mut nodes = graph.get_all();
+    nodes.sort();
+
+    for _ in 0..=graph.len() {
+        let mut changed = false;
+
+        for node in &nodes {
+            for (adj, weight) in graph.get_adj_weighted(node) {
+                changed |= relax(dist, prev, node, adj, weight);
+            }
+        }
+
+        if !changed {
+            return Ok(());
+        }
+    }
+
+    Err(NegativeCycle)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use crate::data_structures::graphs::weighted_graph::WeightedGraph;
+
+    use super::*;
+
+    #[test]
+    fn test_bellman_ford() {
+        let graph: WeightedGraph<&str, i32> = [
+            ("A", "B", 4),
+            ("A", "C", 2),
+            ("C", "B", -3),
+            ("B", "D", 1),
+            ("E", "A", 1),
+        ]
+        .into_iter()
+        .collect();
+
+        let (dist, prev) = bellman_ford(&graph, &"A").unwrap();
+
+        assert_eq!(
+            dist,
+            HashMap::from([("A", 0), ("B", -1), ("C", 2), ("D", 0)])
+        );
+        assert_eq!(prev[&"B"], "C");
+        assert_eq!(prev[&"D"], "B");
+
+        // E can reach A, but not the other way around
+        assert!(!dist.contains_key(&"E"));
+        assert_eq!(potentials(&graph).unwrap()[&"B"], -3);
+    }
+
+    #[test]
+    fn negative_cycle() {
+        let graph: WeightedGraph<&str, i32> = [("A", "B", 1), ("B", "C", -2), ("C", "B", 1)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(bellman_ford(&graph, &"A"), Err(NegativeCycle));
+        assert_eq!(potentials(&graph), Err(NegativeCycle));
+
+        // the cycle can't be reached from C's side of things
+        let graph: WeightedGraph<&str, i32> = [("A", "B", -2), ("B", "A", 1), ("C", "D", 1)]
+            .into_iter()
+            .collect();
+
+        assert!(bellman_ford(&graph, &"C").is_ok());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
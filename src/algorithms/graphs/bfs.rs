@@ -1,9 +1,15 @@
 ///////////////////////////////////////////////////////////////////////////////
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::Hash;
 
-use crate::data_structures::graphs::IGraph;
+use crate::{
+    algorithms::graphs::relax,
+    data_structures::{
+        binary_heap::BinaryHeap,
+        graphs::{IDefiniteGraph, IGraph, IWeightedGraph},
+    },
+};
 
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -60,4 +66,694 @@ where
     known
 }
 
+//---------------------------------------------------------------------------//
+
+/// Returns every node reachable from `origin`, in the order a breadth first
+/// search visits them
+///
+/// Unlike `breadth_first_search`, which goes through neighbors in whatever
+/// order `get_adj` hands them back, each node's neighbors are visited from
+/// smallest to largest, so the order is the same on every run.
+pub fn breadth_first_search_ordered<T: IGraph>(graph: &T, origin: T::Node) -> Vec<T::Node> {
+    let mut seen = HashSet::from([origin.clone()]);
+    let mut queue = VecDeque::from([origin]);
+    let mut order = vec![];
+
+    while let Some(node) = queue.pop_front() {
+        let mut adj: Vec<T::Node> = graph.get_adj(&node).into_iter().collect();
+        adj.sort();
+
+        for adj in adj {
+            if seen.insert(adj.clone()) {
+                queue.push_back(adj);
+            }
+        }
+
+        order.push(node);
+    }
+
+    order
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns a map of (node -> (distance from origin, number of shortest paths))
+///
+/// Only nodes reachable from `origin` are included.
+pub fn bfs_path_counts<T: IGraph>(graph: &T, origin: T::Node) -> HashMap<T::Node, (usize, usize)>
+where
+    T::Node: Eq + Hash + Clone,
+{
+    // this is the same layered search as `breadth_first_search`, but instead
+    // of remembering a single path to each node, we'll count them
+
+    let mut frontier = vec![origin.clone()];
+
+    // there is exactly one (empty) path from the origin to itself
+    let mut known: HashMap<T::Node, (usize, usize)> = HashMap::new();
+    known.insert(origin, (0, 1));
+
+    let mut depth = 0;
+
+    while !frontier.is_empty() {
+        depth += 1;
+
+        let mut new_frontier = vec![];
+
+        for node in frontier {
+            let (_, paths) = known[&node];
+
+            for adj in graph.get_adj(&node) {
+                match known.get_mut(&adj) {
+                    // we've found a brand new node, everything that leads to
+                    // us also leads to it
+                    None => {
+                        known.insert(adj.clone(), (depth, paths));
+                        new_frontier.push(adj);
+                    }
+                    // we've found another shortest path to a node in the next
+                    // layer, so it inherits all of our paths as well
+                    Some((dist, count)) if *dist == depth => {
+                        *count += paths;
+                    }
+                    // anything else was already reached by a shorter path
+                    _ => {}
+                }
+            }
+        }
+
+        frontier = new_frontier;
+    }
+
+    known
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns a map of (node -> distance from origin)
+///
+/// Only nodes reachable from `origin` are included.
+pub fn bfs_depths<T: IGraph>(graph: &T, origin: T::Node) -> HashMap<T::Node, usize> {
+    let mut frontier = vec![origin.clone()];
+
+    let mut depths = HashMap::from([(origin, 0)]);
+    let mut depth = 0;
+
+    while !frontier.is_empty() {
+        depth += 1;
+
+        let mut new_frontier = vec![];
+
+        for node in frontier {
+            for adj in graph.get_adj(&node) {
+                if !depths.contains_key(&adj) {
+                    depths.insert(adj.clone(), depth);
+                    new_frontier.push(adj);
+                }
+            }
+        }
+
+        frontier = new_frontier;
+    }
+
+    depths
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the longest shortest path between any two nodes, counted in
+/// edges
+///
+/// Output:
+/// - If every node can reach every other node
+///     - `Some(usize)` The diameter (0 for a single node)
+/// - Else (including the empty graph)
+///     - `None` Some distances are infinite
+///
+/// Time complexity: O(V * (V + E)), a BFS from every node
+///
+pub fn diameter<T: IDefiniteGraph>(graph: &T) -> Option<usize> {
+    eccentricities(graph)?
+        .into_iter()
+        .map(|(_, eccentricity)| eccentricity)
+        .max()
+}
+
+/// Returns the nodes that are the least far from the node furthest away
+/// from them (minimum eccentricity), sorted
+///
+/// Empty if the graph is, or if some node can't reach some other node (the
+/// eccentricities would all be infinite).
+///
+/// Time complexity: O(V * (V + E)), a BFS from every node
+///
+pub fn center<T: IDefiniteGraph>(graph: &T) -> Vec<T::Node> {
+    let Some(eccentricities) = eccentricities(graph) else {
+        return vec![];
+    };
+
+    let Some(radius) = eccentricities.iter().map(|(_, e)| *e).min() else {
+        return vec![];
+    };
+
+    let mut center: Vec<T::Node> = eccentricities
+        .into_iter()
+        .filter(|(_, e)| *e == radius)
+        .map(|(node, _)| node)
+        .collect();
+    center.sort();
+
+    center
+}
+
+/// How far each node is from the node furthest from it, or `None` if any
+/// node can't reach every other node
+fn eccentricities<T: IDefiniteGraph>(graph: &T) -> Option<Vec<(T::Node, usize)>> {
+    let n = graph.len();
+
+    graph
+        .get_all()
+        .into_iter()
+        .map(|node| {
+            let depths = bfs_depths(graph, node.clone());
+
+            (depths.len() == n).then(|| {
+                let furthest = depths.into_values().max().unwrap_or(0);
+                (node, furthest)
+            })
+        })
+        .collect()
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the `k` highest scoring nodes at most `max_depth` edges away from
+/// `origin`
+///
+/// Inputs:
+/// - `graph: &T` The graph to search
+/// - `origin: T::Node` The node to start from, which counts as depth 0
+/// - `max_depth: usize` How many layers out to look
+/// - `k: usize` How many nodes to keep
+/// - `score: impl Fn(&T::Node) -> i64` How good each node is
+///
+/// Output:
+/// - `Vec<T::Node>` Up to `k` nodes, highest score first (ties go to the
+///   larger node)
+///
+/// Side-effects: N/A
+///
+/// Time complexity: O((V + E) + V log k), for the part of the graph within
+/// `max_depth`
+///
+pub fn top_k_within<T: IGraph>(
+    graph: &T,
+    origin: T::Node,
+    max_depth: usize,
+    k: usize,
+    score: impl Fn(&T::Node) -> i64,
+) -> Vec<T::Node> {
+    /*
+    The heap is a min-heap holding the best k nodes seen so far, so its top
+    is the worst of them. Each new node either doesn't beat that and is
+    dropped, or pushes it out. That keeps the memory at O(k) no matter how
+    many nodes are in range, and the search never looks past the last layer
+    it needs.
+    */
+    if k == 0 {
+        return vec![];
+    }
+
+    let mut best: BinaryHeap<(i64, T::Node)> = BinaryHeap::new();
+
+    let mut seen = HashSet::from([origin.clone()]);
+    let mut frontier = vec![origin];
+
+    for depth in 0..=max_depth {
+        let mut new_frontier = vec![];
+
+        for node in frontier {
+            best.insert((score(&node), node.clone()));
+            if best.len() > k {
+                best.extract_min();
+            }
+
+            if depth == max_depth {
+                continue;
+            }
+
+            for adj in graph.get_adj(&node) {
+                if seen.insert(adj.clone()) {
+                    new_frontier.push(adj);
+                }
+            }
+        }
+
+        if new_frontier.is_empty() {
+            break;
+        }
+
+        frontier = new_frontier;
+    }
+
+    best.into_sorted_vec()
+        .into_iter()
+        .rev()
+        .map(|(_, node)| node)
+        .collect()
+}
+
+//---------------------------------------------------------------------------//
+
+/// Same as `bfs_depths`, but expands each layer across threads
+///
+/// The frontier is split into chunks that are explored in parallel. Each
+/// chunk gathers the unseen neighbors it finds and adds them to the next
+/// frontier in one go, which is a shared set, so a node found from several
+/// chunks only appears once.
+///
+/// Layers smaller than a chunk aren't worth handing out to other threads,
+/// so they're expanded sequentially instead.
+#[cfg(feature = "rayon")]
+pub fn par_breadth_first_search<T: IGraph + Sync>(
+    graph: &T,
+    origin: T::Node,
+) -> HashMap<T::Node, usize>
+where
+    T::Node: Send + Sync,
+{
+    par_bfs_chunked(graph, origin, 256)
+}
+
+/// `par_breadth_first_search`, handing out layers in chunks of `chunk` nodes
+#[cfg(feature = "rayon")]
+fn par_bfs_chunked<T: IGraph + Sync>(
+    graph: &T,
+    origin: T::Node,
+    chunk: usize,
+) -> HashMap<T::Node, usize>
+where
+    T::Node: Send + Sync,
+{
+    use rayon::prelude::*;
+    use std::sync::Mutex;
+
+    let mut frontier = vec![origin.clone()];
+
+    let mut depths = HashMap::from([(origin, 0)]);
+    let mut depth = 0;
+
+    while !frontier.is_empty() {
+        depth += 1;
+
+        // depths is only read while a layer is being expanded, so every
+        // thread can look at it without locking
+        let seen = &depths;
+        let unseen = |node: &T::Node| {
+            graph
+                .get_adj(node)
+                .into_iter()
+                .filter(|adj| !seen.contains_key(adj))
+        };
+
+        let new_frontier: HashSet<T::Node> = if frontier.len() <= chunk {
+            frontier.iter().flat_map(unseen).collect()
+        } else {
+            let next = Mutex::new(HashSet::new());
+
+            frontier.par_chunks(chunk).for_each(|nodes| {
+                let found: Vec<T::Node> = nodes.iter().flat_map(unseen).collect();
+
+                next.lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .extend(found);
+            });
+
+            next.into_inner()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+        };
+
+        for node in &new_frontier {
+            depths.insert(node.clone(), depth);
+        }
+
+        frontier = new_frontier.into_iter().collect();
+    }
+
+    depths
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns a shortest path from `origin` to `target` in a graph whose edges
+/// all weigh either 0 or 1
+///
+/// Same result as Dijkstra's, but runs in O(V + E) by using a deque instead
+/// of searching for the closest node.
+///
+/// Inputs:
+/// - `graph: &T` The graph to search through (every weight must be 0 or 1)
+/// - `origin: &T::Node` The node to start from
+/// - `target: &T::Node` The node to try and route to
+///
+/// Output:
+/// - If there exists a path from origin to target in graph
+///     - `Some(Vec<T::Node>)` A shortest path from origin to target
+/// - Else
+///     - `None` No valid path found
+///
+/// Side-effects: N/A
+///
+pub fn zero_one_bfs<T: IWeightedGraph>(
+    graph: &T,
+    origin: &T::Node,
+    target: &T::Node,
+) -> Option<Vec<T::Node>> {
+    let zero: T::Weight = 0.into();
+    let one: T::Weight = 1.into();
+
+    // Maps: Node -> the shortest known distance from origin
+    let mut dist: HashMap<T::Node, T::Weight> = HashMap::new();
+
+    // Maps: Node -> the node immediately before it in the known shortest path
+    let mut prev: HashMap<T::Node, T::Node> = HashMap::new();
+
+    // the deque is always sorted by distance, and never holds more than two
+    // distinct distances (d and d + 1)
+    // so the front is always one of the closest unvisited nodes, just like
+    // the frontier in Dijkstra's
+    let mut deque = VecDeque::from([origin.clone()]);
+    dist.insert(origin.clone(), zero.clone());
+
+    while let Some(node) = deque.pop_front() {
+        if node == *target {
+            break;
+        }
+
+        for (adj, edge_weight) in graph.get_adj_weighted(&node) {
+            debug_assert!(
+                edge_weight == zero || edge_weight == one,
+                "zero_one_bfs requires every weight to be 0 or 1, found {:?}",
+                edge_weight
+            );
+
+            let free = edge_weight == zero;
+
+            if relax(&mut dist, &mut prev, &node, adj.clone(), edge_weight) {
+                // free edges keep us at the same distance, so they go
+                // ahead of everything else
+                if free {
+                    deque.push_front(adj);
+                } else {
+                    deque.push_back(adj);
+                }
+            }
+        }
+    }
+
+    if !dist.contains_key(target) {
+        return None;
+    }
+
+    let mut res = vec![target.clone()];
+
+    let mut cur = prev.get(target);
+    while let Some(curr) = cur {
+        res.push(curr.clone());
+        cur = prev.get(curr);
+    }
+
+    res.reverse();
+
+    Some(res)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use crate::{
+        algorithms::graphs::dijkstras::{dijkstras, dijkstras_all},
+        data_structures::graphs::{
+            directed_graph::DirectedGraph, undirected_graph::UndirectedGraph,
+            weighted_graph::WeightedGraph, IGraphEdgeMut, IGraphEdgeWeightedMut, IGraphMut,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_path_counts() {
+        let mut graph = DirectedGraph::new();
+
+        for node in ["A", "B", "C", "D", "E", "F"] {
+            graph.insert_node(node);
+        }
+
+        // two equal length routes from A to D
+        graph.insert_edge("A", "B");
+        graph.insert_edge("A", "C");
+        graph.insert_edge("B", "D");
+        graph.insert_edge("C", "D");
+
+        // a single longer route to E, and an unreachable F
+        graph.insert_edge("D", "E");
+        graph.insert_edge("A", "E");
+        graph.insert_edge("F", "A");
+
+        let counts = bfs_path_counts(&graph, "A");
+
+        assert_eq!(counts.get("A"), Some(&(0, 1)));
+        assert_eq!(counts.get("B"), Some(&(1, 1)));
+        assert_eq!(counts.get("C"), Some(&(1, 1)));
+        assert_eq!(counts.get("D"), Some(&(2, 2)));
+        assert_eq!(counts.get("E"), Some(&(1, 1)));
+        assert_eq!(counts.get("F"), None);
+    }
+
+    #[test]
+    fn test_zero_one_bfs() {
+        let mut graph = WeightedGraph::new();
+
+        for node in ["A", "B", "C", "D", "E", "F"] {
+            graph.insert_node(node);
+        }
+
+        // the direct route is one step but costs 1, the long way round is free
+        graph.insert_edge_weighted("A", "E", 1);
+        graph.insert_edge_weighted("A", "B", 0);
+        graph.insert_edge_weighted("B", "C", 0);
+        graph.insert_edge_weighted("C", "D", 0);
+        graph.insert_edge_weighted("D", "E", 0);
+        graph.insert_edge_weighted("E", "F", 1);
+        graph.insert_edge_weighted("C", "F", 1);
+
+        let path = zero_one_bfs(&graph, &"A", &"E");
+
+        assert_eq!(path, Some(vec!["A", "B", "C", "D", "E"]));
+        assert_eq!(path, dijkstras(&graph, &"A", &"E"));
+
+        assert_eq!(zero_one_bfs(&graph, &"E", &"A"), None);
+        assert_eq!(zero_one_bfs(&graph, &"A", &"A"), Some(vec!["A"]));
+
+        // compare path costs with dijkstra's on a bigger graph
+        let mut graph = WeightedGraph::new();
+
+        for i in 0..100 {
+            graph.insert_node(i);
+        }
+
+        for i in 0..100 {
+            for j in [1, 7, 31] {
+                graph.insert_edge_weighted(i, (i * j + 3) % 100, (i + j) % 2);
+            }
+        }
+
+        let (dist, _) = dijkstras_all(&graph, &0);
+
+        for target in 0..100 {
+            match zero_one_bfs(&graph, &0, &target) {
+                Some(path) => {
+                    let cost: i32 = path
+                        .windows(2)
+                        .map(|pair| {
+                            graph
+                                .get_adj_weighted(&pair[0])
+                                .into_iter()
+                                .filter(|(to, _)| *to == pair[1])
+                                .map(|(_, weight)| weight)
+                                .min()
+                                .unwrap()
+                        })
+                        .sum();
+
+                    assert_eq!(Some(&cost), dist.get(&target));
+                }
+                None => assert!(!dist.contains_key(&target)),
+            }
+        }
+    }
+
+    #[test]
+    fn ordered() {
+        let mut graph = DirectedGraph::new();
+
+        for node in 1..=7 {
+            graph.insert_node(node);
+        }
+
+        for (from, to) in [
+            (1, 3),
+            (1, 2),
+            (2, 5),
+            (2, 4),
+            (3, 6),
+            (4, 7),
+            (5, 7),
+            (6, 2),
+        ] {
+            graph.insert_edge(from, to);
+        }
+
+        for _ in 0..10 {
+            assert_eq!(
+                breadth_first_search_ordered(&graph, 1),
+                vec![1, 2, 3, 4, 5, 6, 7]
+            );
+        }
+        assert_eq!(breadth_first_search_ordered(&graph, 6), vec![6, 2, 4, 5, 7]);
+    }
+
+    #[test]
+    fn test_bfs_depths() {
+        let mut graph = DirectedGraph::new();
+
+        for node in ["A", "B", "C", "D", "E"] {
+            graph.insert_node(node);
+        }
+
+        graph.insert_edge("A", "B");
+        graph.insert_edge("A", "C");
+        graph.insert_edge("B", "D");
+        graph.insert_edge("C", "D");
+        graph.insert_edge("D", "A");
+
+        assert_eq!(
+            bfs_depths(&graph, "A"),
+            HashMap::from([("A", 0), ("B", 1), ("C", 1), ("D", 2)])
+        );
+        assert_eq!(bfs_depths(&graph, "E"), HashMap::from([("E", 0)]));
+    }
+
+    #[test]
+    fn diameter_and_center() {
+        // P5: 1 - 2 - 3 - 4 - 5
+        let path: UndirectedGraph<i32> = [(1, 2), (2, 3), (3, 4), (4, 5)].into_iter().collect();
+
+        assert_eq!(diameter(&path), Some(4));
+        assert_eq!(center(&path), vec![3]);
+
+        // an even path has two middle nodes
+        let mut even = path.clone();
+        even.insert_node(6);
+        even.insert_edge(5, 6);
+
+        assert_eq!(diameter(&even), Some(5));
+        assert_eq!(center(&even), vec![3, 4]);
+
+        // every node of a cycle is as central as any other
+        let mut cycle = path.clone();
+        cycle.insert_edge(5, 1);
+
+        assert_eq!(diameter(&cycle), Some(2));
+        assert_eq!(center(&cycle), vec![1, 2, 3, 4, 5]);
+
+        // a lone node is its own center
+        let mut single = UndirectedGraph::new();
+        single.insert_node(0);
+
+        assert_eq!(diameter(&single), Some(0));
+        assert_eq!(center(&single), vec![0]);
+
+        // infinite distances
+        let mut split = path.clone();
+        split.insert_node(9);
+
+        assert_eq!(diameter(&split), None);
+        assert_eq!(center(&split), Vec::<i32>::new());
+        assert_eq!(diameter(&UndirectedGraph::<i32>::new()), None);
+
+        // directed edges only count one way
+        let one_way: DirectedGraph<i32> = [(1, 2), (2, 3)].into_iter().collect();
+        assert_eq!(diameter(&one_way), None);
+    }
+
+    #[test]
+    fn test_top_k_within() {
+        // layer d holds the nodes 10d..10d + 4, each pointing at every node
+        // in the next layer
+        let mut graph = DirectedGraph::new();
+        let layer = |d: i32| (10 * d)..(10 * d + 4);
+
+        graph.insert_node(0);
+        for d in 1..=4 {
+            for node in layer(d) {
+                graph.insert_node(node);
+                for prev in layer(d - 1).filter(|prev| d > 1 || *prev == 0) {
+                    graph.insert_edge(prev, node);
+                }
+            }
+        }
+
+        // favours the far layers and the odd nodes
+        let score = |node: &i32| i64::from(node / 10 * 3 + node % 2 * 5);
+
+        // the third layer is too far, no matter how well it scores
+        assert_eq!(top_k_within(&graph, 0, 2, 3, score), vec![23, 21, 13]);
+
+        for max_depth in 0..6 {
+            for k in 0..20 {
+                let mut expected: Vec<i32> = graph
+                    .get_all()
+                    .into_iter()
+                    .filter(|node| node / 10 <= max_depth)
+                    .collect();
+                expected.sort_by_key(|node| Reverse((score(node), *node)));
+                expected.truncate(k);
+
+                assert_eq!(
+                    top_k_within(&graph, 0, max_depth as usize, k, score),
+                    expected
+                );
+            }
+        }
+
+        // only what's reachable counts, not the rest of its layer
+        assert_eq!(top_k_within(&graph, 31, 1, 3, score), vec![43, 41, 31]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_breadth_first_search() {
+        let size = 120;
+        let graph = crate::algorithms::graphs::shared_test_cases::grid(size);
+
+        for origin in [(0, 0), (size / 2, size / 3)] {
+            let expected = bfs_depths(&graph, origin);
+
+            let depths = super::par_breadth_first_search(&graph, origin);
+            assert_eq!(depths.len(), (size * size) as usize);
+            assert_eq!(depths, expected);
+
+            // no layer here is big enough to split up at the default chunk
+            // size, so shrink it to make sure the parallel path runs too
+            for chunk in [1, 7, 32] {
+                assert_eq!(par_bfs_chunked(&graph, origin, chunk), expected);
+            }
+        }
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
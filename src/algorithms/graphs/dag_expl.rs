@@ -5,7 +5,7 @@
 use std::collections::HashMap;
 
 use crate::{
-    algorithms::graphs::dfs,
+    algorithms::graphs::{dfs, relax},
     data_structures::graphs::{IDefiniteGraph, IWeightedGraph},
 };
 
@@ -29,26 +29,11 @@ pub fn dag<T: IDefiniteGraph + IWeightedGraph + Clone>(
             weights.insert(node.clone(), 0.into());
 
             for (adj, weight) in graph.get_adj_weighted(&node) {
-                weights.insert(adj.clone(), weight);
-                preds.insert(adj, node.clone());
+                relax(&mut weights, &mut preds, &node, adj, weight);
             }
         } else if found {
-            let cur_weight = weights.get(&node).unwrap().clone();
-
             for (adj, weight) in graph.get_adj_weighted(&node) {
-                match (weights.get_mut(&adj), preds.get_mut(&adj)) {
-                    (Some(adj_weight), Some(adj_pred))
-                        if *adj_weight > weight.clone() + cur_weight.clone() =>
-                    {
-                        *adj_weight = weight + cur_weight.clone();
-                        *adj_pred = node.clone();
-                    }
-                    (None, None) => {
-                        weights.insert(adj.clone(), weight);
-                        preds.insert(adj, node.clone());
-                    }
-                    _ => {}
-                }
+                relax(&mut weights, &mut preds, &node, adj, weight);
             }
         } else if node == *target {
             return None;
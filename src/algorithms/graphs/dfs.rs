@@ -25,41 +25,71 @@ pub fn chart_forest<T: IDefiniteGraph>(graph: T) -> ForestChart<T> {
     let mut marks: HashMap<T::Node, bool> = HashMap::new();
     let mut trees = HashMap::new();
 
-    fn chart_tree<T: IGraph>(
-        graph: &T,
+    // Checks a node we've just reached, merging in any tree already charted
+    // from it. Returns whether the node is new and needs to be explored.
+    fn enter<T: IGraph>(
         node: &T::Node,
         marks: &mut HashMap<T::Node, bool>,
         trees: &mut HashMap<T::Node, TreeChart<T>>,
         cyclic: &mut bool,
         topo: &mut Vec<T::Node>,
-    ) {
+    ) -> bool {
         match marks.get(node) {
             Some(mark) if *mark => {
                 if let Some(other) = trees.remove(node) {
                     *cyclic = *cyclic && other.cyclic;
                     topo.extend(other.topo.into_iter());
                 }
-                return;
+                false
             }
             Some(_) => {
                 if let Some(other) = trees.remove(node) {
                     topo.extend(other.topo.into_iter());
                 }
                 *cyclic = true;
-                return;
+                false
+            }
+            None => {
+                marks.insert(node.clone(), false);
+                true
             }
-            None => {}
         }
+    }
 
-        marks.insert(node.clone(), false);
+    // Uses an explicit stack of (node, unvisited neighbors) rather than
+    // recursing, so deep graphs don't overflow the call stack
+    fn chart_tree<T: IGraph>(
+        graph: &T,
+        node: &T::Node,
+        marks: &mut HashMap<T::Node, bool>,
+        trees: &mut HashMap<T::Node, TreeChart<T>>,
+        cyclic: &mut bool,
+        topo: &mut Vec<T::Node>,
+    ) {
+        let mut stack = vec![];
 
-        for node in graph.get_adj(&node) {
-            chart_tree(graph, &node, marks, trees, cyclic, topo);
+        if enter(node, marks, trees, cyclic, topo) {
+            stack.push((node.clone(), graph.get_adj(node).into_iter()));
         }
 
-        marks.insert(node.clone(), true);
+        while let Some((node, children)) = stack.last_mut() {
+            match children.next() {
+                Some(child) => {
+                    if enter(&child, marks, trees, cyclic, topo) {
+                        let adj = graph.get_adj(&child).into_iter();
+                        stack.push((child, adj));
+                    }
+                }
+                None => {
+                    let node = node.clone();
+                    stack.pop();
 
-        topo.push(node.clone());
+                    marks.insert(node.clone(), true);
+
+                    topo.push(node);
+                }
+            }
+        }
     }
 
     for root in graph.get_all() {
@@ -208,3 +238,706 @@ fn dfs_visit<T: IGraph>(
 }
 
 ///////////////////////////////////////////////////////////////////////////////
+
+/// Iterative version of `depth_first_search`
+///
+/// Returns the same roots, topological order and cycle flag, but keeps its
+/// own stack on the heap instead of recursing, so deep graphs (like a chain
+/// of 100,000 nodes) don't overflow the call stack.
+pub fn depth_first_search_iter<T: IDefiniteGraph>(
+    graph: T,
+) -> (HashSet<<T as IGraph>::Node>, Vec<<T as IGraph>::Node>, bool)
+where
+    T::Node: Eq + Hash + Clone,
+{
+    let (roots, mut order, cyclic) = post_order(&graph);
+
+    order.reverse();
+
+    (roots, order, cyclic)
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns every node in the graph in depth first pre-order (each node comes
+/// right before the nodes first discovered through it)
+///
+/// Searches start from the smallest unvisited node, and each node's neighbors
+/// are visited from smallest to largest, so unlike `depth_first_search`
+/// (which follows `get_all`/`get_adj` order) the result is the same on every
+/// run.
+pub fn depth_first_search_ordered<T: IDefiniteGraph>(graph: &T) -> Vec<T::Node> {
+    let mut roots = graph.get_all();
+    roots.sort();
+
+    let mut visited: HashSet<T::Node> = HashSet::new();
+    let mut order = vec![];
+
+    for root in roots {
+        // nodes are only marked once they're popped, so the stack can hold a
+        // node more than once, but that's what keeps this a true pre-order
+        let mut stack = vec![root];
+
+        while let Some(node) = stack.pop() {
+            if !visited.insert(node.clone()) {
+                continue;
+            }
+
+            // pushed largest first so the smallest is popped first
+            let mut adj: Vec<T::Node> = graph
+                .get_adj(&node)
+                .into_iter()
+                .filter(|adj| !visited.contains(adj))
+                .collect();
+            adj.sort_by(|a, b| b.cmp(a));
+
+            stack.extend(adj);
+            order.push(node);
+        }
+    }
+
+    order
+}
+
+//---------------------------------------------------------------------------//
+
+/// Iterative depth first search over the whole graph
+///
+/// Same as `depth_first_search_iter`, except `order` is left in post-order
+/// (every node comes after everything reachable from it, for acyclic graphs)
+fn post_order<T: IDefiniteGraph>(
+    graph: &T,
+) -> (HashSet<<T as IGraph>::Node>, Vec<<T as IGraph>::Node>, bool)
+where
+    T::Node: Eq + Hash + Clone,
+{
+    let mut roots = HashSet::new();
+    let mut order = vec![];
+    let mut cyclic = false;
+
+    let mut perm_mark: HashSet<T::Node> = HashSet::new();
+    let mut temp_mark: HashSet<T::Node> = HashSet::new();
+
+    // each frame is a node we're processing and the neighbors we haven't
+    // looked at yet, which replaces the for loop in dfs_visit
+    let mut stack: Vec<(T::Node, std::collections::hash_set::IntoIter<T::Node>)> = vec![];
+
+    for origin in graph.get_all() {
+        if perm_mark.contains(&origin) || temp_mark.contains(&origin) {
+            continue;
+        }
+
+        roots.insert(origin.clone());
+
+        temp_mark.insert(origin.clone());
+        stack.push((origin.clone(), graph.get_adj(&origin).into_iter()));
+
+        while let Some((node, children)) = stack.last_mut() {
+            match children.next() {
+                // same checks as the start of dfs_visit
+                Some(child) => {
+                    if perm_mark.contains(&child) {
+                        roots.remove(&child);
+                    } else if temp_mark.contains(&child) {
+                        roots.remove(&child);
+                        cyclic = true;
+                    } else {
+                        temp_mark.insert(child.clone());
+                        let adj = graph.get_adj(&child).into_iter();
+                        stack.push((child, adj));
+                    }
+                }
+                // no neighbors left, so we're done with this node
+                None => {
+                    let node = node.clone();
+                    stack.pop();
+
+                    temp_mark.remove(&node);
+                    perm_mark.insert(node.clone());
+                    order.push(node);
+                }
+            }
+        }
+    }
+
+    (roots, order, cyclic)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returned by the topological sorts when the graph has a cycle, since no
+/// valid ordering exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle;
+
+//---------------------------------------------------------------------------//
+
+/// Returns the nodes in topological order
+///
+/// Every node comes *before* all of the nodes it has edges to, i.e.
+/// for an edge `a -> b`, `a` is listed first.
+///
+/// Inputs:
+/// - `graph: &T` The graph to sort
+///
+/// Output:
+/// - If the graph is acyclic
+///     - `Ok(Vec<T::Node>)` The nodes in topological order
+/// - Else
+///     - `Err(Cycle)`
+///
+/// Side-effects: N/A
+///
+pub fn topological_sort<T: IDefiniteGraph>(graph: &T) -> Result<Vec<T::Node>, Cycle> {
+    let mut order = reverse_topological_sort(graph)?;
+
+    order.reverse();
+
+    Ok(order)
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the nodes in reverse topological order (dependencies first)
+///
+/// Every node comes *after* all of the nodes it has edges to, i.e.
+/// for an edge `a -> b`, `b` is listed first. If edges point from a task to
+/// the tasks it depends on, this is a valid order to run them in.
+///
+/// Inputs:
+/// - `graph: &T` The graph to sort
+///
+/// Output:
+/// - If the graph is acyclic
+///     - `Ok(Vec<T::Node>)` The nodes in reverse topological order
+/// - Else
+///     - `Err(Cycle)`
+///
+/// Side-effects: N/A
+///
+pub fn reverse_topological_sort<T: IDefiniteGraph>(graph: &T) -> Result<Vec<T::Node>, Cycle> {
+    let (_, order, cyclic) = post_order(graph);
+
+    if cyclic {
+        Err(Cycle)
+    } else {
+        Ok(order)
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the nodes grouped into generations, where every node only has
+/// edges coming in from earlier generations
+///
+/// If edges point from a task to the tasks that depend on it, each
+/// generation is a batch of tasks that can all run at the same time, once
+/// the generations before it are done.
+///
+/// Inputs:
+/// - `graph: &T` The graph to sort
+///
+/// Output:
+/// - If the graph is acyclic
+///     - `Ok(Vec<Vec<T::Node>>)` The generations, each one sorted.
+///       Generation 0 is every node with no incoming edges.
+/// - Else
+///     - `Err(Cycle)`
+///
+/// Side-effects: N/A
+///
+pub fn topological_generations<T: IDefiniteGraph>(graph: &T) -> Result<Vec<Vec<T::Node>>, Cycle> {
+    /*
+    Kahn's algorithm, a generation at a time ---
+
+        Count the incoming edges of every node.
+        Nodes with none can go first, that's the first generation.
+        Taking a generation out of the graph removes its outgoing edges, and
+        any node whose count drops to zero is ready for the next generation.
+        If we run out of ready nodes before placing every node, the rest are
+        all waiting on each other, which means there's a cycle.
+
+    */
+
+    let mut in_degree: HashMap<T::Node, usize> =
+        graph.get_all().into_iter().map(|node| (node, 0)).collect();
+
+    for node in graph.get_all() {
+        for adj in graph.get_adj(&node) {
+            *in_degree.entry(adj).or_insert(0) += 1;
+        }
+    }
+
+    let mut generation: Vec<T::Node> = in_degree
+        .iter()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(node, _)| node.clone())
+        .collect();
+
+    let mut generations = vec![];
+    let mut placed = 0;
+
+    while !generation.is_empty() {
+        generation.sort();
+
+        let mut next = vec![];
+
+        for node in &generation {
+            for adj in graph.get_adj(node) {
+                let degree = in_degree.get_mut(&adj).expect("counted above");
+                *degree -= 1;
+
+                if *degree == 0 {
+                    next.push(adj);
+                }
+            }
+        }
+
+        placed += generation.len();
+        generations.push(std::mem::replace(&mut generation, next));
+    }
+
+    if placed < in_degree.len() {
+        Err(Cycle)
+    } else {
+        Ok(generations)
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the first back edge found, i.e. an edge `(from, to)` where `to`
+/// is still on the DFS stack, so the edge closes a cycle
+///
+/// Nodes and their neighbors are visited in sorted order, so the same graph
+/// always gives the same edge. Useful for pointing at *where* a cycle is,
+/// e.g. in dependency errors.
+///
+/// Inputs:
+/// - `graph: &T` The graph to search
+///
+/// Output:
+/// - If the graph is cyclic
+///     - `Some((from, to))` The edge closing the cycle, there is a path from
+///       `to` back to `from`
+/// - Else
+///     - `None`
+///
+pub fn first_back_edge<T: IDefiniteGraph>(graph: &T) -> Option<(T::Node, T::Node)> {
+    let sorted_adj = |node: &T::Node| {
+        let mut adj: Vec<T::Node> = graph.get_adj(node).into_iter().collect();
+        adj.sort();
+        adj.into_iter()
+    };
+
+    let mut roots: Vec<T::Node> = graph.get_all().into_iter().collect();
+    roots.sort();
+
+    let mut done: HashSet<T::Node> = HashSet::new();
+    let mut on_stack: HashSet<T::Node> = HashSet::new();
+    let mut stack = vec![];
+
+    for root in roots {
+        if done.contains(&root) {
+            continue;
+        }
+
+        on_stack.insert(root.clone());
+        stack.push((root.clone(), sorted_adj(&root)));
+
+        while let Some((node, children)) = stack.last_mut() {
+            match children.next() {
+                Some(child) => {
+                    if on_stack.contains(&child) {
+                        return Some((node.clone(), child));
+                    } else if !done.contains(&child) {
+                        on_stack.insert(child.clone());
+                        let adj = sorted_adj(&child);
+                        stack.push((child, adj));
+                    }
+                }
+                None => {
+                    let node = node.clone();
+                    stack.pop();
+
+                    on_stack.remove(&node);
+                    done.insert(node);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns every simple path (no repeated nodes) from `origin` to `target`
+///
+/// Only meant for small graphs: the number of simple paths can grow
+/// exponentially with the size of the graph (a complete graph on n nodes has
+/// more than (n - 2)! of them between any two nodes), and so does the time
+/// spent finding them. See `all_simple_paths_max_len` to limit the search.
+///
+/// Inputs:
+/// - `graph: &T` The graph to search
+/// - `origin: &T::Node` The node every path starts at
+/// - `target: &T::Node` The node every path ends at
+///
+/// Output:
+/// - `Vec<Vec<T::Node>>` Every path, from `origin` to `target` inclusive.
+///   Neighbors are explored in sorted order, so the paths come out sorted.
+///
+pub fn all_simple_paths<T: IGraph>(
+    graph: &T,
+    origin: &T::Node,
+    target: &T::Node,
+) -> Vec<Vec<T::Node>> {
+    all_simple_paths_max_len(graph, origin, target, usize::MAX)
+}
+
+//---------------------------------------------------------------------------//
+
+/// Like `all_simple_paths`, but skips paths with more than `max_len` edges
+pub fn all_simple_paths_max_len<T: IGraph>(
+    graph: &T,
+    origin: &T::Node,
+    target: &T::Node,
+    max_len: usize,
+) -> Vec<Vec<T::Node>> {
+    /*
+    Backtracking DFS ---
+
+        We keep the path we're currently on, along with a set of the nodes in
+        it so we never step onto a node twice.
+        Every time we reach the target we save a copy of the path, and every
+        time we run out of places to go we step back (popping the node off
+        the path and forgetting we'd visited it) so that other paths can go
+        through it.
+
+    */
+
+    fn visit<T: IGraph>(
+        graph: &T,
+        target: &T::Node,
+        max_len: usize,
+        path: &mut Vec<T::Node>,
+        on_path: &mut HashSet<T::Node>,
+        paths: &mut Vec<Vec<T::Node>>,
+    ) {
+        let node = path.last().expect("path always holds the origin").clone();
+
+        if node == *target {
+            paths.push(path.clone());
+            return;
+        }
+
+        // the path has `path.len() - 1` edges, taking another one would go
+        // over the limit
+        if path.len() > max_len {
+            return;
+        }
+
+        let mut adj: Vec<T::Node> = graph.get_adj(&node).into_iter().collect();
+        adj.sort();
+
+        for next in adj {
+            if on_path.insert(next.clone()) {
+                path.push(next.clone());
+                visit(graph, target, max_len, path, on_path, paths);
+                path.pop();
+                on_path.remove(&next);
+            }
+        }
+    }
+
+    let mut paths = vec![];
+
+    if graph.contains(origin) {
+        let mut path = vec![origin.clone()];
+        let mut on_path = HashSet::from([origin.clone()]);
+
+        visit(graph, target, max_len, &mut path, &mut on_path, &mut paths);
+    }
+
+    paths
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    //-----------------------------------------------------------------------//
+
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::data_structures::graphs::{directed_graph::DirectedGraph, IGraphEdgeMut, IGraphMut};
+
+    //-----------------------------------------------------------------------//
+
+    fn chain(len: usize) -> DirectedGraph<usize> {
+        let mut graph = DirectedGraph::new();
+
+        for i in 0..len {
+            graph.insert_node(i);
+        }
+
+        for i in 1..len {
+            graph.insert_edge(i - 1, i);
+        }
+
+        graph
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn iter_deep_chain() {
+        let len = 100_000;
+
+        let (roots, order, cyclic) = depth_first_search_iter(chain(len));
+
+        assert!(!cyclic);
+        assert_eq!(order.len(), len);
+        assert!(roots.contains(&0));
+
+        let position: HashMap<usize, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (*node, i))
+            .collect();
+
+        for i in 1..len {
+            assert!(position[&(i - 1)] < position[&i]);
+        }
+    }
+
+    #[test]
+    fn iter_matches_recursive() {
+        let mut graph = chain(50);
+
+        graph.insert_edge(10, 30);
+        graph.insert_edge(3, 45);
+        graph.insert_node(60);
+
+        let (_, order, cyclic) = depth_first_search_iter(graph.clone());
+        let (_, expected, expected_cyclic) = depth_first_search(graph.clone());
+
+        assert_eq!(cyclic, expected_cyclic);
+        assert_eq!(order.len(), expected.len());
+
+        graph.insert_edge(40, 20);
+
+        let (_, order, cyclic) = depth_first_search_iter(graph.clone());
+        let (_, _, expected_cyclic) = depth_first_search(graph);
+
+        assert!(cyclic);
+        assert_eq!(cyclic, expected_cyclic);
+        assert_eq!(order.len(), 51);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn chart_deep_chain() {
+        let len = 100_000;
+
+        let chart = chart_forest(chain(len));
+
+        assert_eq!(chart.trees.len(), 1);
+
+        let tree = chart.trees.values().next().unwrap();
+
+        assert!(!tree.cyclic);
+        assert_eq!(tree.topo.len(), len);
+    }
+
+    #[test]
+    fn chart_cycle() {
+        let mut graph = chain(20);
+        graph.insert_edge(19, 0);
+
+        let chart = chart_forest(graph);
+
+        assert_eq!(chart.trees.len(), 1);
+
+        let tree = chart.trees.values().next().unwrap();
+
+        assert!(tree.cyclic);
+        assert_eq!(tree.topo.len(), 20);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn topological_sorts() {
+        let mut graph = chain(50);
+
+        graph.insert_edge(10, 30);
+        graph.insert_edge(3, 45);
+        graph.insert_node(60);
+        graph.insert_edge(60, 5);
+
+        let forward = topological_sort(&graph).unwrap();
+        let mut backward = reverse_topological_sort(&graph).unwrap();
+
+        let position: HashMap<usize, usize> = forward
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (*node, i))
+            .collect();
+
+        for node in graph.get_all() {
+            for adj in graph.get_adj(&node) {
+                assert!(position[&node] < position[&adj]);
+            }
+        }
+
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        graph.insert_edge(40, 20);
+
+        assert_eq!(topological_sort(&graph), Err(Cycle));
+        assert_eq!(reverse_topological_sort(&graph), Err(Cycle));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn generations() {
+        // A -> B -> D, A -> C -> D
+        let mut graph = DirectedGraph::new();
+        for node in ["A", "B", "C", "D"] {
+            graph.insert_node(node);
+        }
+        for (from, to) in [("A", "B"), ("A", "C"), ("B", "D"), ("C", "D")] {
+            graph.insert_edge(from, to);
+        }
+
+        assert_eq!(
+            topological_generations(&graph),
+            Ok(vec![vec!["A"], vec!["B", "C"], vec!["D"]])
+        );
+
+        // an edge skipping a generation doesn't pull its target forward
+        graph.insert_node("E");
+        graph.insert_edge("A", "E");
+        graph.insert_edge("D", "E");
+
+        assert_eq!(
+            topological_generations(&graph),
+            Ok(vec![vec!["A"], vec!["B", "C"], vec!["D"], vec!["E"]])
+        );
+
+        graph.insert_edge("E", "B");
+        assert_eq!(topological_generations(&graph), Err(Cycle));
+
+        assert_eq!(
+            topological_generations(&DirectedGraph::<i32>::new()),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn back_edge() {
+        let mut graph = chain(10);
+
+        assert_eq!(first_back_edge(&graph), None);
+
+        graph.insert_edge(7, 3);
+
+        let (from, to) = first_back_edge(&graph).unwrap();
+        assert_eq!((from, to), (7, 3));
+
+        // the edge closes the cycle: `to` leads back around to `from`
+        assert!(all_simple_paths(&graph, &to, &from)
+            .iter()
+            .any(|path| path.len() > 1));
+
+        graph.insert_edge(5, 5);
+        assert_eq!(first_back_edge(&graph), Some((5, 5)));
+    }
+
+    #[test]
+    fn simple_paths() {
+        // diamond: 0 -> {1, 2} -> 3
+        let mut graph = DirectedGraph::new();
+
+        for i in 0..4 {
+            graph.insert_node(i);
+        }
+
+        graph.insert_edge(0, 1);
+        graph.insert_edge(0, 2);
+        graph.insert_edge(1, 3);
+        graph.insert_edge(2, 3);
+
+        assert_eq!(
+            all_simple_paths(&graph, &0, &3),
+            vec![vec![0, 1, 3], vec![0, 2, 3]]
+        );
+        assert_eq!(all_simple_paths(&graph, &3, &0), Vec::<Vec<i32>>::new());
+        assert_eq!(all_simple_paths(&graph, &0, &0), vec![vec![0]]);
+
+        // cycles and shortcuts
+        graph.insert_edge(3, 0);
+        graph.insert_edge(1, 2);
+        graph.insert_edge(0, 3);
+
+        assert_eq!(
+            all_simple_paths(&graph, &0, &3),
+            vec![vec![0, 1, 2, 3], vec![0, 1, 3], vec![0, 2, 3], vec![0, 3]]
+        );
+        assert_eq!(
+            all_simple_paths_max_len(&graph, &0, &3, 2),
+            vec![vec![0, 1, 3], vec![0, 2, 3], vec![0, 3]]
+        );
+        assert_eq!(
+            all_simple_paths_max_len(&graph, &0, &3, 1),
+            vec![vec![0, 3]]
+        );
+        assert_eq!(
+            all_simple_paths_max_len(&graph, &0, &3, 0),
+            Vec::<Vec<i32>>::new()
+        );
+    }
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn ordered() {
+        let mut graph = DirectedGraph::new();
+
+        for node in 1..=8 {
+            graph.insert_node(node);
+        }
+
+        // 8 can't be reached from 1, so it starts a second search
+        for (from, to) in [
+            (1, 3),
+            (1, 2),
+            (2, 5),
+            (2, 4),
+            (3, 6),
+            (4, 7),
+            (5, 7),
+            (6, 2),
+            (8, 6),
+        ] {
+            graph.insert_edge(from, to);
+        }
+
+        for _ in 0..10 {
+            assert_eq!(
+                depth_first_search_ordered(&graph),
+                vec![1, 2, 4, 7, 5, 3, 6, 8]
+            );
+        }
+        assert_eq!(depth_first_search_ordered(&chain(5)), vec![0, 1, 2, 3, 4]);
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
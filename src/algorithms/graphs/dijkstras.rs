@@ -1,8 +1,19 @@
 ///////////////////////////////////////////////////////////////////////////////
 
-use std::collections::{HashMap, HashSet};
-
-use crate::data_structures::graphs::IWeightedGraph;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::{
+    algorithms::graphs::relax,
+    data_structures::{
+        binary_heap::BinaryHeap,
+        graphs::{directed_graph::DirectedGraph, IGraphEdgeMut, IGraphMut, IWeightedGraph},
+    },
+};
 
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -26,59 +37,43 @@ pub fn dijkstras<T: IWeightedGraph>(
     origin: &T::Node,
     target: &T::Node,
 ) -> Option<Vec<T::Node>> {
-    // explained in depth in pseudo-code
-
-    // Maps: Node -> the shortest known distance from origin
-    let mut dist: HashMap<T::Node, T::Weight> = HashMap::new();
+    dijkstras_filtered(graph, origin, target, |_, _, _| true)
+}
 
-    // Maps: Node -> the node immediately before it in the known shortest path
-    let mut prev: HashMap<T::Node, T::Node> = HashMap::new();
+//---------------------------------------------------------------------------//
 
-    // Set of nodes we've already visited
-    let mut known: HashSet<T::Node> = HashSet::new();
+/// Returns a shortest path from `origin` to `target` that only uses edges
+/// accepted by `edge_ok`
+///
+/// Inputs:
+/// - `graph: &T` The graph to search through
+/// - `origin: &T::Node` The node to start from
+/// - `target: &T::Node` The node to try and route to
+/// - `edge_ok: impl Fn(&T::Node, &T::Node, &T::Weight) -> bool` Called with
+///   `(from, to, weight)` for each edge, edges it rejects are ignored
+///
+/// Output:
+/// - If there exists a path of accepted edges from origin to target
+///     - `Some(Vec<T::Node>)` A shortest such path
+/// - Else
+///     - `None` No valid path found
+///
+/// Side-effects: N/A
+///
+pub fn dijkstras_filtered<T: IWeightedGraph>(
+    graph: &T,
+    origin: &T::Node,
+    target: &T::Node,
+    edge_ok: impl Fn(&T::Node, &T::Node, &T::Weight) -> bool,
+) -> Option<Vec<T::Node>> {
+    // explained in depth in pseudo-code
 
-    // It doesn't take any distance to get from origin to origin
-    dist.insert(origin.clone(), 0.into());
+    // Explore the graph until we've found the shortest path to target
+    let (dist, prev) = search(graph, origin, Some(target), edge_ok);
 
-    // Loop until we find the target
-    while !known.contains(target) {
-        // Find the shortest known edge
-        // If it doesn't exist, we've exhausted our graph and will immediately
-        // return.
-        if let Some((node, weight)) = dist.clone().into_iter().min_by_key(|(_, w)| w.clone()) {
-            // Remove the smallest edge
-            dist.remove(&node);
-
-            // Check if we've already visited this edge's endpoint
-            if !known.contains(&node) {
-                // Remember new node
-                known.insert(node.clone());
-
-                // This is very messy, but is just the relax operation
-                // For every adjacent edge
-                // - If it's endpoint is completely new or the previous
-                //   shortest path is longer than our new one
-                //      - Replace the distance with this node's shortest path
-                //          length + the weight of this edge.
-                for (adj, edge_weight) in graph.get_adj_weighted(&node) {
-                    match (dist.get_mut(&adj), prev.get_mut(&adj)) {
-                        (Some(node_weight), Some(adj_pred))
-                            if *node_weight > weight.clone() + edge_weight.clone() =>
-                        {
-                            *node_weight = weight.clone() + edge_weight.clone();
-                            *adj_pred = node.clone();
-                        }
-                        (None, None) if adj != *origin => {
-                            dist.insert(adj.clone(), weight.clone() + edge_weight);
-                            prev.insert(adj, node.clone());
-                        }
-                        _ => {}
-                    }
-                }
-            }
-        } else {
-            return None;
-        }
+    // If we never reached target, there's no path to it
+    if !dist.contains_key(target) {
+        return None;
     }
 
     // messy backtracking code
@@ -96,6 +91,439 @@ pub fn dijkstras<T: IWeightedGraph>(
     Some(res)
 }
 
+//---------------------------------------------------------------------------//
+
+/// A negative edge found by `dijkstras_checked`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeWeight<N, W> {
+    pub from: N,
+    pub to: N,
+    pub weight: W,
+}
+
+/// The result of `dijkstras_checked`
+pub type CheckedPath<N, W> = Result<Option<Vec<N>>, NegativeWeight<N, W>>;
+
+//---------------------------------------------------------------------------//
+
+/// Same as `dijkstras`, but first checks that no edge reachable from
+/// `origin` has a negative weight
+///
+/// Dijkstra's assumes paths never get shorter by adding edges, so negative
+/// weights silently give wrong answers. Graphs with them need an algorithm
+/// like Bellman-Ford instead.
+///
+/// Output:
+/// - If every reachable edge is non-negative
+///     - `Ok(Option<Vec<T::Node>>)` The result of `dijkstras`
+/// - Else
+///     - `Err(NegativeWeight)` The first negative edge found
+///
+/// Side-effects: N/A
+///
+pub fn dijkstras_checked<T: IWeightedGraph>(
+    graph: &T,
+    origin: &T::Node,
+    target: &T::Node,
+) -> CheckedPath<T::Node, T::Weight> {
+    let zero: T::Weight = 0.into();
+
+    let mut seen = HashSet::from([origin.clone()]);
+    let mut stack = vec![origin.clone()];
+
+    while let Some(node) = stack.pop() {
+        for (adj, weight) in graph.get_adj_weighted(&node) {
+            if weight < zero {
+                return Err(NegativeWeight {
+                    from: node,
+                    to: adj,
+                    weight,
+                });
+            }
+
+            if seen.insert(adj.clone()) {
+                stack.push(adj);
+            }
+        }
+    }
+
+    Ok(dijkstras(graph, origin, target))
+}
+
+//---------------------------------------------------------------------------//
+
+/// Distances and predecessors, see `dijkstras_all`
+pub type ShortestPaths<N, W> = (HashMap<N, W>, HashMap<N, N>);
+
+//---------------------------------------------------------------------------//
+
+/// Returns the shortest distances and predecessors of every node reachable
+/// from `origin`
+///
+/// Inputs:
+/// - `graph: &T` The graph to search through
+/// - `origin: &T::Node` The node to start from
+///
+/// Output:
+/// - `HashMap<T::Node, T::Weight>` Maps: Node -> shortest distance from origin
+/// - `HashMap<T::Node, T::Node>` Maps: Node -> the node immediately before it
+///   in a shortest path from origin
+///
+/// Side-effects: N/A
+///
+pub fn dijkstras_all<T: IWeightedGraph>(
+    graph: &T,
+    origin: &T::Node,
+) -> ShortestPaths<T::Node, T::Weight> {
+    search(graph, origin, None, |_, _, _| true)
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the shortest path tree of every node reachable from `origin`
+///
+/// Inputs:
+/// - `graph: &T` The graph to search through
+/// - `origin: &T::Node` The node to start from
+///
+/// Output:
+/// - `DirectedGraph<T::Node>` A tree with an edge from each node's
+///   predecessor to the node itself
+///
+/// Side-effects: N/A
+///
+pub fn dijkstras_tree<T: IWeightedGraph>(graph: &T, origin: &T::Node) -> DirectedGraph<T::Node> {
+    let (dist, prev) = dijkstras_all(graph, origin);
+
+    let mut tree = DirectedGraph::new();
+
+    for node in dist.keys() {
+        tree.insert_node(node.clone());
+    }
+
+    for (node, pred) in prev {
+        tree.insert_edge(pred, node);
+    }
+
+    tree
+}
+
+//---------------------------------------------------------------------------//
+
+/// Runs Dijkstra's algorithm from `origin`, stopping early once `target` has
+/// been visited (if provided) and skipping edges rejected by `edge_ok`
+///
+/// Time complexity: O((V + E) log(V)), since each relaxed edge pushes at
+/// most one entry onto the frontier heap
+fn search<T: IWeightedGraph>(
+    graph: &T,
+    origin: &T::Node,
+    target: Option<&T::Node>,
+    edge_ok: impl Fn(&T::Node, &T::Node, &T::Weight) -> bool,
+) -> ShortestPaths<T::Node, T::Weight> {
+    let mut state = SearchState::new(origin);
+
+    // Loop until we find the target (or forever if we don't have one)
+    while target.is_none_or(|target| !state.known.contains(target)) {
+        // Visit the closest node we haven't visited yet
+        // If there isn't one, we've exhausted our graph and can stop.
+        let Some(node) = state.visit_closest() else {
+            break;
+        };
+
+        // Relax every adjacent edge, and put any endpoint we found a
+        // shorter path to (back) on the frontier
+        for (adj, edge_weight) in graph.get_adj_weighted(&node) {
+            if state.known.contains(&adj) || !edge_ok(&node, &adj, &edge_weight) {
+                continue;
+            }
+
+            state.relax(&node, adj, edge_weight);
+        }
+    }
+
+    (state.dist, state.prev)
+}
+
+//---------------------------------------------------------------------------//
+
+/// The state of one run of Dijkstra's, used by `search` and by each
+/// direction of `bidirectional_dijkstra`
+struct SearchState<N, W>
+where
+    N: Ord + Clone + Default + fmt::Debug,
+    W: Ord + Clone + Default + fmt::Debug,
+{
+    // Maps: Node -> the shortest known distance from the start
+    dist: HashMap<N, W>,
+    // Maps: Node -> the node immediately before it in the known shortest path
+    prev: HashMap<N, N>,
+    // (distance, node), a node is pushed again each time its distance drops
+    // so older entries for it go stale
+    frontier: BinaryHeap<(W, N)>,
+    // Set of nodes we've already visited
+    known: HashSet<N>,
+}
+
+impl<N, W> SearchState<N, W>
+where
+    N: Eq + Hash + Ord + Clone + Default + fmt::Debug,
+    W: Ord + Add<W, Output = W> + Clone + Default + fmt::Debug + From<i32>,
+{
+    fn new(start: &N) -> Self {
+        // It doesn't take any distance to get from start to start
+        let mut frontier = BinaryHeap::new();
+        frontier.insert((0.into(), start.clone()));
+
+        SearchState {
+            dist: HashMap::from([(start.clone(), 0.into())]),
+            prev: HashMap::new(),
+            frontier,
+            known: HashSet::new(),
+        }
+    }
+
+    /// The distance to the unvisited node nearest the start, and the node
+    ///
+    /// Stale entries (for nodes already visited) on top of the frontier are
+    /// thrown away along the way.
+    ///
+    /// Time complexity: O(log(n)) per stale entry thrown away
+    fn closest(&mut self) -> Option<(W, N)> {
+        while let Some((weight, node)) = self.frontier.min() {
+            if !self.known.contains(node) {
+                return Some((weight.clone(), node.clone()));
+            }
+
+            self.frontier.extract_min();
+        }
+
+        None
+    }
+
+    /// Takes the unvisited node nearest the start off the frontier and marks
+    /// it visited, see `closest`
+    fn visit_closest(&mut self) -> Option<N> {
+        let (_, node) = self.closest()?;
+
+        // `closest` already threw away anything stale, so this is it
+        self.frontier.extract_min();
+        self.known.insert(node.clone());
+
+        Some(node)
+    }
+
+    /// Relaxes the edge `from -> to`, putting `to` (back) on the frontier if
+    /// that found a shorter path to it
+    fn relax(&mut self, from: &N, to: N, weight: W) {
+        if relax(&mut self.dist, &mut self.prev, from, to.clone(), weight) {
+            self.frontier.insert((self.dist[&to].clone(), to));
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns a shortest path from `origin` to `target`, searching from both
+/// ends at once
+///
+/// Same result as `dijkstras` (although it may pick a different path when
+/// there's a tie), but the two searches only have to grow until they meet.
+///
+/// The search from `target` has to follow edges backwards, which
+/// `IWeightedGraph` can't do, so this first walks everything reachable from
+/// `origin` to find them. That walk costs as much as a plain search, so to
+/// run many queries on the same graph, build its transpose once and use
+/// `bidirectional_dijkstra_transposed` instead.
+///
+/// Inputs:
+/// - `graph: &T` The graph to search through
+/// - `origin: &T::Node` The node to start from
+/// - `target: &T::Node` The node to try and route to
+///
+/// Output:
+/// - If there exists a path from origin to target in graph
+///     - `Some(Vec<T::Node>)` A shortest path from origin to target
+/// - Else
+///     - `None` No valid path found
+///
+/// Side-effects: N/A
+///
+/// Time complexity: O((V + E) log(V))
+///
+pub fn bidirectional_dijkstra<T: IWeightedGraph>(
+    graph: &T,
+    origin: &T::Node,
+    target: &T::Node,
+) -> Option<Vec<T::Node>> {
+    // a path from origin only goes through nodes reachable from origin, so
+    // those are the only edges we need reversed
+    let mut reversed: HashMap<_, Vec<_>> = HashMap::new();
+
+    let mut seen = HashSet::from([origin.clone()]);
+    let mut stack = vec![origin.clone()];
+
+    while let Some(node) = stack.pop() {
+        for (adj, weight) in graph.get_adj_weighted(&node) {
+            reversed
+                .entry(adj.clone())
+                .or_default()
+                .push((node.clone(), weight));
+
+            if seen.insert(adj.clone()) {
+                stack.push(adj);
+            }
+        }
+    }
+
+    meet_in_middle(
+        graph,
+        |node| reversed.get(node).cloned().unwrap_or_default(),
+        origin,
+        target,
+    )
+}
+
+//---------------------------------------------------------------------------//
+
+/// Same as `bidirectional_dijkstra`, but follows edges backwards through
+/// `transposed` rather than working them out for every query
+///
+/// Build `transposed` once (e.g. with `WeightedGraph::transpose`) and reuse
+/// it for every query, or for an undirected graph just pass the graph
+/// itself. Then only the nodes the two searches reach before meeting get
+/// looked at, which for long paths is far less of the graph.
+///
+/// Inputs:
+/// - `graph: &T` The graph to search through
+/// - `transposed: &R` The same graph with every edge reversed
+/// - `origin: &T::Node` The node to start from
+/// - `target: &T::Node` The node to try and route to
+///
+/// Output:
+/// - Same as `bidirectional_dijkstra`
+///
+/// Side-effects: N/A
+///
+/// Time complexity: O((V + E) log(V)) in the worst case, over just the
+/// nodes the two searches reach before meeting
+///
+pub fn bidirectional_dijkstra_transposed<T, R>(
+    graph: &T,
+    transposed: &R,
+    origin: &T::Node,
+    target: &T::Node,
+) -> Option<Vec<T::Node>>
+where
+    T: IWeightedGraph,
+    R: IWeightedGraph<Node = T::Node, Weight = T::Weight>,
+{
+    meet_in_middle(
+        graph,
+        |node| transposed.get_adj_weighted(node),
+        origin,
+        target,
+    )
+}
+
+//---------------------------------------------------------------------------//
+
+/// Runs the two searches of `bidirectional_dijkstra`, with `backward_adj`
+/// giving the edges that lead into a node
+fn meet_in_middle<T, B>(
+    graph: &T,
+    backward_adj: impl Fn(&T::Node) -> B,
+    origin: &T::Node,
+    target: &T::Node,
+) -> Option<Vec<T::Node>>
+where
+    T: IWeightedGraph,
+    B: IntoIterator<Item = (T::Node, T::Weight)>,
+{
+    /*
+    The forward search runs Dijkstra's from origin over graph, and the
+    backward one from target following edges in reverse. They take turns settling a
+    node. Whenever one of them looks at an edge leading somewhere the other
+    has reached, that gives a complete path, and we remember the shortest
+    one seen.
+
+    Once the two closest frontier nodes together are at least as far as
+    that path, nothing left unexplored could beat it, so we stop.
+    */
+    let mut forward: SearchState<T::Node, T::Weight> = SearchState::new(origin);
+    let mut backward = SearchState::new(target);
+
+    // the shortest path found so far, and where the searches met on it
+    let mut best: Option<(T::Weight, T::Node)> =
+        (origin == target).then(|| (0.into(), origin.clone()));
+
+    let mut forward_turn = true;
+
+    while let (Some(ahead), Some(behind)) = (forward.closest(), backward.closest()) {
+        if best
+            .as_ref()
+            .is_some_and(|(length, _)| ahead.0 + behind.0 >= *length)
+        {
+            break;
+        }
+
+        let (side, other) = if forward_turn {
+            (&mut forward, &backward)
+        } else {
+            (&mut backward, &forward)
+        };
+
+        let Some(node) = side.visit_closest() else {
+            break;
+        };
+
+        let edges: Vec<(T::Node, T::Weight)> = if forward_turn {
+            graph.get_adj_weighted(&node).into_iter().collect()
+        } else {
+            backward_adj(&node).into_iter().collect()
+        };
+
+        for (adj, edge_weight) in edges {
+            if let Some(rest) = other.dist.get(&adj) {
+                let length = side.dist[&node].clone() + edge_weight.clone() + rest.clone();
+
+                if best.as_ref().is_none_or(|(shortest, _)| length < *shortest) {
+                    best = Some((length, adj.clone()));
+                }
+            }
+
+            if side.known.contains(&adj) {
+                continue;
+            }
+
+            side.relax(&node, adj, edge_weight);
+        }
+
+        forward_turn = !forward_turn;
+    }
+
+    let (_, meet) = best?;
+
+    // origin up to the meeting point, then on down to target
+    let mut path = vec![meet.clone()];
+
+    let mut cur = forward.prev.get(&meet);
+    while let Some(curr) = cur {
+        path.push(curr.clone());
+        cur = forward.prev.get(curr);
+    }
+
+    path.reverse();
+
+    let mut cur = backward.prev.get(&meet);
+    while let Some(curr) = cur {
+        path.push(curr.clone());
+        cur = backward.prev.get(curr);
+    }
+
+    Some(path)
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 pub fn dijkstras_explore<T: IWeightedGraph>(
@@ -141,14 +569,22 @@ pub fn dijkstras_explore<T: IWeightedGraph>(
 #[cfg(test)]
 mod tests {
     use crate::{
-        algorithms::graphs::dijkstras::dijkstras,
+        algorithms::graphs::{
+            dfs::depth_first_search,
+            dijkstras::{
+                bidirectional_dijkstra, bidirectional_dijkstra_transposed, dijkstras,
+                dijkstras_checked, dijkstras_filtered, dijkstras_tree, NegativeWeight,
+            },
+            shared_test_cases::{weighted_grid, Counting},
+            validate_path,
+        },
         data_structures::graphs::{
-            weighted_graph::WeightedGraph, IGraphEdgeWeightedMut, IGraphMut,
+            weighted_graph::WeightedGraph, IDefiniteGraph, IGraph, IGraphEdgeWeightedMut, IGraphMut,
         },
+        meta::rng::XorShift,
     };
 
-    #[test]
-    fn test_dijkstras() {
+    fn test_graph() -> WeightedGraph<&'static str, i32> {
         let mut graph = WeightedGraph::new();
         // https://www.youtube.com/watch?v=EFg3u_E6eHU
 
@@ -189,10 +625,205 @@ mod tests {
         graph.insert_edge_weighted("G", "F", 5);
         graph.insert_edge_weighted("G", "B", 2);
 
+        graph
+    }
+
+    #[test]
+    fn test_dijkstras() {
+        let graph = test_graph();
+
         let path = dijkstras(&graph, &"A", &"B");
         println!("{:?}", path);
 
         assert_eq!(path, Some(vec!["A", "C", "E", "B"]));
+        assert_eq!(validate_path(&graph, &path.unwrap()), Some(6));
+
+        // A -> F -> G beats going round through B
+        let path = dijkstras(&graph, &"A", &"G").unwrap();
+        assert_eq!(validate_path(&graph, &path), Some(7));
+    }
+
+    #[test]
+    fn test_dijkstras_tree() {
+        let mut graph = test_graph();
+        graph.insert_node("H");
+
+        let tree = dijkstras_tree(&graph, &"A");
+        println!("{:?}", tree);
+
+        // every reachable node, but not the unreachable H
+        assert_eq!(tree.len(), 7);
+        assert!(!tree.contains(&"H"));
+
+        // every node other than the origin has exactly one predecessor
+        for node in tree.get_all() {
+            let preds = tree
+                .get_all()
+                .into_iter()
+                .filter(|other| tree.get_adj(other).contains(&node))
+                .count();
+
+            assert_eq!(preds, if node == "A" { 0 } else { 1 });
+        }
+
+        let (_, _, cyclic) = depth_first_search(tree.clone());
+        assert!(!cyclic);
+
+        // following the tree gives the same path as dijkstras
+        assert!(tree.get_adj(&"A").contains(&"C"));
+        assert!(tree.get_adj(&"C").contains(&"E"));
+        assert!(tree.get_adj(&"E").contains(&"B"));
+    }
+
+    #[test]
+    fn test_dijkstras_checked() {
+        let mut graph = test_graph();
+
+        assert_eq!(
+            dijkstras_checked(&graph, &"A", &"B"),
+            Ok(Some(vec!["A", "C", "E", "B"]))
+        );
+
+        graph.insert_edge_weighted("F", "D", -1);
+
+        assert_eq!(
+            dijkstras_checked(&graph, &"A", &"B"),
+            Err(NegativeWeight {
+                from: "F",
+                to: "D",
+                weight: -1
+            })
+        );
+    }
+
+    #[test]
+    fn test_dijkstras_filtered() {
+        let graph = test_graph();
+
+        let best = dijkstras(&graph, &"A", &"G").unwrap();
+        let cost = |path: &[&str]| validate_path(&graph, path).unwrap();
+
+        // an always-true filter changes nothing
+        assert_eq!(
+            dijkstras_filtered(&graph, &"A", &"G", |_, _, _| true),
+            Some(best.clone())
+        );
+
+        // close the first edge of the best path
+        let (from, to) = (best[0], best[1]);
+        let detour =
+            dijkstras_filtered(&graph, &"A", &"G", |a, b, _| (*a, *b) != (from, to)).unwrap();
+
+        assert_ne!(detour, best);
+        assert!(!detour
+            .windows(2)
+            .any(|pair| (pair[0], pair[1]) == (from, to)));
+        assert!(cost(&detour) > cost(&best));
+
+        // nothing gets through if every edge is closed
+        assert_eq!(
+            dijkstras_filtered(&graph, &"A", &"G", |_, _, _| false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bidirectional_dijkstra() {
+        let mut graph = test_graph();
+        graph.insert_node("H");
+        let transposed = graph.transpose();
+
+        for origin in ["A", "B", "C", "D", "E", "F", "G"] {
+            for target in ["A", "B", "C", "D", "E", "F", "G"] {
+                let expected = dijkstras(&graph, &origin, &target).unwrap();
+                let paths = [
+                    bidirectional_dijkstra(&graph, &origin, &target).unwrap(),
+                    bidirectional_dijkstra_transposed(&graph, &transposed, &origin, &target)
+                        .unwrap(),
+                ];
+
+                for path in paths {
+                    assert_eq!(path.first(), Some(&origin));
+                    assert_eq!(path.last(), Some(&target));
+                    assert_eq!(
+                        validate_path(&graph, &path),
+                        validate_path(&graph, &expected)
+                    );
+                }
+            }
+        }
+
+        assert_eq!(bidirectional_dijkstra(&graph, &"A", &"H"), None);
+        assert_eq!(
+            bidirectional_dijkstra_transposed(&graph, &transposed, &"A", &"H"),
+            None
+        );
+        assert_eq!(bidirectional_dijkstra(&graph, &"H", &"H"), Some(vec!["H"]));
+        assert_eq!(
+            bidirectional_dijkstra_transposed(&graph, &transposed, &"H", &"H"),
+            Some(vec!["H"])
+        );
+    }
+
+    #[test]
+    fn bidirectional_dijkstra_random() {
+        let mut rng = XorShift::new(29);
+
+        for _ in 0..50 {
+            let mut graph = WeightedGraph::new();
+
+            for node in 0..15 {
+                graph.insert_node(node);
+            }
+
+            for _ in 0..40 {
+                let (from, to) = (rng.next_index(15), rng.next_index(15));
+                graph.insert_edge_weighted(from, to, rng.next_index(10) as i32);
+            }
+
+            let transposed = graph.transpose();
+
+            for (origin, target) in [(0, 14), (3, 7), (5, 5)] {
+                let expected = dijkstras(&graph, &origin, &target);
+                let paths = [
+                    bidirectional_dijkstra(&graph, &origin, &target),
+                    bidirectional_dijkstra_transposed(&graph, &transposed, &origin, &target),
+                ];
+
+                for path in paths {
+                    assert_eq!(path.is_some(), expected.is_some());
+
+                    if let (Some(path), Some(expected)) = (path, expected.as_ref()) {
+                        assert_eq!(
+                            validate_path(&graph, &path),
+                            validate_path(&graph, expected)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bidirectional_dijkstra_explores_less() {
+        // edges go both ways, so the grid is its own transpose
+        let graph = Counting::new(weighted_grid(61));
+        let (origin, target) = ((30, 10), (30, 50));
+
+        let path = dijkstras(&graph, &origin, &target).unwrap();
+        let one_way = graph.calls.replace(0);
+
+        let both_ways =
+            bidirectional_dijkstra_transposed(&graph, &graph, &origin, &target).unwrap();
+        let two_way = graph.calls.get();
+
+        assert_eq!(
+            validate_path(&graph.graph, &both_ways),
+            validate_path(&graph.graph, &path)
+        );
+
+        // each side only grows about half as far
+        assert!(two_way * 3 < one_way * 2, "{two_way} vs {one_way}");
     }
 }
 
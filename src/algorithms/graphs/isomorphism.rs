@@ -0,0 +1,220 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::collections::{HashMap, HashSet};
+
+use crate::data_structures::graphs::IDefiniteGraph;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns whether `a` and `b` have the same structure, ignoring labels
+///
+/// i.e. whether the nodes of `a` can be relabeled so that it has exactly the
+/// edges of `b`. Edges are directed, so an undirected graph only matches
+/// another undirected one.
+///
+/// Only meant for small graphs. It backtracks through possible matchings,
+/// and although only nodes with the same in and out degree are ever paired
+/// up, some graphs (like those where every node has the same degree) still
+/// take exponential time, up to O(V!) in the worst case.
+///
+/// Inputs:
+/// - `a: &T`, `b: &U` The graphs to compare
+///
+/// Outputs:
+/// - `bool` Whether a relabeling exists
+///
+/// Side-effects: N/A
+///
+pub fn is_isomorphic<T: IDefiniteGraph, U: IDefiniteGraph>(a: &T, b: &U) -> bool {
+    let a = Indexed::new(a);
+    let b = Indexed::new(b);
+
+    // quick checks before doing any real work
+    if a.out.len() != b.out.len() || a.edges != b.edges {
+        return false;
+    }
+
+    let mut a_degrees = a.degrees();
+    let mut b_degrees = b.degrees();
+    a_degrees.sort();
+    b_degrees.sort();
+
+    if a_degrees != b_degrees {
+        return false;
+    }
+
+    // match the most connected nodes first, they rule out the most
+    let mut order: Vec<usize> = (0..a.out.len()).collect();
+    order.sort_by_key(|i| std::cmp::Reverse(a.degree(*i)));
+
+    let mut mapping = vec![None; a.out.len()];
+    let mut used = vec![false; b.out.len()];
+
+    extend(&a, &b, &order, &mut mapping, &mut used)
+}
+
+//---------------------------------------------------------------------------//
+
+/// Tries every way of matching the next node in `order`, backtracking when
+/// one doesn't work out
+fn extend(
+    a: &Indexed,
+    b: &Indexed,
+    order: &[usize],
+    mapping: &mut Vec<Option<usize>>,
+    used: &mut Vec<bool>,
+) -> bool {
+    let Some((&node, rest)) = order.split_first() else {
+        return true;
+    };
+
+    for candidate in 0..b.out.len() {
+        if used[candidate] || a.degree(node) != b.degree(candidate) {
+            continue;
+        }
+
+        // every edge between `node` and an already matched node (in either
+        // direction, and to itself) has to be there in `b` too, and the
+        // other way around
+        let consistent = std::iter::once((node, candidate))
+            .chain(
+                mapping
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(other, image)| image.map(|image| (other, image))),
+            )
+            .all(|(other, image)| {
+                a.out[node].contains(&other) == b.out[candidate].contains(&image)
+                    && a.out[other].contains(&node) == b.out[image].contains(&candidate)
+            });
+
+        if !consistent {
+            continue;
+        }
+
+        mapping[node] = Some(candidate);
+        used[candidate] = true;
+
+        if extend(a, b, rest, mapping, used) {
+            return true;
+        }
+
+        mapping[node] = None;
+        used[candidate] = false;
+    }
+
+    false
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// A graph with its nodes swapped for indices, so graphs with different
+/// node types can be compared
+struct Indexed {
+    out: Vec<HashSet<usize>>,
+    in_degree: Vec<usize>,
+    edges: usize,
+}
+
+impl Indexed {
+    fn new<T: IDefiniteGraph>(graph: &T) -> Self {
+        let nodes = graph.get_all();
+        let index: HashMap<&T::Node, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node, i))
+            .collect();
+
+        let out: Vec<HashSet<usize>> = nodes
+            .iter()
+            .map(|node| {
+                graph
+                    .get_adj(node)
+                    .iter()
+                    .filter_map(|adj| index.get(adj).copied())
+                    .collect()
+            })
+            .collect();
+
+        let mut in_degree = vec![0; nodes.len()];
+        for adj in out.iter().flatten() {
+            in_degree[*adj] += 1;
+        }
+
+        let edges = out.iter().map(HashSet::len).sum();
+
+        Self {
+            out,
+            in_degree,
+            edges,
+        }
+    }
+
+    /// (out degree, in degree)
+    fn degree(&self, node: usize) -> (usize, usize) {
+        (self.out[node].len(), self.in_degree[node])
+    }
+
+    fn degrees(&self) -> Vec<(usize, usize)> {
+        (0..self.out.len()).map(|node| self.degree(node)).collect()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use crate::data_structures::graphs::{
+        directed_graph::DirectedGraph, undirected_graph::UndirectedGraph,
+    };
+
+    use super::is_isomorphic;
+
+    fn undirected<T: Ord + Clone + Default + std::hash::Hash + std::fmt::Debug>(
+        edges: &[(T, T)],
+    ) -> UndirectedGraph<T> {
+        edges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn triangles() {
+        let a = undirected(&[(1, 2), (2, 3), (3, 1)]);
+        let b = undirected(&[("x", "z"), ("z", "y"), ("y", "x")]);
+
+        assert!(is_isomorphic(&a, &b));
+        assert!(is_isomorphic(&b, &a));
+        assert!(is_isomorphic(&a, &a));
+    }
+
+    #[test]
+    fn not_isomorphic() {
+        let triangle = undirected(&[(1, 2), (2, 3), (3, 1)]);
+        let path = undirected(&[("a", "b"), ("b", "c")]);
+
+        assert!(!is_isomorphic(&triangle, &path));
+
+        // same degrees everywhere, but one 6-cycle isn't two triangles
+        let hexagon = undirected(&[(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 1)]);
+        let two_triangles = undirected(&[(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4)]);
+
+        assert!(!is_isomorphic(&hexagon, &two_triangles));
+        assert!(is_isomorphic(
+            &hexagon,
+            &undirected(&[(6, 4), (4, 2), (2, 5), (5, 3), (3, 1), (1, 6)])
+        ));
+    }
+
+    #[test]
+    fn directed() {
+        // a -> b -> c against a -> b <- c, same undirected shape
+        let chain: DirectedGraph<char> = [('a', 'b'), ('b', 'c')].into_iter().collect();
+        let collider: DirectedGraph<char> = [('a', 'b'), ('c', 'b')].into_iter().collect();
+        let relabeled: DirectedGraph<i32> = [(3, 1), (1, 2)].into_iter().collect();
+
+        assert!(!is_isomorphic(&chain, &collider));
+        assert!(is_isomorphic(&chain, &relabeled));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
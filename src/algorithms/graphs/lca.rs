@@ -0,0 +1,226 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashMap;
+
+use crate::data_structures::{graphs::IDefiniteGraph, union_find::UnionFind};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the lowest common ancestor of each pair of nodes in a rooted tree
+/// (Tarjan's offline LCA)
+///
+/// Answering all the queries together in one pass is much cheaper than
+/// walking up the tree for each one.
+///
+/// Inputs:
+/// - `tree: &T` The tree, with every edge pointing from a parent to its
+///   child
+/// - `root: &T::Node` The root of the tree
+/// - `queries: &[(T::Node, T::Node)]` The pairs to find the ancestors of
+///
+/// Output:
+/// - `Vec<T::Node>` The lowest common ancestor of each pair, in the same
+///   order as `queries` (a node counts as its own ancestor)
+///
+/// Side-effects: N/A
+///
+/// Panics if `tree` isn't a tree rooted at `root` (every node reachable from
+/// `root` along exactly one path), or a query has a node that isn't in it
+///
+/// Time complexity: O((V + Q) α(V)), for Q queries
+///
+pub fn lca_batch<T: IDefiniteGraph>(
+    tree: &T,
+    root: &T::Node,
+    queries: &[(T::Node, T::Node)],
+) -> Vec<T::Node> {
+    /*
+    Go through the tree depth first. Once we've finished a child, we union
+    it into its parent's set, and mark the parent as the ancestor of that
+    whole set. So while we're in the middle of some node u, every finished
+    node v sits in the set of the deepest node on u's path up to the root
+    that also leads down to v. That's their lowest common ancestor.
+
+    So when we finish u, each query (u, v) whose v is already finished is
+    answered by the ancestor of v's set. If v isn't finished yet, the query
+    gets answered when v is.
+    */
+    assert!(tree.contains(root), "the root {:?} isn't in the tree", root);
+
+    let mut nodes = tree.get_all();
+    nodes.sort();
+
+    let index: HashMap<&T::Node, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node, i))
+        .collect();
+
+    let position = |node: &T::Node| {
+        *index
+            .get(node)
+            .unwrap_or_else(|| panic!("{:?} isn't in the tree", node))
+    };
+
+    // the queries involving each node, by query number
+    let mut pending: Vec<Vec<(usize, usize)>> = vec![vec![]; nodes.len()];
+
+    for (i, (a, b)) in queries.iter().enumerate() {
+        let (a, b) = (position(a), position(b));
+
+        pending[a].push((i, b));
+        pending[b].push((i, a));
+    }
+
+    let mut sets = UnionFind::new(nodes.len());
+    let mut ancestor: Vec<usize> = (0..nodes.len()).collect();
+    let mut visited = vec![false; nodes.len()];
+    let mut finished = vec![false; nodes.len()];
+    let mut answers: Vec<Option<usize>> = vec![None; queries.len()];
+
+    // an explicit stack rather than recursion, so deep trees don't overflow
+    // each node is kept with the children it has left to visit
+    let root = position(root);
+    let mut stack = vec![(root, children(tree, &nodes[root], position))];
+    visited[root] = true;
+
+    while let Some((node, remaining)) = stack.last_mut() {
+        let node = *node;
+
+        if let Some(child) = remaining.pop() {
+            assert!(
+                !visited[child],
+                "not a tree, {:?} can be reached more than one way",
+                nodes[child]
+            );
+
+            visited[child] = true;
+            stack.push((child, children(tree, &nodes[child], position)));
+            continue;
+        }
+
+        stack.pop();
+        finished[node] = true;
+
+        for &(query, other) in &pending[node] {
+            if finished[other] {
+                answers[query] = Some(ancestor[sets.find(other)]);
+            }
+        }
+
+        if let Some((parent, _)) = stack.last() {
+            sets.union(*parent, node);
+            ancestor[sets.find(*parent)] = *parent;
+        }
+    }
+
+    assert!(
+        visited.iter().all(|seen| *seen),
+        "not a tree, some nodes can't be reached from the root"
+    );
+
+    answers
+        .into_iter()
+        .map(|answer| nodes[answer.expect("every node was finished")].clone())
+        .collect()
+}
+
+//---------------------------------------------------------------------------//
+
+fn children<T: IDefiniteGraph>(
+    tree: &T,
+    node: &T::Node,
+    position: impl Fn(&T::Node) -> usize,
+) -> Vec<usize> {
+    tree.get_adj(node).iter().map(position).collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::data_structures::graphs::{
+        directed_graph::DirectedGraph, undirected_graph::UndirectedGraph, IGraphEdgeMut, IGraphMut,
+    };
+
+    //         A
+    //       / | \
+    //      B  C  D
+    //     / \     \
+    //    E   F     G
+    //       / \
+    //      H   I
+    fn tree() -> DirectedGraph<&'static str> {
+        [
+            ("A", "B"),
+            ("A", "C"),
+            ("A", "D"),
+            ("B", "E"),
+            ("B", "F"),
+            ("D", "G"),
+            ("F", "H"),
+            ("F", "I"),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_lca_batch() {
+        let queries = [
+            ("H", "I"),
+            ("E", "H"),
+            ("H", "E"),
+            ("I", "G"),
+            ("C", "D"),
+            ("F", "I"),
+            ("B", "B"),
+            ("A", "H"),
+            ("E", "F"),
+        ];
+
+        assert_eq!(
+            lca_batch(&tree(), &"A", &queries),
+            vec!["F", "B", "B", "A", "A", "F", "B", "A", "B"]
+        );
+
+        assert_eq!(lca_batch(&tree(), &"A", &[]), Vec::<&str>::new());
+
+        let mut single = DirectedGraph::new();
+        single.insert_node(1);
+        assert_eq!(lca_batch(&single, &1, &[(1, 1)]), vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "more than one way")]
+    fn two_parents() {
+        let mut graph = tree();
+        graph.insert_edge("C", "F");
+
+        lca_batch(&graph, &"A", &[("H", "I")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be reached")]
+    fn wrong_root() {
+        lca_batch(&tree(), &"B", &[("H", "I")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "more than one way")]
+    fn undirected() {
+        let graph: UndirectedGraph<i32> = [(1, 2), (1, 3)].into_iter().collect();
+
+        lca_batch(&graph, &1, &[(2, 3)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "isn't in the tree")]
+    fn missing_node() {
+        lca_batch(&tree(), &"A", &[("H", "Z")]);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,361 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+
+use crate::{
+    data_structures::graphs::{
+        undirected_graph::UndirectedGraph, IDefiniteGraph, IGraph, IWeightedGraph,
+    },
+    data_structures::union_find::UnionFind,
+    meta::rng::XorShift,
+};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns a global minimum cut of an undirected weighted graph
+/// (Stoer-Wagner)
+///
+/// The crate has no undirected weighted graph, so `graph` is read as one:
+/// the weight between two nodes is the larger of the total weight of edges
+/// going each way between them. That way it doesn't matter whether each
+/// undirected edge is stored in one direction or both.
+///
+/// Inputs:
+/// - `graph: &T` The graph to cut, weights should be non-negative
+///
+/// Outputs:
+/// - `(Vec<T::Node>, i32)` One side of the cut (sorted, the other side is
+///   every other node) and the total weight of the edges crossing it.
+///   Disconnected graphs have a cut of weight 0. Graphs with fewer than two
+///   nodes can't be cut, and give an empty side with weight 0.
+///
+/// Side-effects: N/A
+///
+/// Time complexity: O(V^3)
+///
+pub fn min_cut<T: IWeightedGraph<Weight = i32> + IDefiniteGraph>(graph: &T) -> (Vec<T::Node>, i32) {
+    /*
+    Stoer-Wagner ---
+
+        Each phase grows a set one node at a time, always adding the node
+        most tightly connected to the set so far (maximum adjacency order).
+        Call the last two nodes added s and t.
+        The cut separating t from everything else is a minimum s-t cut, so
+        it's a candidate for the global minimum.
+        Either that's the answer, or s and t are on the same side of the
+        global minimum cut, so we can merge them into one node and carry on.
+        After V - 1 phases everything has been merged, and the best candidate
+        we saw is the answer.
+
+    */
+
+    let mut nodes = graph.get_all();
+    nodes.sort();
+
+    let n = nodes.len();
+
+    if n < 2 {
+        return (vec![], 0);
+    }
+
+    let index: HashMap<&T::Node, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node, i))
+        .collect();
+
+    // total weight of the edges going from i to j
+    let mut directed: HashMap<(usize, usize), i32> = HashMap::new();
+
+    for (i, node) in nodes.iter().enumerate() {
+        for (adj, weight) in graph.get_adj_weighted(node) {
+            let j = index[&adj];
+            if i != j {
+                *directed.entry((i, j)).or_insert(0) += weight;
+            }
+        }
+    }
+
+    let mut weights = vec![vec![0; n]; n];
+
+    for ((i, j), weight) in directed {
+        let weight = weight.max(weights[i][j]);
+        weights[i][j] = weight;
+        weights[j][i] = weight;
+    }
+
+    // the original nodes merged into each node
+    let mut merged: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+    let mut alive: Vec<usize> = (0..n).collect();
+
+    let mut best: Option<(Vec<usize>, i32)> = None;
+
+    while alive.len() > 1 {
+        // how strongly each node is connected to the set built so far
+        let mut connection = vec![0; n];
+        let mut added = vec![false; n];
+
+        let mut prev = alive[0];
+        let mut last = alive[0];
+
+        for _ in 0..alive.len() {
+            // the most tightly connected node not yet in the set, ties go
+            // to the first one
+            let next = *alive
+                .iter()
+                .filter(|i| !added[**i])
+                .max_by_key(|i| (connection[**i], std::cmp::Reverse(**i)))
+                .expect("one node is added per round");
+
+            added[next] = true;
+            prev = last;
+            last = next;
+
+            for &i in &alive {
+                if !added[i] {
+                    connection[i] += weights[next][i];
+                }
+            }
+        }
+
+        // the cut of the phase: `last` on its own against the rest
+        let cut = connection[last];
+        if best.as_ref().is_none_or(|(_, weight)| cut < *weight) {
+            best = Some((merged[last].clone(), cut));
+        }
+
+        // merge `last` into `prev`
+        let taken = std::mem::take(&mut merged[last]);
+        merged[prev].extend(taken);
+
+        for &i in &alive {
+            weights[prev][i] += weights[last][i];
+            weights[i][prev] = weights[prev][i];
+        }
+        weights[prev][prev] = 0;
+
+        alive.retain(|i| *i != last);
+    }
+
+    let (side, weight) = best.expect("there were at least two nodes");
+
+    let mut side: Vec<T::Node> = side.into_iter().map(|i| nodes[i].clone()).collect();
+    side.sort();
+
+    (side, weight)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the size of a minimum cut of `graph` (Karger's algorithm)
+///
+/// Each iteration contracts random edges until only two super-nodes are
+/// left, and the edges still between them are a cut. That cut is only the
+/// minimum with probability at least 2 / (V * (V - 1)), so this is a Monte
+/// Carlo algorithm: the answer can be too big, but never too small, and more
+/// iterations make a wrong answer less likely. Around V^2 * ln(V) iterations
+/// make failing very unlikely.
+///
+/// Inputs:
+/// - `graph: &UndirectedGraph<T>` The graph to cut, self-loops are ignored
+/// - `seed: u64` The seed for picking edges
+/// - `iterations: usize` How many times to try
+///
+/// Outputs:
+/// - `usize` The fewest edges crossing any cut found. Disconnected graphs
+///   give 0. Graphs with fewer than two nodes (or no iterations) give 0 as
+///   well, since nothing was cut.
+///
+/// Side-effects: N/A
+///
+/// Time complexity: O(iterations * E log(V))
+///
+pub fn karger_min_cut<T>(graph: &UndirectedGraph<T>, seed: u64, iterations: usize) -> usize
+where
+    T: Ord + Debug + Hash + Clone + Default,
+{
+    /*
+    `UndirectedGraph::contract_edge` can't be used directly here: contracting
+    merges parallel edges into one, and Karger's relies on them.
+    A node with three edges to the other side should be three times as likely
+    to be merged with it, and count three times towards the cut.
+
+    Contracting edges in a random order is the same as shuffling the list of
+    edges and going through it, skipping any that have become self-loops
+    (both ends already merged). So we keep the original edges around and
+    track which super-node every node has been merged into with a
+    union-find instead.
+    */
+
+    let mut nodes = graph.get_all();
+    nodes.sort();
+
+    let n = nodes.len();
+
+    if n < 2 {
+        return 0;
+    }
+
+    let index: HashMap<&T, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node, i))
+        .collect();
+
+    // every edge once, sorted so only the seed decides the result
+    let mut edges: Vec<(usize, usize)> = nodes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, node)| {
+            graph
+                .get_adj(node)
+                .into_iter()
+                .map(|adj| index[&adj])
+                .filter(move |j| i < *j)
+                .map(move |j| (i, j))
+        })
+        .collect();
+    edges.sort();
+
+    let mut rng = XorShift::new(seed);
+    let mut best: Option<usize> = None;
+
+    for _ in 0..iterations {
+        rng.shuffle(&mut edges);
+
+        let mut super_nodes = UnionFind::new(n);
+
+        for &(a, b) in &edges {
+            if super_nodes.sets() == 2 {
+                break;
+            }
+
+            super_nodes.union(a, b);
+        }
+
+        // if there are still more than two super-nodes, the graph is
+        // disconnected and nothing crosses between them
+        let cut = edges
+            .iter()
+            .filter(|&&(a, b)| !super_nodes.same(a, b))
+            .count();
+
+        best = Some(best.map_or(cut, |best| best.min(cut)));
+    }
+
+    best.unwrap_or(0)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashSet;
+
+    use crate::data_structures::graphs::{
+        undirected_graph::UndirectedGraph, weighted_graph::WeightedGraph, IGraphMut,
+    };
+
+    use super::{karger_min_cut, min_cut};
+
+    // the example from Stoer and Wagner's paper
+    fn paper() -> Vec<(i32, i32, i32)> {
+        vec![
+            (1, 2, 2),
+            (1, 5, 3),
+            (2, 3, 3),
+            (2, 5, 2),
+            (2, 6, 2),
+            (3, 4, 4),
+            (3, 7, 2),
+            (4, 7, 2),
+            (4, 8, 2),
+            (5, 6, 3),
+            (6, 7, 1),
+            (7, 8, 3),
+        ]
+    }
+
+    fn crossing(edges: &[(i32, i32, i32)], side: &HashSet<i32>) -> i32 {
+        edges
+            .iter()
+            .filter(|(from, to, _)| side.contains(from) != side.contains(to))
+            .map(|(_, _, weight)| weight)
+            .sum()
+    }
+
+    #[test]
+    fn known_cut() {
+        let edges = paper();
+
+        // stored one way and both ways should give the same answer
+        let one_way: WeightedGraph<i32, i32> = edges.iter().cloned().collect();
+        let both_ways: WeightedGraph<i32, i32> = edges
+            .iter()
+            .flat_map(|&(from, to, weight)| [(from, to, weight), (to, from, weight)])
+            .collect();
+
+        for graph in [one_way, both_ways] {
+            let (side, weight) = min_cut(&graph);
+
+            assert_eq!(weight, 4);
+            assert!(!side.is_empty() && side.len() < 8);
+
+            let side: HashSet<i32> = side.into_iter().collect();
+            assert_eq!(crossing(&edges, &side), 4);
+        }
+    }
+
+    #[test]
+    fn small_graphs() {
+        let mut graph: WeightedGraph<i32, i32> = WeightedGraph::new();
+        assert_eq!(min_cut(&graph), (vec![], 0));
+
+        graph.insert_node(1);
+        assert_eq!(min_cut(&graph), (vec![], 0));
+
+        // two separate pieces
+        let graph: WeightedGraph<i32, i32> = [(1, 2, 5), (3, 4, 5)].into_iter().collect();
+        let (side, weight) = min_cut(&graph);
+
+        assert_eq!(weight, 0);
+        assert!(!side.is_empty() && side.len() < 4);
+    }
+
+    #[test]
+    fn karger() {
+        // two 5-cliques joined by two edges
+        let mut graph: UndirectedGraph<i32> = UndirectedGraph::new();
+
+        for side in [0, 5] {
+            for i in side..side + 5 {
+                for j in i + 1..side + 5 {
+                    graph.extend([(i, j)]);
+                }
+            }
+        }
+        graph.extend([(0, 5), (4, 9)]);
+
+        assert_eq!(karger_min_cut(&graph, 9, 200), 2);
+
+        // a single try can be unlucky, but never finds a smaller cut
+        for seed in 0..20 {
+            assert!(karger_min_cut(&graph, seed, 1) >= 2);
+        }
+
+        // cutting off one node of a cycle takes two edges
+        let cycle: UndirectedGraph<i32> = (0..8).map(|i| (i, (i + 1) % 8)).collect();
+        assert_eq!(karger_min_cut(&cycle, 1, 100), 2);
+
+        // nothing to cut
+        let mut split: UndirectedGraph<i32> = [(1, 2), (3, 4)].into_iter().collect();
+        assert_eq!(karger_min_cut(&split, 1, 10), 0);
+        split.remove_node(3);
+        split.remove_node(4);
+        split.remove_node(2);
+        assert_eq!(karger_min_cut(&split, 1, 10), 0);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -1,10 +1,164 @@
 ///////////////////////////////////////////////////////////////////////////////
 
+use std::{collections::HashMap, hash::Hash, ops::Add};
+
+use crate::data_structures::graphs::IWeightedGraph;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub mod all_pairs;
 pub mod astar;
+pub mod bellman_ford;
 pub mod bfs;
 pub mod dag_expl;
 pub mod dfs;
 pub mod dijkstras;
+pub mod isomorphism;
+pub mod lca;
+pub mod min_cut;
+pub mod path_index;
 pub mod prims;
+pub mod random_tree;
+pub mod tsp;
+pub mod yens;
+
+#[cfg(test)]
+mod shared_test_cases;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Relaxes the edge `from -> to`
+///
+/// If going through `from` gives a shorter path to `to` than the best one
+/// known so far (or `to` hasn't been reached yet), records the new distance
+/// in `dist` and makes `from` the predecessor of `to` in `prev`.
+///
+/// Inputs:
+/// - `dist: &mut HashMap<N, W>` The shortest known distance to each node
+/// - `prev: &mut HashMap<N, N>` The node before each node on its shortest
+///   known path
+/// - `from: &N` The start of the edge
+/// - `to: N` The end of the edge
+/// - `weight: W` The weight of the edge
+///
+/// Outputs:
+/// - `bool` Whether the distance to `to` was updated
+///   (always `false` if `from` hasn't been reached yet)
+///
+/// Side-effects: Updates `dist` and `prev` for `to`
+///
+pub fn relax<N, W>(
+    dist: &mut HashMap<N, W>,
+    prev: &mut HashMap<N, N>,
+    from: &N,
+    to: N,
+    weight: W,
+) -> bool
+where
+    N: Eq + Hash + Clone,
+    W: Ord + Add<W, Output = W> + Clone,
+{
+    let Some(from_weight) = dist.get(from) else {
+        return false;
+    };
+
+    let new_weight = from_weight.clone() + weight;
+
+    match dist.get(&to) {
+        Some(old_weight) if *old_weight <= new_weight => false,
+        _ => {
+            dist.insert(to.clone(), new_weight);
+            prev.insert(to, from.clone());
+            true
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the total weight of `path`, if it's a real path through `graph`
+///
+/// Mostly useful for checking the output of the path finding algorithms.
+/// Where there are parallel edges, the lightest one is used. A path with a
+/// single node (or none at all) weighs zero.
+///
+/// Inputs:
+/// - `graph: &T` The graph the path should go through
+/// - `path: &[T::Node]` The nodes along the path, in order
+///
+/// Outputs:
+/// - `Some(T::Weight)` The total weight, if every consecutive pair of nodes
+///   is joined by an edge
+/// - `None` Otherwise
+///
+/// Side-effects: N/A
+///
+pub fn validate_path<T: IWeightedGraph>(graph: &T, path: &[T::Node]) -> Option<T::Weight> {
+    path.windows(2).try_fold(T::Weight::from(0), |total, pair| {
+        let weight = graph
+            .get_adj_weighted(&pair[0])
+            .into_iter()
+            .filter(|(node, _)| *node == pair[1])
+            .map(|(_, weight)| weight)
+            .min()?;
+
+        Some(total + weight)
+    })
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashMap;
+
+    use crate::data_structures::graphs::weighted_graph::WeightedGraph;
+
+    use super::{relax, validate_path};
+
+    #[test]
+    fn test_relax() {
+        let mut dist = HashMap::from([("A", 0), ("B", 5)]);
+        let mut prev = HashMap::new();
+
+        // new node
+        assert!(relax(&mut dist, &mut prev, &"A", "C", 7));
+        assert_eq!(dist["C"], 7);
+        assert_eq!(prev["C"], "A");
+
+        // shorter
+        assert!(relax(&mut dist, &mut prev, &"B", "C", 1));
+        assert_eq!(dist["C"], 6);
+        assert_eq!(prev["C"], "B");
+
+        // not shorter (ties keep the old path)
+        assert!(!relax(&mut dist, &mut prev, &"A", "C", 6));
+        assert!(!relax(&mut dist, &mut prev, &"A", "C", 10));
+        assert_eq!(dist["C"], 6);
+        assert_eq!(prev["C"], "B");
+
+        // unreached start
+        assert!(!relax(&mut dist, &mut prev, &"Z", "C", 0));
+        assert_eq!(dist.len(), 3);
+    }
+
+    #[test]
+    fn test_validate_path() {
+        let graph: WeightedGraph<&str, i32> =
+            [("A", "B", 2), ("B", "C", 3), ("B", "C", 1), ("C", "A", 4)]
+                .into_iter()
+                .collect();
+
+        assert_eq!(validate_path(&graph, &["A", "B", "C", "A"]), Some(7));
+        assert_eq!(validate_path(&graph, &["A"]), Some(0));
+        assert_eq!(validate_path(&graph, &[]), Some(0));
+
+        // missing edges, in either the middle or the wrong direction
+        assert_eq!(validate_path(&graph, &["A", "C"]), None);
+        assert_eq!(validate_path(&graph, &["A", "B", "A"]), None);
+        assert_eq!(validate_path(&graph, &["A", "B", "Z"]), None);
+    }
+}
 
 ///////////////////////////////////////////////////////////////////////////////
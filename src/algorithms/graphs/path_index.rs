@@ -0,0 +1,177 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashMap;
+
+use crate::{
+    algorithms::graphs::dijkstras::{dijkstras_all, ShortestPaths},
+    data_structures::graphs::IWeightedGraph,
+};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Answers repeated shortest path queries on a graph that doesn't change
+///
+/// The first query from an origin runs `dijkstras_all` from it, which finds
+/// the shortest path to every node at once. That's kept, so any later query
+/// from the same origin is just a walk back through the predecessors.
+///
+/// The graph is borrowed for as long as the index is around, so it can't
+/// be changed out from under the cache.
+pub struct ShortestPathIndex<'a, T: IWeightedGraph> {
+    graph: &'a T,
+    cache: HashMap<T::Node, ShortestPaths<T::Node, T::Weight>>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<'a, T: IWeightedGraph> ShortestPathIndex<'a, T> {
+    //-----------------------------------------------------------------------//
+
+    /// Creates an empty index over `graph`, nothing is searched until the
+    /// first query
+    pub fn new(graph: &'a T) -> Self {
+        ShortestPathIndex {
+            graph,
+            cache: HashMap::new(),
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns a shortest path from `origin` to `target` if it exists
+    ///
+    /// Inputs:
+    /// - `origin: &T::Node` The node to start from
+    /// - `target: &T::Node` The node to try and route to
+    ///
+    /// Output:
+    /// - If there exists a path from origin to target in graph
+    ///     - `Some(Vec<T::Node>)` A shortest path from origin to target
+    /// - Else
+    ///     - `None` No valid path found
+    ///
+    /// Side-effects: Searches from `origin` and caches the result, if it
+    /// hasn't been queried before
+    ///
+    pub fn path(&mut self, origin: &T::Node, target: &T::Node) -> Option<Vec<T::Node>> {
+        let (dist, prev) = self.search(origin);
+
+        if !dist.contains_key(target) {
+            return None;
+        }
+
+        let mut res = vec![target.clone()];
+
+        let mut cur = prev.get(target);
+        while let Some(curr) = cur {
+            res.push(curr.clone());
+            cur = prev.get(curr);
+        }
+
+        res.reverse();
+
+        Some(res)
+    }
+
+    //.......................................................................//
+
+    /// Returns the length of a shortest path from `origin` to `target`, if
+    /// there is one
+    ///
+    /// Side-effects: Same as `path`
+    ///
+    pub fn distance(&mut self, origin: &T::Node, target: &T::Node) -> Option<T::Weight> {
+        let (dist, _) = self.search(origin);
+
+        dist.get(target).cloned()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Whether queries from `origin` are already cached
+    pub fn is_cached(&self, origin: &T::Node) -> bool {
+        self.cache.contains_key(origin)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn search(&mut self, origin: &T::Node) -> &ShortestPaths<T::Node, T::Weight> {
+        let graph = self.graph;
+
+        self.cache
+            .entry(origin.clone())
+            .or_insert_with(|| dijkstras_all(graph, origin))
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        algorithms::graphs::{dijkstras::dijkstras, shared_test_cases::Counting, validate_path},
+        data_structures::graphs::{
+            weighted_graph::WeightedGraph, IGraphEdgeWeightedMut, IGraphMut,
+        },
+    };
+
+    #[test]
+    fn cached() {
+        let mut graph = WeightedGraph::new();
+
+        for node in 0..6 {
+            graph.insert_node(node);
+        }
+
+        graph.insert_edge_weighted(0, 1, 4);
+        graph.insert_edge_weighted(0, 2, 1);
+        graph.insert_edge_weighted(2, 1, 2);
+        graph.insert_edge_weighted(1, 3, 5);
+        graph.insert_edge_weighted(2, 3, 8);
+        graph.insert_edge_weighted(3, 4, 1);
+
+        let graph = Counting::new(graph);
+        let mut index = ShortestPathIndex::new(&graph);
+
+        assert!(!index.is_cached(&0));
+        assert_eq!(graph.calls.get(), 0);
+
+        assert_eq!(index.path(&0, &4), Some(vec![0, 2, 1, 3, 4]));
+        assert!(index.is_cached(&0));
+
+        let calls = graph.calls.get();
+        assert!(calls > 0);
+
+        // every later query from 0 comes straight out of the cache
+        assert_eq!(index.path(&0, &4), Some(vec![0, 2, 1, 3, 4]));
+        assert_eq!(index.path(&0, &1), Some(vec![0, 2, 1]));
+        assert_eq!(index.distance(&0, &3), Some(8));
+        assert_eq!(index.path(&0, &5), None);
+        assert_eq!(index.path(&0, &0), Some(vec![0]));
+        assert_eq!(graph.calls.get(), calls);
+
+        // a new origin needs a new search
+        assert_eq!(index.distance(&1, &4), Some(6));
+        assert!(graph.calls.get() > calls);
+        assert_eq!(index.path(&4, &0), None);
+
+        // and agrees with searching from scratch
+        for (origin, target) in [(0, 3), (2, 4), (1, 4)] {
+            let path = index.path(&origin, &target).unwrap();
+
+            assert_eq!(
+                validate_path(&graph.graph, &path),
+                validate_path(
+                    &graph.graph,
+                    &dijkstras(&graph.graph, &origin, &target).unwrap()
+                )
+            );
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -61,14 +61,16 @@ pub fn prims<T: IWeightedGraph>(
 #[cfg(test)]
 mod tests {
 
-    use crate::data_structures::graphs::{
-        self, weighted_graph::WeightedGraph, IGraphEdgeWeightedMut, IGraphMut,
+    use crate::{
+        algorithms::graphs::validate_path,
+        data_structures::graphs::{
+            self, weighted_graph::WeightedGraph, IDefiniteGraph, IGraphEdgeWeightedMut, IGraphMut,
+        },
     };
 
     use super::prims;
 
-    #[test]
-    fn test_prims() {
+    fn graph() -> WeightedGraph<&'static str, i32> {
         let mut graph = WeightedGraph::new();
         // https://www.youtube.com/watch?v=cplfcGZmX7I
 
@@ -105,6 +107,13 @@ mod tests {
         graph.insert_edge_weighted("F", "E", 8);
         graph.insert_edge_weighted("F", "G", 9);
 
+        graph
+    }
+
+    #[test]
+    fn test_prims() {
+        let graph = graph();
+
         graphs::fmt(graph.clone());
 
         println!("prims");
@@ -114,6 +123,37 @@ mod tests {
         println!("{:?}\nTotal weight: {:?}", min_spanning_graph, total);
 
         assert_eq!(total, 24);
+
+        // a spanning tree: every node, one edge less, and each edge (at the
+        // weight the tree gives it) really is in the graph
+        let edges: Vec<_> = min_spanning_graph.weighted_edges().collect();
+        assert_eq!(min_spanning_graph.len(), graph.len());
+        assert_eq!(edges.len(), graph.len() - 1);
+
+        for (from, to, weight) in edges {
+            assert_eq!(validate_path(&graph, &[from, to]), Some(weight));
+        }
+    }
+
+    #[test]
+    fn weight_metrics() {
+        let mut graph = graph();
+
+        // every undirected edge is stored both ways, apart from F -> G
+        assert_eq!(graph.total_weight(), 83);
+        assert_eq!(graph.weighted_out_degree(&"F"), 30);
+        assert_eq!(graph.weighted_out_degree(&"G"), 0);
+        assert_eq!(graph.weighted_out_degree(&"Z"), 0);
+
+        assert_eq!(graph.max_weight_edge(), Some(("F", "G", 9)));
+
+        // E -> F and F -> E tie, the smaller pair wins
+        graph.insert_edge_weighted("E", "F", 9);
+        graph.insert_edge_weighted("F", "E", 9);
+        assert_eq!(graph.max_weight_edge(), Some(("E", "F", 9)));
+
+        assert_eq!(WeightedGraph::<i32, i32>::new().max_weight_edge(), None);
+        assert_eq!(WeightedGraph::<i32, i32>::new().total_weight(), 0);
     }
 }
 
@@ -0,0 +1,165 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashSet;
+
+use crate::{
+    data_structures::graphs::{
+        directed_graph::DirectedGraph, IDefiniteGraph, IGraphEdgeMut, IGraphMut,
+    },
+    meta::rng::XorShift,
+};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns a random spanning tree of `graph`, with edges pointing away from
+/// the root
+///
+/// Runs a depth first search from a random root, going through each node's
+/// neighbors in a random order. The same seed always gives the same tree.
+///
+/// For a connected (undirected) graph the tree covers every node, otherwise
+/// only the nodes reachable from the root are included. Trees from a search
+/// like this lean towards long paths, so they aren't spread evenly over every
+/// possible spanning tree, but it's enough for generating test cases.
+///
+/// Inputs:
+/// - `graph: &T` The graph to pick edges from
+/// - `seed: u64` The seed for the random choices
+///
+/// Output:
+/// - `DirectedGraph<T::Node>` The tree (empty if the graph is)
+///
+/// Side-effects: N/A
+///
+pub fn random_spanning_tree<T: IDefiniteGraph>(graph: &T, seed: u64) -> DirectedGraph<T::Node> {
+    let mut rng = XorShift::new(seed);
+    let mut tree = DirectedGraph::new();
+
+    // sorted so the seed is the only thing deciding the result
+    let mut nodes = graph.get_all();
+    nodes.sort();
+
+    if nodes.is_empty() {
+        return tree;
+    }
+
+    let root = nodes.swap_remove(rng.next_index(nodes.len()));
+
+    let mut visited = HashSet::new();
+    // (node, the node we found it from)
+    let mut stack = vec![(root, None)];
+
+    while let Some((node, parent)) = stack.pop() {
+        // a node can be pushed once for every edge leading to it, the first
+        // one popped wins
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+
+        tree.insert_node(node.clone());
+        if let Some(parent) = parent {
+            tree.insert_edge(parent, node.clone());
+        }
+
+        let mut adj: Vec<T::Node> = graph
+            .get_adj(&node)
+            .into_iter()
+            .filter(|adj| !visited.contains(adj))
+            .collect();
+        adj.sort();
+        rng.shuffle(&mut adj);
+
+        stack.extend(adj.into_iter().map(|adj| (adj, Some(node.clone()))));
+    }
+
+    tree
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashSet;
+
+    use crate::{
+        algorithms::graphs::{dfs::depth_first_search, shared_test_cases::grid},
+        data_structures::graphs::{
+            directed_graph::DirectedGraph, IDefiniteGraph, IGraph, IGraphEdgeMut, IGraphMut,
+        },
+    };
+
+    use super::random_spanning_tree;
+
+    fn edges<T: IDefiniteGraph>(graph: &T) -> Vec<(T::Node, T::Node)> {
+        let mut edges: Vec<_> = graph
+            .get_all()
+            .into_iter()
+            .flat_map(|from| {
+                graph
+                    .get_adj(&from)
+                    .into_iter()
+                    .map(move |to| (from.clone(), to))
+            })
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn spanning_tree() {
+        let graph = grid(6);
+        let mut trees = HashSet::new();
+
+        for seed in 0..20 {
+            let tree = random_spanning_tree(&graph, seed);
+            let tree_edges = edges(&tree);
+
+            // every node, one edge less, all taken from the graph
+            assert_eq!(tree.len(), graph.len());
+            assert_eq!(tree_edges.len(), graph.len() - 1);
+            assert!(tree_edges
+                .iter()
+                .all(|(from, to)| graph.get_adj(from).contains(to)));
+
+            // no cycles and nothing with two parents, so it's a tree
+            let (roots, _, cyclic) = depth_first_search(tree.clone());
+            assert!(!cyclic);
+            assert_eq!(roots.len(), 1);
+
+            assert_eq!(edges(&random_spanning_tree(&graph, seed)), tree_edges);
+            trees.insert(tree_edges);
+        }
+
+        assert!(trees.len() > 1);
+    }
+
+    #[test]
+    fn reachable_only() {
+        // 3 can't be reached from anything, so it's the only valid root that
+        // gives a spanning tree, while other roots leave it out
+        let mut graph = DirectedGraph::new();
+        for node in 0..4 {
+            graph.insert_node(node);
+        }
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (3, 0)] {
+            graph.insert_edge(from, to);
+        }
+
+        for seed in 0..20 {
+            let tree = random_spanning_tree(&graph, seed);
+            let (_, _, cyclic) = depth_first_search(tree.clone());
+
+            assert!(!cyclic);
+            assert_eq!(edges(&tree).len(), tree.len() - 1);
+            assert!(tree.len() == 4 || !tree.contains(&3));
+        }
+
+        assert_eq!(
+            random_spanning_tree(&DirectedGraph::<i32>::new(), 1).len(),
+            0
+        );
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
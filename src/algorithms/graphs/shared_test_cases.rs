@@ -0,0 +1,114 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::{cell::Cell, collections::HashSet, fmt, hash::Hash, rc::Rc};
+
+use crate::data_structures::graphs::{
+    undirected_graph::UndirectedGraph, weighted_graph::WeightedGraph, IGraph, IGraphEdgeMut,
+    IGraphEdgeWeightedMut, IGraphMut, IWeightedGraph,
+};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// A `size` by `size` grid
+pub fn grid(size: i32) -> UndirectedGraph<(i32, i32)> {
+    let mut graph = UndirectedGraph::new();
+
+    for x in 0..size {
+        for y in 0..size {
+            graph.insert_node((x, y));
+        }
+    }
+
+    for x in 0..size {
+        for y in 0..size {
+            if x + 1 < size {
+                graph.insert_edge((x, y), (x + 1, y));
+            }
+            if y + 1 < size {
+                graph.insert_edge((x, y), (x, y + 1));
+            }
+        }
+    }
+
+    graph
+}
+
+//---------------------------------------------------------------------------//
+
+/// The same grid as `grid`, with each edge going both ways at weight 1, so
+/// it's its own transpose
+pub fn weighted_grid(size: i32) -> WeightedGraph<(i32, i32), i32> {
+    let mut graph = WeightedGraph::new();
+
+    for x in 0..size {
+        for y in 0..size {
+            graph.insert_node((x, y));
+        }
+    }
+
+    for x in 0..size {
+        for y in 0..size {
+            for (dx, dy) in [(1, 0), (0, 1)] {
+                if x + dx < size && y + dy < size {
+                    graph.insert_edge_weighted((x, y), (x + dx, y + dy), 1);
+                    graph.insert_edge_weighted((x + dx, y + dy), (x, y), 1);
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// A weighted graph that counts every time a search asks for a node's edges
+#[derive(Clone)]
+pub struct Counting<N>
+where
+    N: Ord + fmt::Debug + Hash + Clone,
+{
+    pub graph: WeightedGraph<N, i32>,
+    pub calls: Rc<Cell<usize>>,
+}
+
+impl<N> Counting<N>
+where
+    N: Ord + fmt::Debug + Hash + Clone,
+{
+    pub fn new(graph: WeightedGraph<N, i32>) -> Self {
+        Counting {
+            graph,
+            calls: Rc::new(Cell::new(0)),
+        }
+    }
+}
+
+impl<N> IGraph for Counting<N>
+where
+    N: Ord + fmt::Debug + Hash + Clone + Default,
+{
+    type Node = N;
+
+    fn get_adj(&self, node: &N) -> HashSet<N> {
+        self.graph.get_adj(node)
+    }
+
+    fn contains(&self, item: &N) -> bool {
+        self.graph.contains(item)
+    }
+}
+
+impl<N> IWeightedGraph for Counting<N>
+where
+    N: Ord + fmt::Debug + Hash + Clone + Default,
+{
+    type Weight = i32;
+
+    fn get_adj_weighted(&self, node: &N) -> HashSet<(N, i32)> {
+        self.calls.set(self.calls.get() + 1);
+        self.graph.get_adj_weighted(node)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,159 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashSet;
+
+use crate::data_structures::graphs::{IDefiniteGraph, IWeightedGraph};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns a tour of the graph built by always walking to the nearest
+/// unvisited node, and then back to the start.
+///
+/// This is a greedy heuristic, the tour is usually decent but not the
+/// shortest possible one.
+///
+/// Inputs:
+/// - `graph: &T` The graph to tour
+/// - `start: &T::Node` The node to start and end the tour at
+///
+/// Outputs:
+/// - `None` if `start` isn't in the graph, or the walk gets stuck before
+///   visiting every node or can't get back to `start`
+/// - `Some((Vec<T::Node>, T::Weight))` Otherwise, the tour and its total
+///   weight. The tour begins and ends with `start`, every other node
+///   appears exactly once.
+///
+/// Side-effects: N/A
+///
+pub fn nearest_neighbor_tour<T: IWeightedGraph + IDefiniteGraph>(
+    graph: &T,
+    start: &T::Node,
+) -> Option<(Vec<T::Node>, T::Weight)> {
+    if !graph.contains(start) {
+        return None;
+    }
+
+    let mut tour = vec![start.clone()];
+    let mut visited = HashSet::from([start.clone()]);
+    let mut total = T::Weight::from(0);
+
+    // a single node is already a (trivial) tour, it just starts and ends
+    // without going anywhere
+    if graph.len() == 1 {
+        tour.push(start.clone());
+        return Some((tour, total));
+    }
+
+    let mut current = start.clone();
+
+    while visited.len() < graph.len() {
+        // ties are broken on the node so the tour doesn't depend on hash order
+        let (next, weight) = graph
+            .get_adj_weighted(&current)
+            .into_iter()
+            .filter(|(node, _)| !visited.contains(node))
+            .min_by(|(a, x), (b, y)| x.cmp(y).then(a.cmp(b)))?;
+
+        visited.insert(next.clone());
+        tour.push(next.clone());
+        total = total + weight;
+        current = next;
+    }
+
+    // close the loop
+    let weight = graph
+        .get_adj_weighted(&current)
+        .into_iter()
+        .filter(|(node, _)| node == start)
+        .map(|(_, weight)| weight)
+        .min()?;
+
+    tour.push(start.clone());
+    total = total + weight;
+
+    Some((tour, total))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashSet;
+
+    use crate::data_structures::graphs::{
+        weighted_graph::WeightedGraph, IGraphEdgeWeightedMut, IGraphMut, IWeightedGraph,
+    };
+
+    use super::nearest_neighbor_tour;
+
+    fn complete() -> WeightedGraph<&'static str, i32> {
+        let mut graph = WeightedGraph::new();
+
+        let edges = [
+            ("A", "B", 2),
+            ("A", "C", 9),
+            ("A", "D", 10),
+            ("B", "C", 6),
+            ("B", "D", 4),
+            ("C", "D", 3),
+        ];
+
+        for node in ["A", "B", "C", "D"] {
+            graph.insert_node(node);
+        }
+
+        for (from, to, weight) in edges {
+            graph.insert_edge_weighted(from, to, weight);
+            graph.insert_edge_weighted(to, from, weight);
+        }
+
+        graph
+    }
+
+    #[test]
+    fn test_nearest_neighbor_tour() {
+        let graph = complete();
+
+        let (tour, total) = nearest_neighbor_tour(&graph, &"A").unwrap();
+
+        assert_eq!(tour, vec!["A", "B", "D", "C", "A"]);
+
+        // every node exactly once, plus the return to the start
+        let inner: HashSet<_> = tour[..tour.len() - 1].iter().collect();
+        assert_eq!(inner.len(), 4);
+        assert_eq!(tour.first(), tour.last());
+
+        let sum: i32 = tour
+            .windows(2)
+            .map(|pair| {
+                graph
+                    .get_adj_weighted(&pair[0])
+                    .into_iter()
+                    .find(|(node, _)| *node == pair[1])
+                    .unwrap()
+                    .1
+            })
+            .sum();
+        assert_eq!(total, sum);
+        assert_eq!(total, 2 + 4 + 3 + 9);
+    }
+
+    #[test]
+    fn no_tour() {
+        let mut graph = complete();
+
+        // nothing leads back to "E"
+        graph.insert_node("E");
+        graph.insert_edge_weighted("E", "A", 1);
+
+        assert_eq!(nearest_neighbor_tour(&graph, &"A"), None);
+        assert_eq!(nearest_neighbor_tour(&graph, &"Z"), None);
+
+        let mut single = WeightedGraph::new();
+        single.insert_node(1);
+        assert_eq!(nearest_neighbor_tour(&single, &1), Some((vec![1, 1], 0)));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
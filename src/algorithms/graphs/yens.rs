@@ -0,0 +1,162 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::collections::{BTreeSet, HashSet};
+
+use crate::{
+    algorithms::graphs::{dijkstras::dijkstras_filtered, validate_path},
+    data_structures::graphs::{IDefiniteGraph, IWeightedGraph},
+};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns up to `k` shortest loopless paths from `origin` to `target`,
+/// shortest first (Yen's algorithm)
+///
+/// Inputs:
+/// - `graph: &T` The graph to search through
+/// - `origin: &T::Node` The node every path starts at
+/// - `target: &T::Node` The node every path ends at
+/// - `k: usize` How many paths to find
+///
+/// Outputs:
+/// - `Vec<Vec<T::Node>>` The paths, from `origin` to `target` inclusive.
+///   Fewer than `k` if there aren't that many distinct loopless paths, and
+///   empty if there's no path at all. Paths of equal weight can come in any
+///   order.
+///
+/// Side-effects: N/A
+///
+/// Time complexity: O(k * n) runs of Dijkstra's
+///
+pub fn k_shortest_paths<T: IWeightedGraph + IDefiniteGraph>(
+    graph: &T,
+    origin: &T::Node,
+    target: &T::Node,
+    k: usize,
+) -> Vec<Vec<T::Node>> {
+    /*
+    Yen's algorithm ---
+
+        Start with the shortest path.
+        Every other path has to leave one of the paths we've already found at
+        some point (the spur node), so for each node along the last path
+        found, keep the path up to it (the root) and find the shortest way
+        from it to the target that:
+            - doesn't take the next edge of any found path sharing that root
+              (or we'd just find that path again)
+            - doesn't go back through the root (or it wouldn't be loopless)
+        Every root + spur path is a candidate, and the cheapest candidate is
+        the next shortest path.
+
+    */
+
+    let mut found: Vec<Vec<T::Node>> = vec![];
+
+    if k == 0 {
+        return found;
+    }
+
+    match dijkstras_filtered(graph, origin, target, |_, _, _| true) {
+        Some(path) => found.push(path),
+        None => return found,
+    }
+
+    // ordered by weight, then by the path itself, which also drops repeats
+    let mut candidates: BTreeSet<(T::Weight, Vec<T::Node>)> = BTreeSet::new();
+
+    while found.len() < k {
+        let last = found.last().expect("found starts with a path").clone();
+
+        for i in 0..last.len() - 1 {
+            let spur = &last[i];
+            let root = &last[..=i];
+
+            let removed_edges: HashSet<(T::Node, T::Node)> = found
+                .iter()
+                .filter(|path| path.len() > i + 1 && path[..=i] == *root)
+                .map(|path| (path[i].clone(), path[i + 1].clone()))
+                .collect();
+
+            let removed_nodes: HashSet<&T::Node> = root[..i].iter().collect();
+
+            let spur_path = dijkstras_filtered(graph, spur, target, |from, to, _| {
+                !removed_nodes.contains(to) && !removed_edges.contains(&(from.clone(), to.clone()))
+            });
+
+            if let Some(spur_path) = spur_path {
+                let mut path = root[..i].to_vec();
+                path.extend(spur_path);
+
+                if !found.contains(&path) {
+                    let weight = validate_path(graph, &path)
+                        .expect("consecutive nodes of a path share an edge");
+                    candidates.insert((weight, path));
+                }
+            }
+        }
+
+        match candidates.pop_first() {
+            Some((_, path)) => found.push(path),
+            None => break,
+        }
+    }
+
+    found
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use crate::data_structures::graphs::weighted_graph::WeightedGraph;
+
+    use super::k_shortest_paths;
+
+    fn routes() -> WeightedGraph<&'static str, i32> {
+        [
+            ("A", "B", 1),
+            ("B", "D", 1),
+            ("A", "C", 1),
+            ("C", "D", 2),
+            ("A", "D", 5),
+            ("B", "C", 1),
+            ("E", "A", 1),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn two_routes() {
+        let graph = routes();
+
+        assert_eq!(
+            k_shortest_paths(&graph, &"A", &"D", 2),
+            vec![vec!["A", "B", "D"], vec!["A", "C", "D"]]
+        );
+    }
+
+    #[test]
+    fn runs_out() {
+        let graph = routes();
+
+        let paths = k_shortest_paths(&graph, &"A", &"D", 10);
+
+        assert_eq!(
+            paths,
+            vec![
+                vec!["A", "B", "D"],
+                vec!["A", "C", "D"],
+                vec!["A", "B", "C", "D"],
+                vec!["A", "D"],
+            ]
+        );
+
+        assert!(k_shortest_paths(&graph, &"A", &"D", 0).is_empty());
+        assert!(k_shortest_paths(&graph, &"D", &"A", 3).is_empty());
+        assert_eq!(k_shortest_paths(&graph, &"A", &"A", 3), vec![vec!["A"]]);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,148 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+Two classic uses of a stack for parsing.
+
+Brackets close in the opposite order they were opened in, and reverse-Polish
+(postfix) expressions apply each operator to the two values most recently
+worked out, so in both cases the thing we need next is always whatever we
+saw last.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use crate::data_structures::stack::array_stack::solution::ArrayStack;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns whether every bracket in `s` is closed by the matching bracket,
+/// in the right order
+///
+/// - Inputs:
+///     | `s: &str`
+///     | The text to check, only `()`, `[]` and `{}` are looked at
+///
+/// - Output:
+///     | `true` if the brackets are balanced (including when there are none)
+///
+/// - Time complexity: O(n)
+///
+pub fn is_balanced(s: &str) -> bool {
+    let mut open = ArrayStack::new();
+
+    for c in s.chars() {
+        match c {
+            '(' | '[' | '{' => open.push(c),
+            ')' | ']' | '}' => {
+                let expected = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+
+                // closing something that was never opened, or closing the
+                // wrong thing
+                if open.pop() != Some(expected) {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // anything left was never closed
+    open.is_empty()
+}
+
+//---------------------------------------------------------------------------//
+
+/// Evaluates an arithmetic expression in reverse-Polish notation
+///
+/// e.g. `["2", "3", "+", "4", "*"]` is `(2 + 3) * 4`
+///
+/// - Inputs:
+///     | `tokens: &[&str]`
+///     | Numbers and the operators `+`, `-`, `*` and `/`
+///
+/// - Output:
+///     | `Some(f64)` the value of the expression
+///     | `None` if a token isn't a number or operator, an operator doesn't
+///     | have two values to work on, or there isn't exactly one value left
+///     | at the end
+///
+/// Dividing by zero follows the usual floating point rules (giving infinity
+/// or NaN) rather than failing.
+///
+/// - Time complexity: O(n)
+///
+pub fn eval_rpn(tokens: &[&str]) -> Option<f64> {
+    let mut values = ArrayStack::new();
+
+    for token in tokens {
+        let value = match *token {
+            "+" | "-" | "*" | "/" => {
+                // the right hand side was pushed last
+                let right = values.pop()?;
+                let left = values.pop()?;
+
+                match *token {
+                    "+" => left + right,
+                    "-" => left - right,
+                    "*" => left * right,
+                    _ => left / right,
+                }
+            }
+            number => number.parse::<f64>().ok()?,
+        };
+
+        values.push(value);
+    }
+
+    let result = values.pop()?;
+
+    values.is_empty().then_some(result)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn balanced() {
+        for s in [
+            "",
+            "()",
+            "([]{})",
+            "{[()()]}",
+            "fn main() { let x = [1, (2)]; }",
+        ] {
+            assert!(is_balanced(s), "{s}");
+        }
+
+        for s in ["(", ")", "(]", "([)]", "{{}", "())(", "]["] {
+            assert!(!is_balanced(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn rpn() {
+        assert_eq!(eval_rpn(&["2", "3", "+", "4", "*"]), Some(20.0));
+        assert_eq!(eval_rpn(&["10", "4", "-"]), Some(6.0));
+        assert_eq!(eval_rpn(&["7", "2", "/"]), Some(3.5));
+        assert_eq!(eval_rpn(&["-1.5"]), Some(-1.5));
+        assert_eq!(eval_rpn(&["1", "0", "/"]), Some(f64::INFINITY));
+
+        assert_eq!(eval_rpn(&[]), None);
+        assert_eq!(eval_rpn(&["+"]), None);
+        assert_eq!(eval_rpn(&["1", "+"]), None);
+        assert_eq!(eval_rpn(&["1", "2"]), None);
+        assert_eq!(eval_rpn(&["1", "x", "+"]), None);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
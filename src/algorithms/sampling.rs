@@ -0,0 +1,110 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use crate::meta::rng::XorShift;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns `k` items picked uniformly at random from `iter`
+///
+/// - Inputs:
+///     - `iter: impl Iterator<Item = T>` The items to pick from, this is only
+///       walked through once so it can be a stream of unknown length
+///     - `k: usize` How many items to pick
+///     - `seed: u64` Seed for the random number generator, the same seed
+///       and items always give the same sample
+/// - Output: `Vec<T>`
+///     - `k` items from `iter`, or all of them if there are `k` or fewer
+/// - Side-effects: Consumes `iter`
+/// - Time complexity: O(n)
+///     - `n` = the number of items in `iter`
+/// - Space complexity: O(k)
+pub fn reservoir_sample<T: Clone>(iter: impl Iterator<Item = T>, k: usize, seed: u64) -> Vec<T> {
+    /*
+    --- Algorithm R
+
+        Keep the first `k` items in a "reservoir". After that, item number i
+        (counting from 0) replaces a random reservoir slot with probability
+        k / (i + 1), and is dropped otherwise.
+
+        By induction every item seen so far ends up in the reservoir with
+        probability k / (items seen): a new item gets in with exactly that
+        chance, and an item already in survives with chance
+        1 - (k / (i + 1)) * (1 / k) = i / (i + 1), which scales its old
+        k / i down to k / (i + 1).
+
+    */
+
+    let mut rng = XorShift::scrambled(seed);
+    let mut reservoir = Vec::with_capacity(k);
+
+    if k == 0 {
+        return reservoir;
+    }
+
+    for (i, item) in iter.enumerate() {
+        if i < k {
+            reservoir.push(item);
+        } else {
+            // picking from 0..=i lands in 0..k with chance k / (i + 1)
+            let slot = rng.next_index(i + 1);
+
+            if slot < k {
+                reservoir[slot] = item;
+            }
+        }
+    }
+
+    reservoir
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn small_cases() {
+        assert_eq!(reservoir_sample(0..10, 0, 1), Vec::<i32>::new());
+        assert_eq!(reservoir_sample(0..3, 5, 1), vec![0, 1, 2]);
+        assert_eq!(reservoir_sample(0..3, 3, 1), vec![0, 1, 2]);
+
+        let sample = reservoir_sample(0..100, 10, 7);
+        assert_eq!(sample.len(), 10);
+        assert!(sample.iter().all(|item| (0..100).contains(item)));
+
+        // no item is picked twice
+        let mut unique = sample.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), 10);
+
+        assert_eq!(reservoir_sample(0..100, 10, 7), sample);
+    }
+
+    #[test]
+    fn uniform() {
+        let population = 20;
+        let k = 5;
+        let trials = 20_000;
+
+        let mut counts = vec![0; population];
+
+        for seed in 1..=trials {
+            for item in reservoir_sample(0..population, k, seed as u64) {
+                counts[item] += 1;
+            }
+        }
+
+        // each item should be picked k / population of the time
+        let expected = (trials * k / population) as f64;
+
+        for count in counts {
+            let error = (count as f64 - expected).abs() / expected;
+            assert!(error < 0.05, "{} vs expected {}", count, expected);
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
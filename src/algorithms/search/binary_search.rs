@@ -150,6 +150,48 @@ where
     inner(arr, target, 0, arr.len())
 }
 
+//---------------------------------------------------------------------------//
+
+/// Returns how many items in a sorted list are no greater than `target`.
+///
+/// That's also the index just past the last item `<= target`, i.e. where
+/// `target` would be inserted to go after any copies of it already there.
+///
+/// Inputs:
+/// - `arr: &[T]`
+///     - The sorted list to check in (duplicates are fine)
+///
+/// - `target: &T`
+///     - The value to compare against
+///
+/// Output:
+/// - `usize`
+///     - The number of items `<= target`, from `0` to `arr.len()`
+///
+pub fn binary_search_upper_bound<T>(arr: &[T], target: &T) -> usize
+where
+    T: Ord,
+{
+    // Same search region as binary_search_iterative, except we can't stop
+    // early on an equal item, since there might be more of them after it.
+    // Everything before `start` is known to be <= target, and everything
+    // from `end` on is known to be > target.
+    let mut start: usize = 0;
+    let mut end: usize = arr.len();
+
+    while start < end {
+        let midpoint = start + (end - start) / 2;
+
+        if &arr[midpoint] <= target {
+            start = midpoint + 1;
+        } else {
+            end = midpoint;
+        }
+    }
+
+    start
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
@@ -221,6 +263,27 @@ mod tests {
     //     helper(&arr, big_number, None);
     //     helper(&arr, 50, Some(50));
     // }
+
+    #[test]
+    fn test_upper_bound() {
+        let arr = [1, 3, 3, 3, 5, 8];
+
+        assert_eq!(binary_search_upper_bound(&arr, &0), 0);
+        assert_eq!(binary_search_upper_bound(&arr, &1), 1);
+        assert_eq!(binary_search_upper_bound(&arr, &2), 1);
+        assert_eq!(binary_search_upper_bound(&arr, &3), 4);
+        assert_eq!(binary_search_upper_bound(&arr, &7), 5);
+        assert_eq!(binary_search_upper_bound(&arr, &8), 6);
+        assert_eq!(binary_search_upper_bound(&arr, &100), 6);
+        assert_eq!(binary_search_upper_bound::<i32>(&[], &3), 0);
+
+        for target in 0..10 {
+            assert_eq!(
+                binary_search_upper_bound(&arr, &target),
+                arr.iter().filter(|item| **item <= target).count()
+            );
+        }
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,145 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the smallest and largest elements in `arr`
+///
+/// - Inputs:
+///     | `arr: &[T]`
+///     | The array to search through
+///
+/// - Returns:
+///     | If `arr` is not empty
+///         | `Some((&T, &T))`
+///         | The minimum and maximum wrapped in `Some()`
+///     | Otherwise
+///         | `None`
+///
+/// - Time complexity:
+///     | At most 3⌈n/2⌉ comparisons, rather than the 2n of checking each
+///     | element against both the minimum and maximum
+///
+pub fn min_max<T>(arr: &[T]) -> Option<(&T, &T)>
+where
+    T: Ord,
+{
+    /*
+    Instead of comparing every element against both the minimum and maximum,
+    we'll take the elements two at a time.
+    Comparing the pair against each other first tells us which one could be a
+    new minimum and which one could be a new maximum, so we only need to check
+    each of them against one side.
+    That's 3 comparisons for every 2 elements.
+    */
+
+    // start with the first element, or the first pair if the length is even
+    // so that the rest splits evenly into pairs
+    let (mut min, mut max, rest) = if arr.is_empty() {
+        return None;
+    } else if arr.len() % 2 == 1 {
+        (&arr[0], &arr[0], &arr[1..])
+    } else if arr[0] < arr[1] {
+        (&arr[0], &arr[1], &arr[2..])
+    } else {
+        (&arr[1], &arr[0], &arr[2..])
+    };
+
+    for pair in rest.chunks_exact(2) {
+        let (small, large) = if pair[0] < pair[1] {
+            (&pair[0], &pair[1])
+        } else {
+            (&pair[1], &pair[0])
+        };
+
+        if small < min {
+            min = small;
+        }
+
+        if large > max {
+            max = large;
+        }
+    }
+
+    Some((min, max))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use std::cell::Cell;
+    use std::cmp::Ordering;
+
+    use super::*;
+
+    fn helper(arr: &[i32]) {
+        let expected = match (arr.iter().min(), arr.iter().max()) {
+            (Some(min), Some(max)) => Some((min, max)),
+            _ => None,
+        };
+
+        assert_eq!(min_max(arr), expected);
+    }
+
+    #[test]
+    fn test_cases() {
+        helper(&[]);
+        helper(&[1]);
+        helper(&[1, 2]);
+        helper(&[2, 1]);
+        helper(&[3, 3, 3]);
+        helper(&[593, 52, 0, 40104, 20, 19, 2, 30, 8]);
+        helper(&[5, 23, 6, 8, 9, 0, 2]);
+        helper(&[-503, 1, 203, 585, 900]);
+        helper(&[23, 9, 8, 6, 5, 2, 0]);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    // wraps a number, counting every time it gets compared
+    struct Counted<'a>(i32, &'a Cell<usize>);
+
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl Eq for Counted<'_> {}
+
+    impl PartialOrd for Counted<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Counted<'_> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.1.set(self.1.get() + 1);
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn test_comparisons() {
+        for len in 1..200 {
+            let count = Cell::new(0);
+            let arr: Vec<Counted> = (0..len)
+                .map(|i| Counted((i * 7919) % 211, &count))
+                .collect();
+
+            let (min, max) = min_max(&arr).unwrap();
+            let comparisons = count.get();
+
+            assert_eq!(min.0, (0..len).map(|i| (i * 7919) % 211).min().unwrap());
+            assert_eq!(max.0, (0..len).map(|i| (i * 7919) % 211).max().unwrap());
+
+            let len = len as usize;
+            assert!(comparisons <= 3 * len.div_ceil(2));
+            if len > 2 {
+                assert!(comparisons < 2 * (len - 1));
+            }
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,112 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+Sliding window problems, where we want an answer for every run of `k`
+consecutive elements and don't want to pay O(k) per window for it.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use std::collections::VecDeque;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the maximum of every window of `k` consecutive elements
+///
+/// - Inputs:
+///     - `slice: &[T]`
+///     - `k: usize`
+///       The window length
+/// - Output: `Vec<T>`
+///     - One maximum per window, in order, so `slice.len() - k + 1` of them.
+///       Empty if `k` is zero or longer than the slice, since then there are
+///       no complete windows.
+/// - Side-effects: N/A
+/// - Time complexity: O(n)
+///
+pub fn max_sliding_window<T: Ord + Clone>(slice: &[T], k: usize) -> Vec<T> {
+    /*
+    We keep a deque of indices whose values are strictly decreasing from
+    front to back. The front is always the maximum of the current window.
+
+    When a new element comes in, anything at the back that's no bigger than
+    it can never be a window maximum again (the new element is bigger and
+    will stay in the window longer), so it's popped.
+    When the front slides out of the window, it's popped too.
+
+    Every index is pushed and popped at most once, so it's O(n) overall.
+    */
+    if k == 0 || k > slice.len() {
+        return vec![];
+    }
+
+    let mut deque: VecDeque<usize> = VecDeque::with_capacity(k);
+    let mut maxima = Vec::with_capacity(slice.len() - k + 1);
+
+    for (i, item) in slice.iter().enumerate() {
+        while deque.back().is_some_and(|back| slice[*back] <= *item) {
+            deque.pop_back();
+        }
+
+        deque.push_back(i);
+
+        if deque.front().is_some_and(|front| *front + k <= i) {
+            deque.pop_front();
+        }
+
+        if i + 1 >= k {
+            let front = *deque.front().expect("the newest index was just pushed");
+            maxima.push(slice[front].clone());
+        }
+    }
+
+    maxima
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::meta::rng::XorShift;
+
+    #[test]
+    fn test_max_sliding_window() {
+        assert_eq!(
+            max_sliding_window(&[1, 3, -1, -3, 5, 3, 6, 7], 3),
+            vec![3, 3, 5, 5, 6, 7]
+        );
+
+        assert_eq!(max_sliding_window(&[1, 2, 3], 0), Vec::<i32>::new());
+        assert_eq!(max_sliding_window(&[1, 2, 3], 4), Vec::<i32>::new());
+        assert_eq!(max_sliding_window::<i32>(&[], 1), Vec::<i32>::new());
+        assert_eq!(max_sliding_window(&[1, 2, 3], 3), vec![3]);
+        assert_eq!(max_sliding_window(&[4, 2, 3], 1), vec![4, 2, 3]);
+        assert_eq!(max_sliding_window(&[2, 2, 2, 2], 2), vec![2, 2, 2]);
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift::new(19);
+
+        for _ in 0..100 {
+            let slice: Vec<usize> = (0..rng.next_index(50))
+                .map(|_| rng.next_index(20))
+                .collect();
+
+            for k in 1..=slice.len() {
+                let expected: Vec<usize> = slice
+                    .windows(k)
+                    .map(|window| *window.iter().max().unwrap())
+                    .collect();
+
+                assert_eq!(max_sliding_window(&slice, k), expected);
+            }
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -55,20 +55,21 @@
 // `where T: Ord + ...` is how we talk about interfaces in Rust.
 // Here, we're saying that `T` should always implement:
 // - an interface for ordering (`Ord`), so we can use `<`
-// - an interface for pretty printing (`Debug`)
-// - and an interface for making objects with the same contents as another
-// object (`Clone`)
+// - and an interface for pretty printing (`Debug`)
+//
+// We only ever swap elements around, never copy them, so that's all we need.
+// This lets the other sorts fall back on us for small slices.
 //
 pub fn insertion_sort<T>(arr: &mut [T])
 where
-    T: Ord + std::fmt::Debug + std::fmt::Display + Clone,
+    T: Ord + std::fmt::Debug,
 {
     /*
     -- Main algorithm (insertion sort)
 
     For each element in `arr`
         |
-        | Save the value of the current element as `key`
+        | Call the current element `key`
         |
         | Let's just say that previous elements are already sorted.
         | We'll see why as we continue.
@@ -100,14 +101,12 @@ where
 
     // Iterate over every element
     for index in 1..arr.len() {
-        // save the current value
-        let key = arr[index].clone();
-
         // initialize our reverse index (skipping ourselves)
         let mut rev_ind = index - 1;
 
         // make sure we're smaller than the leftward element
-        while key < arr[rev_ind] {
+        // (`key` is always sitting just right of `rev_ind`)
+        while arr[rev_ind + 1] < arr[rev_ind] {
             // swap places with the leftward element
             arr.swap(rev_ind + 1, rev_ind);
 
@@ -125,6 +124,12 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
+/// Slices this short or shorter are usually faster to insertion sort than to
+/// keep splitting up, so other sorts hand them over to `insertion_sort`
+pub const INSERTION_CUTOFF: usize = 16;
+
+///////////////////////////////////////////////////////////////////////////////
+
 // Time complexity variables:
 //   n = the length of `arr`
 //
@@ -45,4 +45,24 @@ fn reverse_sorted_cases() {
     helper(shared_reverse_sorted_cases());
 }
 
+//---------------------------------------------------------------------------//
+
+#[test]
+fn degenerate_cases() {
+    helper(shared_degenerate_cases());
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn zero_sized() {
+    for case in shared_zst_cases() {
+        let mut real = case.clone();
+
+        insertion_sort(&mut real);
+
+        assert_eq!(real, case);
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
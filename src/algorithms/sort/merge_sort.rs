@@ -1,6 +1,8 @@
 ///////////////////////////////////////////////////////////////////////////////
 
-use std::fmt;
+use std::{cmp::Ordering, fmt};
+
+use super::insertion_sort::solution::{insertion_sort, INSERTION_CUTOFF};
 
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -15,6 +17,27 @@ use std::fmt;
 ///     | The sorted vector
 ///
 pub fn merge_sort<T: Clone + Ord + fmt::Debug>(arr: Vec<T>) -> Vec<T> {
+    merge_sort_with_cutoff(arr, INSERTION_CUTOFF)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Sorts the provided vector in ascending order, switching to insertion sort
+/// for pieces of `cutoff` elements or fewer.
+///
+/// - Inputs
+///     | `arr: Vec<T>`
+///     | The vector array to sort
+///     |
+///     | `cutoff: usize`
+///     | The largest piece to hand over to insertion sort
+///     | (`1` gives a plain merge sort)
+///
+/// - Outputs
+///     | `Vec<T>`
+///     | The sorted vector
+///
+pub fn merge_sort_with_cutoff<T: Clone + Ord + fmt::Debug>(arr: Vec<T>, cutoff: usize) -> Vec<T> {
     /*
     --- Merge sort
 
@@ -23,13 +46,22 @@ pub fn merge_sort<T: Clone + Ord + fmt::Debug>(arr: Vec<T>) -> Vec<T> {
 
         This implementation could be improved by re-using allocated vectors.
 
+        Splitting all the way down to single elements costs a lot of small
+        allocations and recursive calls, so once a piece is small enough we
+        insertion sort it instead. Insertion sort is O(n^2), but for a handful
+        of elements it beats the overhead.
+
     */
 
-    fn inner<T: Clone + Ord + fmt::Debug>(arr: Vec<&T>) -> Vec<&T> {
+    fn inner<T: Clone + Ord + fmt::Debug>(mut arr: Vec<&T>, cutoff: usize) -> Vec<&T> {
         // check if we're small enough to already be sorted
         if arr.len() < 2 {
             // if so, return the array (its already sorted)
             arr.to_vec()
+        } else if arr.len() <= cutoff {
+            // small enough that insertion sort is quicker
+            insertion_sort(&mut arr);
+            arr
         } else {
             // find the middle point
             let middle = arr.len() / 2;
@@ -39,8 +71,8 @@ pub fn merge_sort<T: Clone + Ord + fmt::Debug>(arr: Vec<T>) -> Vec<T> {
             let upper: Vec<&T> = arr[middle..].to_vec();
 
             // recursively sort each separately
-            let lower = inner(lower);
-            let upper = inner(upper);
+            let lower = inner(lower, cutoff);
+            let upper = inner(upper, cutoff);
 
             // create a temp vector for merging
             let mut res = vec![];
@@ -80,7 +112,7 @@ pub fn merge_sort<T: Clone + Ord + fmt::Debug>(arr: Vec<T>) -> Vec<T> {
 
     // syntax nightmare to turn Vec<T> into Vec<&T> and back again
     // probably can be refactored away, but I didn't have time
-    inner(arr.iter().map(|i| i).collect())
+    inner(arr.iter().collect(), cutoff)
         .iter()
         .map(|i| i.to_owned().to_owned())
         .collect()
@@ -88,10 +120,126 @@ pub fn merge_sort<T: Clone + Ord + fmt::Debug>(arr: Vec<T>) -> Vec<T> {
 
 ///////////////////////////////////////////////////////////////////////////////
 
+/// Sorts the provided vector using `cmp` to order elements.
+///
+/// The sort is stable: elements that compare equal keep their original
+/// order. Elements are moved rather than cloned, so `T` doesn't need to be
+/// `Clone` (or even `Ord`).
+///
+/// - Inputs
+///     | `arr: Vec<T>`
+///     | The vector array to sort
+///     |
+///     | `cmp: F`
+///     | Returns how two elements are ordered
+///
+/// - Outputs
+///     | `Vec<T>`
+///     | The sorted vector
+///
+pub fn merge_sort_by<T, F: Fn(&T, &T) -> Ordering>(arr: Vec<T>, cmp: F) -> Vec<T> {
+    fn inner<T, F: Fn(&T, &T) -> Ordering>(mut arr: Vec<T>, cmp: &F) -> Vec<T> {
+        if arr.len() < 2 {
+            return arr;
+        }
+
+        let upper = arr.split_off(arr.len() / 2);
+
+        let lower = inner(arr, cmp);
+        let upper = inner(upper, cmp);
+
+        let mut res = Vec::with_capacity(lower.len() + upper.len());
+        let mut lower = lower.into_iter().peekable();
+
+        for item in upper {
+            // ties go to the lower half first, which keeps the sort stable
+            while let Some(next) = lower.next_if(|next| cmp(next, &item) != Ordering::Greater) {
+                res.push(next);
+            }
+            res.push(item);
+        }
+
+        res.extend(lower);
+
+        res
+    }
+
+    inner(arr, &cmp)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Counts the inversions in `slice`, the pairs of elements that are out of
+/// order (`i < j` but `slice[i] > slice[j]`)
+///
+/// Equal elements are never an inversion. `slice` itself is left untouched.
+///
+/// - Inputs
+///     | `slice: &[T]`
+///     | The elements to check
+///
+/// - Outputs
+///     | `u64`
+///     | The number of inversions, from 0 for a sorted slice up to
+///     | n(n-1)/2 for a strictly decreasing one
+///
+/// - Time complexity:
+///     | O(n log n)
+///
+pub fn count_inversions<T: Ord + Clone>(slice: &[T]) -> u64 {
+    /*
+    Merge sort, except we count while merging.
+
+    Both halves are sorted, so whenever an item from the upper half is placed
+    before items still waiting in the lower half, it must have been behind
+    (and smaller than) every one of them in the original order.
+    That's one inversion for each of those waiting items.
+
+    Inversions inside each half are counted by the recursive calls, and
+    sorting a half doesn't change how many inversions it has with the other.
+    */
+
+    fn inner<'a, T: Ord>(arr: &[&'a T], count: &mut u64) -> Vec<&'a T> {
+        if arr.len() < 2 {
+            return arr.to_vec();
+        }
+
+        let middle = arr.len() / 2;
+
+        let lower = inner(&arr[..middle], count);
+        let upper = inner(&arr[middle..], count);
+
+        let mut res = Vec::with_capacity(arr.len());
+        let mut i = 0;
+
+        for item in upper {
+            // ties go to the lower half first, equal items aren't inverted
+            while i < lower.len() && lower[i] <= item {
+                res.push(lower[i]);
+                i += 1;
+            }
+
+            *count += (lower.len() - i) as u64;
+            res.push(item);
+        }
+
+        res.extend_from_slice(&lower[i..]);
+
+        res
+    }
+
+    let mut count = 0;
+    inner(&slice.iter().collect::<Vec<&T>>(), &mut count);
+    count
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::algorithms::sort::shared_test_cases::{shared_degenerate_cases, shared_zst_cases};
 
     fn helper(cases: Vec<Vec<i32>>) {
         for case in cases {
@@ -143,6 +291,32 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn degenerate_cases() {
+        helper(shared_degenerate_cases());
+
+        for case in shared_zst_cases() {
+            assert_eq!(merge_sort(case.clone()), case);
+            assert_eq!(merge_sort_with_cutoff(case.clone(), 1), case);
+        }
+    }
+
+    #[test]
+    fn cutoff_cases() {
+        let arr: Vec<i32> = (0..5000).map(|i| (i * 7919) % 1013 - 500).collect();
+        let mut expected = arr.clone();
+        expected.sort();
+
+        for cutoff in [1, 16, 1000] {
+            assert_eq!(merge_sort_with_cutoff(arr.clone(), cutoff), expected);
+            assert_eq!(merge_sort_with_cutoff(vec![3, 1, 2], cutoff), vec![1, 2, 3]);
+            assert_eq!(
+                merge_sort_with_cutoff(Vec::<i32>::new(), cutoff),
+                Vec::<i32>::new()
+            );
+        }
+    }
+
     #[test]
     fn test_big_sorted() {
         let big_number = (2 as i32).pow(20);
@@ -164,6 +338,59 @@ mod tests {
 
         helper(vec![arr]);
     }
+
+    #[test]
+    fn inversions() {
+        fn brute_force(arr: &[i32]) -> u64 {
+            let mut count = 0;
+            for i in 0..arr.len() {
+                for j in i + 1..arr.len() {
+                    if arr[i] > arr[j] {
+                        count += 1;
+                    }
+                }
+            }
+            count
+        }
+
+        assert_eq!(count_inversions::<i32>(&[]), 0);
+        assert_eq!(count_inversions(&[1]), 0);
+        assert_eq!(count_inversions(&[1, 2, 2, 3, 10]), 0);
+
+        for n in [2u64, 3, 10, 1000] {
+            let arr: Vec<u64> = (0..n).rev().collect();
+            assert_eq!(count_inversions(&arr), n * (n - 1) / 2);
+        }
+
+        // only strictly out of order pairs count
+        assert_eq!(count_inversions(&[3, 3, 3]), 0);
+        assert_eq!(count_inversions(&[2, 4, 1, 3, 5]), 3);
+
+        let arr: Vec<i32> = (0..500).map(|i| (i * 7919) % 211 - 100).collect();
+        let copy = arr.clone();
+
+        assert_eq!(count_inversions(&arr), brute_force(&arr));
+        assert_eq!(arr, copy);
+    }
+
+    #[test]
+    fn sort_by() {
+        let arr: Vec<i32> = (0..1000).map(|i| (i * 7919) % 211 - 100).collect();
+        let mut expected = arr.clone();
+        expected.sort_by(|a, b| b.cmp(a));
+
+        assert_eq!(merge_sort_by(arr, |a, b| b.cmp(a)), expected);
+        assert_eq!(
+            merge_sort_by(Vec::<i32>::new(), i32::cmp),
+            Vec::<i32>::new()
+        );
+
+        // stable: sorting on the first item only keeps the second in order
+        let pairs: Vec<(i32, usize)> = (0..100).map(|i| ((i as i32 * 37) % 7, i)).collect();
+        let sorted = merge_sort_by(pairs, |a, b| a.0.cmp(&b.0));
+
+        assert!(sorted.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -2,6 +2,9 @@
 
 use std::fmt;
 
+use super::insertion_sort::solution::{insertion_sort, INSERTION_CUTOFF};
+use crate::meta::rng::XorShift;
+
 ///////////////////////////////////////////////////////////////////////////////
 
 /// Sorts the provided slice in ascending order.
@@ -14,6 +17,26 @@ use std::fmt;
 ///     | Sorts `arr` in ascending order
 ///
 pub fn quick_sort<T: Ord + fmt::Debug>(arr: &mut [T]) {
+    quick_sort_with_cutoff(arr, INSERTION_CUTOFF)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Sorts the provided slice in ascending order, switching to insertion sort
+/// for slices of `cutoff` elements or fewer.
+///
+/// - Inputs
+///     | `arr: &mut [T]`
+///     | The slice to sort (mutable)
+///     |
+///     | `cutoff: usize`
+///     | The largest slice to hand over to insertion sort
+///     | (`0` gives a plain quick sort)
+///
+/// - Side effects
+///     | Sorts `arr` in ascending order
+///
+pub fn quick_sort_with_cutoff<T: Ord + fmt::Debug>(arr: &mut [T], cutoff: usize) {
     // heavily based on [2]'s implementation
     // see commit `fa58f0d` for quick sort without in place mutation
 
@@ -29,129 +52,262 @@ pub fn quick_sort<T: Ord + fmt::Debug>(arr: &mut [T]) {
     Next, we make our problem simpler by splitting up our array.
     One part will consist of elements less than our pivot and the other will
     consist of elements greater than our pivot.
-    The details of splitting are described in the `part` function.
+    The details of splitting are described in the `partition` function.
     We then recursively sort the two smaller arrays.
     This leaves us with a completely sorted array.
 
     */
 
-    fn part<T: Ord + fmt::Debug>(arr: &mut [T]) -> usize {
+    fn inner<T: Ord + fmt::Debug>(mut arr: &mut [T], cutoff: usize) {
         /*
+        Sorting method from [2] ---
 
-        Partition method from [2] ---
+            This part is pretty simple.
+            Just check if we're small enough to already be sorted (size < 2)
+            If not, just partition our array and search each side of the
+            partition seperately.
 
-            We start by picking a pivot. For simplicity, we'll just start
-            with the last element.
+        Syntax key ---
 
-            In order to avoid allocating a second array, we'll create a
-            variable to denote our where we should split our array.
-            Elements at indices less than the variable will have values
-            less than the pivot. Likewise, the elements to the right of
-            the variable will have greater values than the pivot.
-            The textbook calls this variable firsthigh, but I feel like
-            lower_bound is slightly more descriptive.
+            arr.split_at_mut(bound) gives us two slices, arr[..bound]
+            (i=0 to i=bound-1) and arr[bound..] (i=bound to i=length)
+            upper[1..] then skips the pivot at the start of the upper half
 
-            Since we don't know which elements are less than pivot yet,
-            we'll initialize lower_bound to zero, denoting an empty array.
+            We've seen this before but &mut is a safe mutable reference.
+            Basically a point with a couple of safety guarantees and rules.
+
+        Small slices ---
 
-            Now, in order to figure out which elements are smaller than
-            pivot, we loop over each in our provided array.
+            Once we're down to a handful of elements, the recursion costs more
+            than it saves, so we let insertion sort finish those off.
 
-            When we find elements smaller than our pivot, we'll simulate
-            adding them to a second array by placing them in a designated
-            lower area of the provided array. We'll do this by just swapping
-            whatever lower_bound currently points to with our current element.
-            Now, we need to increment lower_bound one slot to right to show
-            our lower area now has one more element.
+        Recursion depth ---
 
-            Once we've checked each element, we have one more issue.
-            The pivot itself is out of place!
-            At the start, we didn't know how many items we were going to have
-            in the lower area so we couldn't say where the seperation point
-            would be. Now we do!
-            To place the pivot correctly in between the lower and upper areas,
-            we'll just swap it with whatever lower_points to.
+            A bad pivot (like in an array of all equal elements, where it
+            always ends up at the front) leaves one side with everything
+            but the pivot. Recursing on both sides would then go n calls
+            deep and overflow the stack on big inputs.
+            So we only recurse on the smaller side, and loop around to sort
+            the bigger side ourselves. The smaller side is at most half of
+            the array, so we never go more than log(n) calls deep.
 
-            Finally, since we've moved our pivot element to our lower_bounds
-            index, and the caller is expecting the index of our pivot, we'll
-            return lower_bounds.
+        */
 
+        while arr.len() > cutoff {
+            let pivot = partition(arr);
 
-        Demo ---
+            // `take` hands us the whole slice, so we can split it up and
+            // keep one of the halves
+            let (lower, upper) = std::mem::take(&mut arr).split_at_mut(pivot);
+            let upper = &mut upper[1..];
 
-            ^ - current
-            * - pivot
-            l - lower end
+            if lower.len() < upper.len() {
+                inner(lower, cutoff);
+                arr = upper;
+            } else {
+                inner(upper, cutoff);
+                arr = lower;
+            }
+        }
 
-            [3,1,2] --- 3>2
-             ^ . .
-             l . *
-            [3,1,2] --- 1<2 --- swap ^ and l
-             . ^ .
-             l . *
-            [1,3,2] --- 2=2
-             . . ^
-             . l *
+        insertion_sort(arr);
+    }
 
-            swap l and *
+    // run our inner function on the full array
+    inner(arr, cutoff);
+}
 
-            [1,2,3]
-             . l .
+///////////////////////////////////////////////////////////////////////////////
 
-            return l
-        */
+/// Sorts the provided slice in ascending order, using pseudo-random pivots.
+///
+/// - Inputs
+///     | `arr: &mut [T]`
+///     | The slice to sort (mutable)
+///     | `seed: u64`
+///     | Seed for picking pivots, the same seed always picks the same pivots
+///
+/// - Side effects
+///     | Sorts `arr` in ascending order
+///
+pub fn quick_sort_seeded<T: Ord>(arr: &mut [T], seed: u64) {
+    /*
+    Same as quick_sort, except before partitioning we swap a random element
+    into the last slot so that it becomes the pivot.
+    This avoids the worst case on already sorted input.
+    */
+
+    fn inner<T: Ord>(mut arr: &mut [T], rng: &mut XorShift) {
+        // same smaller-side-first trick as quick_sort, random pivots still
+        // all land at the front when every element is equal
+        while arr.len() > 1 {
+            let last = arr.len() - 1;
+            arr.swap(rng.next_index(arr.len()), last);
 
-        // initialize to the last element
-        let pivot = arr.len() - 1;
+            let pivot = partition(arr);
 
-        // initialize to an empty area
-        let mut lower_end = 0;
+            let (lower, upper) = std::mem::take(&mut arr).split_at_mut(pivot);
+            let upper = &mut upper[1..];
 
-        // look for elements smaller than pivot
-        for i in 0..arr.len() {
-            if arr[i] < arr[pivot] {
-                // move smaller elements into the designated lower area
-                arr.swap(i, lower_end);
-                // notify ourselves that the lower area is one slot bigger
-                lower_end += 1;
+            if lower.len() < upper.len() {
+                inner(lower, rng);
+                arr = upper;
+            } else {
+                inner(upper, rng);
+                arr = lower;
             }
         }
+    }
+
+    inner(arr, &mut XorShift::new(seed));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the k-th smallest element (counting from zero) of the slice.
+///
+/// - Inputs
+///     | `arr: &mut [T]`
+///     | The slice to search (mutable)
+///     | `k: usize`
+///     | The index the element would have if `arr` was sorted
+///
+/// - Output
+///     | `Some(&T)` the k-th smallest element
+///     | `None` if `k` is out of range
+///
+/// - Side effects
+///     | Partially reorders `arr`
+///
+/// - Time complexity
+///     | O(n) on average, O(n^2) worst case
+///
+pub fn quickselect<T: Ord + Clone>(arr: &mut [T], k: usize) -> Option<&T> {
+    /*
+    --- Quick select overview
 
-        // move the pivot in-between the lower area and upper area
-        arr.swap(pivot, lower_end);
+    Works just like quick sort, except after partitioning we know exactly
+    which side the k-th element ended up on, so we only need to keep looking
+    on that one side.
+    If the pivot lands on index k, we've found it!
 
-        // return the index of our pivot element
-        lower_end
+    */
+
+    if k >= arr.len() {
+        return None;
     }
 
-    fn inner<T: Ord + fmt::Debug>(arr: &mut [T]) {
-        /*
-        Sorting method from [2] ---
+    // the section of the array that must contain index k
+    let mut lower = 0;
+    let mut upper = arr.len();
 
-            This part is pretty simple.
-            Just check if we're small enough to already be sorted (size < 2)
-            If not, just partition our array and search each side of the
-            partition seperately.
+    loop {
+        let pivot = lower + partition(&mut arr[lower..upper]);
 
-        Syntax key ---
+        if k < pivot {
+            upper = pivot;
+        } else if k > pivot {
+            lower = pivot + 1;
+        } else {
+            return Some(&arr[pivot]);
+        }
+    }
+}
 
-            arr[..bound] means we're sectioning off from i=0 to i=bound-1
-            arr[bound+1..] means we're sectioning off from i=bound+1 to i=length
+///////////////////////////////////////////////////////////////////////////////
 
-            We've seen this before but &mut is a safe mutable reference.
-            Basically a point with a couple of safety guarantees and rules.
+/// Splits the slice around its last element, returning the pivot's new index
+fn partition<T: Ord>(arr: &mut [T]) -> usize {
+    /*
 
-        */
+    Partition method from [2] ---
+
+        We start by picking a pivot. For simplicity, we'll just start
+        with the last element.
+
+        In order to avoid allocating a second array, we'll create a
+        variable to denote our where we should split our array.
+        Elements at indices less than the variable will have values
+        less than the pivot. Likewise, the elements to the right of
+        the variable will have greater values than the pivot.
+        The textbook calls this variable firsthigh, but I feel like
+        lower_bound is slightly more descriptive.
+
+        Since we don't know which elements are less than pivot yet,
+        we'll initialize lower_bound to zero, denoting an empty array.
+
+        Now, in order to figure out which elements are smaller than
+        pivot, we loop over each in our provided array.
+
+        When we find elements smaller than our pivot, we'll simulate
+        adding them to a second array by placing them in a designated
+        lower area of the provided array. We'll do this by just swapping
+        whatever lower_bound currently points to with our current element.
+        Now, we need to increment lower_bound one slot to right to show
+        our lower area now has one more element.
 
-        if arr.len() > 1 {
-            let pivot = part(arr);
-            inner(&mut arr[..pivot]);
-            inner(&mut arr[pivot + 1..]);
+        Once we've checked each element, we have one more issue.
+        The pivot itself is out of place!
+        At the start, we didn't know how many items we were going to have
+        in the lower area so we couldn't say where the seperation point
+        would be. Now we do!
+        To place the pivot correctly in between the lower and upper areas,
+        we'll just swap it with whatever lower_points to.
+
+        Finally, since we've moved our pivot element to our lower_bounds
+        index, and the caller is expecting the index of our pivot, we'll
+        return lower_bounds.
+
+
+    Demo ---
+
+        ^ - current
+        * - pivot
+        l - lower end
+
+        [3,1,2] --- 3>2
+         ^ . .
+         l . *
+        [3,1,2] --- 1<2 --- swap ^ and l
+         . ^ .
+         l . *
+        [1,3,2] --- 2=2
+         . . ^
+         . l *
+
+        swap l and *
+
+        [1,2,3]
+         . l .
+
+        return l
+    */
+
+    // there's no pivot to pick in an empty slice (and `len - 1` would
+    // underflow), every caller checks for this first
+    assert!(!arr.is_empty(), "can't partition an empty slice");
+
+    // initialize to the last element
+    let pivot = arr.len() - 1;
+
+    // initialize to an empty area
+    let mut lower_end = 0;
+
+    // look for elements smaller than pivot
+    for i in 0..arr.len() {
+        if arr[i] < arr[pivot] {
+            // move smaller elements into the designated lower area
+            arr.swap(i, lower_end);
+            // notify ourselves that the lower area is one slot bigger
+            lower_end += 1;
         }
     }
 
-    // run our inner function on the full array
-    inner(arr);
+    // move the pivot in-between the lower area and upper area
+    arr.swap(pivot, lower_end);
+
+    // return the index of our pivot element
+    lower_end
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -160,6 +316,7 @@ pub fn quick_sort<T: Ord + fmt::Debug>(arr: &mut [T]) {
 mod tests {
 
     use super::*;
+    use crate::algorithms::sort::shared_test_cases::{shared_degenerate_cases, shared_zst_cases};
 
     fn helper(cases: Vec<Vec<i32>>) {
         for case in cases {
@@ -232,6 +389,154 @@ mod tests {
 
         helper(vec![arr]);
     }
+
+    #[test]
+    fn degenerate_cases() {
+        helper(shared_degenerate_cases());
+
+        // all equal, which always leaves the pivot at the front, so
+        // recursing on both sides would go 20,000 calls deep
+        helper(vec![vec![3; 20_000]]);
+
+        for case in shared_degenerate_cases() {
+            let mut expected = case.clone();
+            expected.sort();
+
+            let mut real = case.clone();
+            quick_sort_seeded(&mut real, 7);
+            assert_eq!(real, expected);
+
+            for (k, item) in expected.iter().enumerate().take(20) {
+                let mut arr = case.clone();
+                assert_eq!(quickselect(&mut arr, k), Some(item));
+            }
+        }
+
+        for case in shared_zst_cases() {
+            let mut real = case.clone();
+            quick_sort(&mut real);
+            assert_eq!(real, case);
+
+            let mut real = case.clone();
+            quick_sort_seeded(&mut real, 7);
+            assert_eq!(real, case);
+        }
+    }
+
+    #[test]
+    fn cutoff_cases() {
+        let arr: Vec<i32> = (0..5000).map(|i| (i * 7919) % 1013 - 500).collect();
+        let mut expected = arr.clone();
+        expected.sort();
+
+        // 0 never insertion sorts, 10,000 insertion sorts everything
+        for cutoff in [0, 1, 16, 10_000] {
+            let mut real = arr.clone();
+            quick_sort_with_cutoff(&mut real, cutoff);
+            assert_eq!(real, expected);
+
+            for case in shared_degenerate_cases() {
+                let mut expected = case.clone();
+                expected.sort();
+
+                let mut real = case;
+                quick_sort_with_cutoff(&mut real, cutoff);
+                assert_eq!(real, expected);
+            }
+
+            let mut empty: Vec<i32> = vec![];
+            quick_sort_with_cutoff(&mut empty, cutoff);
+            assert!(empty.is_empty());
+        }
+    }
+
+    #[test]
+    fn seeded_cases() {
+        for case in [
+            vec![],
+            vec![1],
+            vec![593, 52, 0, 40104, 20, 19, 2, 30, 8],
+            (0..500).collect(),
+            (0..500).rev().collect(),
+            (0..500).map(|i| (i * 7919) % 97).collect(),
+        ] {
+            let mut expected = case.clone();
+            expected.sort();
+
+            for seed in 0..5 {
+                let mut real = case.clone();
+                quick_sort_seeded(&mut real, seed);
+                assert_eq!(real, expected);
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    // compares by key only, so equal keys end up wherever the swaps put them
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Tagged(i32, usize);
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn seeded_reproducible() {
+        let case: Vec<Tagged> = (0..300).map(|i| Tagged((i as i32 * 31) % 10, i)).collect();
+
+        let mut first = case.clone();
+        let mut second = case.clone();
+
+        quick_sort_seeded(&mut first, 1234);
+        quick_sort_seeded(&mut second, 1234);
+
+        // same swaps means the tags of equal keys land in the same places
+        assert_eq!(
+            first.iter().map(|t| t.1).collect::<Vec<usize>>(),
+            second.iter().map(|t| t.1).collect::<Vec<usize>>()
+        );
+        assert!(first.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let mut other = case.clone();
+        quick_sort_seeded(&mut other, 4321);
+
+        assert_ne!(
+            first.iter().map(|t| t.1).collect::<Vec<usize>>(),
+            other.iter().map(|t| t.1).collect::<Vec<usize>>()
+        );
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn quickselect_cases() {
+        for seed in 0..50 {
+            let len = seed * 3 % 40;
+            let case: Vec<i32> = (0..len).map(|i| (i * 7919 + seed * 104729) % 97).collect();
+
+            let mut sorted = case.clone();
+            sorted.sort();
+
+            for (k, expected) in sorted.iter().enumerate() {
+                let mut arr = case.clone();
+                assert_eq!(quickselect(&mut arr, k), Some(expected));
+            }
+
+            let mut arr = case.clone();
+            assert_eq!(quickselect(&mut arr, len as usize), None);
+        }
+
+        assert_eq!(quickselect::<i32>(&mut [], 0), None);
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -6,6 +6,36 @@ pub fn shared_special_cases() -> Vec<Vec<i32>> {
 
 //---------------------------------------------------------------------------//
 
+/// Inputs that tend to trip up index arithmetic or pivot choices
+pub fn shared_degenerate_cases() -> Vec<Vec<i32>> {
+    vec![
+        vec![],
+        vec![-1],
+        vec![4, 4],
+        vec![7; 17],
+        vec![0; 1000],
+        (0..1000).map(|i| i % 2).collect(),
+    ]
+}
+
+//---------------------------------------------------------------------------//
+
+/// A zero-sized type, every value is equal to every other one
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Zst;
+
+impl std::fmt::Display for Zst {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Zst")
+    }
+}
+
+pub fn shared_zst_cases() -> Vec<Vec<Zst>> {
+    vec![vec![], vec![Zst], vec![Zst; 1000]]
+}
+
+//---------------------------------------------------------------------------//
+
 pub fn shared_random_cases() -> Vec<Vec<i32>> {
     vec![
         vec![1, 3, 2],
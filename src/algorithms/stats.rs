@@ -0,0 +1,230 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+Streaming statistics, for when there are too many samples to keep around.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Estimates a single quantile of a stream of numbers in constant memory
+/// (the P² algorithm, by Jain and Chlamtac)
+///
+/// Rather than storing every sample, it keeps five markers: the minimum, the
+/// maximum, the target quantile, and the quantiles halfway to it on either
+/// side. As samples come in, each marker's position is nudged towards where
+/// it should be, and its height is adjusted by fitting a parabola through it
+/// and its neighbours.
+///
+#[derive(Debug, Clone)]
+pub struct P2Quantile {
+    p: f64,
+    count: usize,
+    // marker heights
+    heights: [f64; 5],
+    // actual marker positions (as 0-based ranks)
+    positions: [f64; 5],
+    // where the markers should be
+    desired: [f64; 5],
+    // how far each desired position moves per sample
+    increments: [f64; 5],
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl P2Quantile {
+    //-----------------------------------------------------------------------//
+
+    /// Creates an estimator for the `p` quantile, e.g. `0.5` for the median
+    ///
+    /// Panics if `p` isn't strictly between 0 and 1
+    pub fn new(p: f64) -> Self {
+        assert!(p > 0.0 && p < 1.0, "the quantile must be between 0 and 1");
+
+        Self {
+            p,
+            count: 0,
+            heights: [0.0; 5],
+            positions: [0.0, 1.0, 2.0, 3.0, 4.0],
+            desired: [0.0, 2.0 * p, 4.0 * p, 2.0 + 2.0 * p, 4.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Adds a sample to the stream
+    ///
+    /// - Time complexity: O(1)
+    ///
+    pub fn observe(&mut self, x: f64) {
+        // the first five samples just become the markers
+        if self.count < 5 {
+            self.heights[self.count] = x;
+            self.count += 1;
+
+            if self.count == 5 {
+                self.heights.sort_by(f64::total_cmp);
+            }
+            return;
+        }
+
+        self.count += 1;
+
+        // find the cell x falls in, stretching the ends if it's a new
+        // minimum or maximum
+        let cell = if x < self.heights[0] {
+            self.heights[0] = x;
+            0
+        } else if x >= self.heights[4] {
+            self.heights[4] = x;
+            3
+        } else {
+            (0..4)
+                .find(|i| x < self.heights[i + 1])
+                .expect("x is below the maximum")
+        };
+
+        // every marker above the cell moves up a rank
+        for position in &mut self.positions[cell + 1..] {
+            *position += 1.0;
+        }
+
+        for (desired, increment) in self.desired.iter_mut().zip(self.increments) {
+            *desired += increment;
+        }
+
+        // nudge the middle markers towards where they should be, as long as
+        // they don't run into their neighbours
+        for i in 1..4 {
+            let off = self.desired[i] - self.positions[i];
+            let room_above = self.positions[i + 1] - self.positions[i];
+            let room_below = self.positions[i - 1] - self.positions[i];
+
+            if (off >= 1.0 && room_above > 1.0) || (off <= -1.0 && room_below < -1.0) {
+                let step = off.signum();
+
+                let height = self.parabolic(i, step);
+
+                self.heights[i] = if self.heights[i - 1] < height && height < self.heights[i + 1] {
+                    height
+                } else {
+                    self.linear(i, step)
+                };
+
+                self.positions[i] += step;
+            }
+        }
+    }
+
+    //.......................................................................//
+
+    /// Returns the current estimate of the quantile
+    ///
+    /// With fewer than five samples the exact quantile of what's been seen
+    /// is returned, and with none it's NaN.
+    pub fn estimate(&self) -> f64 {
+        if self.count >= 5 {
+            return self.heights[2];
+        }
+
+        if self.count == 0 {
+            return f64::NAN;
+        }
+
+        let mut seen = self.heights[..self.count].to_vec();
+        seen.sort_by(f64::total_cmp);
+
+        let rank = (self.p * (self.count - 1) as f64).round() as usize;
+        seen[rank]
+    }
+
+    //.......................................................................//
+
+    /// How many samples have been observed
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// The piecewise-parabolic prediction for marker `i` moved by `step`
+    fn parabolic(&self, i: usize, step: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+
+        q[i] + step / (n[i + 1] - n[i - 1])
+            * ((n[i] - n[i - 1] + step) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                + (n[i + 1] - n[i] - step) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]))
+    }
+
+    /// The linear prediction for marker `i` moved by `step`, used when the
+    /// parabola would put it out of order
+    fn linear(&self, i: usize, step: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        let j = if step > 0.0 { i + 1 } else { i - 1 };
+
+        q[i] + step * (q[j] - q[i]) / (n[j] - n[i])
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::meta::rng::XorShift;
+
+    fn uniform(rng: &mut XorShift) -> f64 {
+        (rng.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    #[test]
+    fn uniform_stream() {
+        let mut rng = XorShift::new(2024);
+
+        let mut median = P2Quantile::new(0.5);
+        let mut p90 = P2Quantile::new(0.9);
+
+        for _ in 0..100_000 {
+            let x = uniform(&mut rng);
+            median.observe(x);
+            p90.observe(x);
+        }
+
+        assert_eq!(median.count(), 100_000);
+        assert!(
+            (median.estimate() - 0.5).abs() < 0.01,
+            "{}",
+            median.estimate()
+        );
+        assert!((p90.estimate() - 0.9).abs() < 0.01, "{}", p90.estimate());
+    }
+
+    #[test]
+    fn few_samples() {
+        let mut median = P2Quantile::new(0.5);
+        assert!(median.estimate().is_nan());
+
+        for x in [5.0, 1.0, 3.0] {
+            median.observe(x);
+        }
+        assert_eq!(median.estimate(), 3.0);
+
+        // an already sorted stream is the worst case for the markers
+        let mut median = P2Quantile::new(0.5);
+        for x in (0..1001).map(|i| 100.0 + i as f64) {
+            median.observe(x);
+        }
+        assert!(
+            (median.estimate() - 600.0).abs() < 5.0,
+            "{}",
+            median.estimate()
+        );
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,162 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the index of the first occurrence of `needle` in `haystack`
+///
+/// - Inputs:
+///     - `haystack: &[u8]` The bytes to search through
+///     - `needle: &[u8]` The bytes to look for
+/// - Output: `Option<usize>`
+///     - The start of the first match, `None` if there isn't one
+///     - An empty needle matches at 0, like `str::find`
+/// - Side-effects: N/A
+/// - Time complexity: O(n + m)
+///     - `n = haystack.len()`, `m = needle.len()`
+pub fn kmp_search(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    search(haystack, needle, true).pop()
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the index of every occurrence of `needle` in `haystack`,
+/// including overlapping ones
+///
+/// - Inputs:
+///     - `haystack: &[u8]` The bytes to search through
+///     - `needle: &[u8]` The bytes to look for
+/// - Output: `Vec<usize>`
+///     - The start of each match, in increasing order
+///     - An empty needle matches at every index, `0..=haystack.len()`
+/// - Side-effects: N/A
+/// - Time complexity: O(n + m)
+///     - `n = haystack.len()`, `m = needle.len()`
+pub fn kmp_search_all(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
+    search(haystack, needle, false)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the KMP failure table of `needle`
+///
+/// `table[i]` is the length of the longest proper prefix of `needle[..=i]`
+/// that's also a suffix of it. When a match fails after `i + 1` bytes, those
+/// bytes are still known, so the search can carry on as if it had already
+/// matched `table[i]` bytes instead of starting over.
+///
+/// - Time complexity: O(m)
+///     - `m = needle.len()`
+fn failure_table(needle: &[u8]) -> Vec<usize> {
+    let mut table = vec![0; needle.len()];
+
+    // length of the prefix matched so far
+    let mut len = 0;
+
+    for i in 1..needle.len() {
+        // fall back through shorter prefixes until one can be extended
+        while len > 0 && needle[i] != needle[len] {
+            len = table[len - 1];
+        }
+
+        if needle[i] == needle[len] {
+            len += 1;
+        }
+
+        table[i] = len;
+    }
+
+    table
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returns the start of every match of `needle` in `haystack`, stopping
+/// after the first one if `first_only`
+fn search(haystack: &[u8], needle: &[u8], first_only: bool) -> Vec<usize> {
+    if needle.is_empty() {
+        return if first_only {
+            vec![0]
+        } else {
+            (0..=haystack.len()).collect()
+        };
+    }
+
+    let table = failure_table(needle);
+    let mut res = vec![];
+
+    // number of needle bytes matched so far
+    let mut len = 0;
+
+    for (i, byte) in haystack.iter().enumerate() {
+        // on a mismatch, fall back to the longest prefix we know still matches
+        while len > 0 && *byte != needle[len] {
+            len = table[len - 1];
+        }
+
+        if *byte == needle[len] {
+            len += 1;
+        }
+
+        if len == needle.len() {
+            res.push(i + 1 - len);
+
+            if first_only {
+                break;
+            }
+
+            // keep the overlap so the next match can start inside this one
+            len = table[len - 1];
+        }
+    }
+
+    res
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn naive(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
+        (0..=haystack.len())
+            .filter(|&i| haystack[i..].starts_with(needle))
+            .collect()
+    }
+
+    #[test]
+    fn test_kmp() {
+        assert_eq!(kmp_search(b"ababab", b"abab"), Some(0));
+        assert_eq!(kmp_search_all(b"ababab", b"abab"), vec![0, 2]);
+
+        assert_eq!(kmp_search(b"ababab", b"abba"), None);
+        assert_eq!(kmp_search_all(b"ababab", b"abba"), Vec::<usize>::new());
+
+        assert_eq!(kmp_search(b"", b"a"), None);
+        assert_eq!(kmp_search(b"ab", b"abc"), None);
+        assert_eq!(kmp_search(b"abc", b""), Some(0));
+        assert_eq!(kmp_search_all(b"ab", b""), vec![0, 1, 2]);
+
+        assert_eq!(kmp_search_all(b"aaaa", b"aa"), vec![0, 1, 2]);
+        assert_eq!(kmp_search(b"xxabcabcabd", b"abcabd"), Some(5));
+    }
+
+    #[test]
+    fn matches_naive() {
+        let haystack: Vec<u8> = (0..500u32)
+            .map(|i| b"ab"[((i * 7919) % 13 % 2) as usize])
+            .collect();
+
+        for needle in [&b"a"[..], b"ab", b"aab", b"abab", b"bbb", b"abaab"] {
+            assert_eq!(kmp_search_all(&haystack, needle), naive(&haystack, needle));
+        }
+    }
+
+    #[test]
+    fn test_failure_table() {
+        assert_eq!(failure_table(b"abab"), vec![0, 0, 1, 2]);
+        assert_eq!(failure_table(b"aabaaab"), vec![0, 1, 0, 1, 2, 2, 3]);
+        assert_eq!(failure_table(b""), Vec::<usize>::new());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -1,12 +1,29 @@
 ///////////////////////////////////////////////////////////////////////////////
 
 use core::fmt;
+use std::cmp::Ordering;
+
+use crate::algorithms::sort::merge_sort::merge_sort_by;
 
 ///////////////////////////////////////////////////////////////////////////////
 
 /// A binary min-heap
 pub struct BinaryHeap<T>(Vec<T>);
 
+//---------------------------------------------------------------------------//
+
+/// Returned when trying to take an item out of an empty heap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeapEmpty;
+
+impl fmt::Display for HeapEmpty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the heap is empty")
+    }
+}
+
+impl std::error::Error for HeapEmpty {}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 impl<T> BinaryHeap<T>
@@ -50,6 +67,30 @@ where
         BinaryHeap(Self::heapify(source))
     }
 
+    /// Returns a binary heap with the contents of `source`, without cloning
+    ///
+    /// - Inputs:
+    ///     - `source: Vec<T>`
+    ///         The vector to build from (consumed)
+    ///
+    /// - Output: `BinaryHeap<T>`
+    ///     - A binary heap with the contents of `source`
+    ///
+    /// - Side-effects: N/A
+    ///
+    /// - Time complexity: O(n)
+    ///     - `n = source.len()`
+    pub fn from_vec(source: Vec<T>) -> Self {
+        let mut inner = source;
+
+        // shift everything over one slot to make room for the blank
+        inner.insert(0, T::default());
+
+        Self::heapify_in_place(&mut inner);
+
+        BinaryHeap(inner)
+    }
+
     //-----------------------------------------------------------------------//
 
     /// Returns a binary heap ordered vector with the contents of `source`
@@ -80,21 +121,43 @@ where
             inner.push(source[i].clone());
         }
 
+        Self::heapify_in_place(&mut inner);
+
+        inner
+    }
+
+    /// Re-orders a vector (with the blank first item) into binary heap order
+    ///
+    /// - Inputs:
+    ///     - `inner: &mut Vec<T>`
+    ///         The vector to re-order, the first element is ignored
+    ///
+    /// - Output: N/A
+    ///
+    /// - Side-effects:
+    ///     - Moves elements of `inner` into binary heap order
+    ///
+    /// - Time complexity: O(n)
+    ///     - `n = inner.len()`
+    fn heapify_in_place(inner: &mut Vec<T>) {
+        let n = inner.len() - 1;
+
         /*
         Use bubble down to efficiently re-order the inner vector into a binary
         heap.
         The leaves are already in heap order so we can just skip them and start
-        at n/2. We also don't want to mess with the blank so we'll stop at
-        index 1. Rust ranges apparently only go up so we need to re-write this
-        slightly to go from the end to the start and then reverse it.
+        at n/2. With the blank at index 0, node i has children 2i and 2i + 1,
+        so n/2 is the last node with a child at or before n (for even n its
+        only child is n itself, for odd n both n - 1 and n). We also don't
+        want to mess with the blank so we'll stop at index 1. Rust ranges
+        apparently only go up so we need to re-write this slightly to go from
+        the end to the start and then reverse it.
         */
         for i in (1..n / 2 + 1).rev() {
             // move the given node downwards in the tree until it's in heap
             // order
-            Self::bubble_down(&mut inner, i);
+            Self::bubble_down(inner, i);
         }
-
-        inner
     }
 
     //-----------------------------------------------------------------------//
@@ -174,19 +237,20 @@ where
 
         let mut min_index = index;
 
-        // find the biggest child
+        // find the smallest of the node and its children
         for i in 0..2 {
-            // double check the children exist
-            if left + i <= inner.len() - 1 {
-                // check if the child is bigger
+            // double check the children exist (the last item is at
+            // `inner.len() - 1`, which may be a lone left child)
+            if left + i < inner.len() {
+                // check if the child is smaller
                 if inner[min_index] > inner[left + i] {
                     min_index = left + i;
                 }
             }
         }
         /*
-        If the current node isn't the biggest, the sub-tree is out of order,
-        swap places with the biggest node and then continue bubbling down from
+        If the current node isn't the smallest, the sub-tree is out of order,
+        swap places with the smallest node and then continue bubbling down from
         there.
         */
         if min_index != index {
@@ -261,8 +325,8 @@ where
 
         // swap the last leaf and the smallest node at the end of the vector
         self.0.swap(1, size);
-        // remove the smallest node
-        let min = self.0.remove(size);
+        // remove the smallest node, it's the last element so we can just pop
+        let min = self.0.pop().expect("heap was checked to be non-empty");
         // the moved leaf is probably out order, bubble it down
         Self::bubble_down(&mut self.0, 1);
 
@@ -270,6 +334,25 @@ where
         min
     }
 
+    /// Removes and returns the root (smallest item), without panicking on an
+    /// empty heap
+    ///
+    /// - Inputs:
+    ///     - `&mut self`
+    /// - Output: `Result<T, HeapEmpty>`
+    ///     - The smallest item in the heap (`Err(HeapEmpty)` if the heap is
+    ///       empty)
+    /// - Side-effects: Removes the smallest item
+    /// - Time complexity: O(log(n))
+    ///     - `n = self.len() + 1`
+    pub fn try_extract_min(&mut self) -> Result<T, HeapEmpty> {
+        if self.is_empty() {
+            Err(HeapEmpty)
+        } else {
+            Ok(self.extract_min())
+        }
+    }
+
     //-----------------------------------------------------------------------//
 
     /// Returns the index of the given item
@@ -326,11 +409,16 @@ where
         let n = self.len();
         // swap the given node and the last leaf
         self.0.swap(index, n);
-        // remove the given node
-        let val = self.0.remove(n);
+        // remove the given node, it's the last element so we can just pop
+        let val = self.0.pop().expect("index was checked to be in the heap");
 
         // re-order the moved leaf
-        Self::bubble_down(&mut self.0, index);
+        // it came from a different sub-tree, so it might belong either above
+        // or below its new spot
+        if index < self.0.len() {
+            Self::bubble_down(&mut self.0, index);
+            Self::bubble_up(&mut self.0, index);
+        }
 
         // return the value of the removed node
         val
@@ -343,6 +431,28 @@ where
         self.0.len() - 1
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns whether the heap property holds for every node
+    ///
+    /// Mostly useful for testing changes to the heap.
+    ///
+    /// - Inputs:
+    ///     - `&self`
+    /// - Output: `bool`
+    ///     - `true` if every non-root item is no smaller than its parent
+    /// - Side-effects: N/A
+    /// - Time complexity: O(n)
+    ///     - `n = self.len() + 1`
+    pub fn is_valid(&self) -> bool {
+        // skip the blank and the root, neither of them have parents
+        (2..self.0.len()).all(|i| self.0[i / 2] <= self.0[i])
+    }
+
     //-----------------------------------------------------------------------//
 
     /// Returns the contents of the heap as a sorted vector
@@ -367,11 +477,48 @@ where
         res
     }
 
+    /// Returns the contents of the heap sorted by `cmp` instead of the heap's
+    /// own ordering
+    ///
+    /// - Inputs:
+    ///     - `self`
+    ///     - `cmp: F` Returns how two items are ordered
+    /// - Output: `Vec<T>`
+    ///     - The contents of `self`, sorted (stably) by `cmp`
+    /// - Side-effects: N/A
+    /// - Time complexity: O(n log(n))
+    ///     - `n = self.len()`
+    pub fn into_vec_sorted_by<F: Fn(&T, &T) -> Ordering>(self, cmp: F) -> Vec<T> {
+        // heap order isn't any use to a different ordering, so just drop the
+        // blank and sort what's left
+        let mut items = self.0;
+        items.swap_remove(0);
+
+        merge_sort_by(items, cmp)
+    }
+
     //-----------------------------------------------------------------------//
 }
 
 ///////////////////////////////////////////////////////////////////////////////
 
+impl<T> FromIterator<T> for BinaryHeap<T>
+where
+    T: Ord + Clone + Default + fmt::Debug,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = BinaryHeap::new();
+
+        for item in iter {
+            heap.insert(item);
+        }
+
+        heap
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 /// Sorts the given vector
 pub fn heapsort<T>(list: &mut Vec<T>)
 where
@@ -385,6 +532,246 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
+/// A double-ended priority queue (min-max heap)
+///
+/// Like a binary heap, except the levels alternate: every node on an even
+/// level (starting with the root) is the smallest in its sub-tree, and every
+/// node on an odd level is the largest. The root is the minimum and the
+/// larger of its two children is the maximum.
+///
+/// Unlike `BinaryHeap`, the vector is 0-indexed with no blank first item.
+pub struct MinMaxHeap<T: Ord>(Vec<T>);
+
+//---------------------------------------------------------------------------//
+
+impl<T: Ord> MinMaxHeap<T> {
+    //-----------------------------------------------------------------------//
+
+    // Based on Atkinson et al. "Min-Max Heaps and Generalized Priority
+    // Queues" (1986)
+
+    //-----------------------------------------------------------------------//
+
+    /// Creates a new empty min-max heap
+    pub fn new() -> Self {
+        MinMaxHeap(Vec::new())
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Adds `item` to the heap
+    ///
+    /// - Time complexity: O(log(n))
+    pub fn push(&mut self, item: T) {
+        self.0.push(item);
+
+        let index = self.0.len() - 1;
+        if index == 0 {
+            return;
+        }
+
+        /*
+        The new leaf might break the ordering with its parent, which is on the
+        opposite kind of level. If it does, swapping them fixes that pair and
+        the item carries on up the parent's kind of level instead.
+        Either way it only ever gets compared to its grandparents from then on,
+        since those are the levels of the same kind.
+        */
+        let parent = (index - 1) / 2;
+
+        if Self::is_min_level(index) {
+            if self.0[index] > self.0[parent] {
+                self.0.swap(index, parent);
+                self.bubble_up(parent, false);
+            } else {
+                self.bubble_up(index, true);
+            }
+        } else if self.0[index] < self.0[parent] {
+            self.0.swap(index, parent);
+            self.bubble_up(parent, true);
+        } else {
+            self.bubble_up(index, false);
+        }
+    }
+
+    //.......................................................................//
+
+    /// Removes and returns the smallest item
+    ///
+    /// - Output: `None` if the heap is empty
+    /// - Time complexity: O(log(n))
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.pop_at(0)
+    }
+
+    /// Removes and returns the largest item
+    ///
+    /// - Output: `None` if the heap is empty
+    /// - Time complexity: O(log(n))
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.pop_at(self.max_index()?)
+    }
+
+    //.......................................................................//
+
+    /// Returns the smallest item without removing it
+    ///
+    /// - Time complexity: O(1)
+    pub fn peek_min(&self) -> Option<&T> {
+        self.0.first()
+    }
+
+    /// Returns the largest item without removing it
+    ///
+    /// - Time complexity: O(1)
+    pub fn peek_max(&self) -> Option<&T> {
+        self.max_index().map(|index| &self.0[index])
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Whether `index` is on an even (min) level
+    fn is_min_level(index: usize) -> bool {
+        (index + 1).ilog2().is_multiple_of(2)
+    }
+
+    /// Where the largest item is: the root if it's alone, otherwise the larger
+    /// of its children
+    fn max_index(&self) -> Option<usize> {
+        match self.0.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ => Some(if self.0[1] >= self.0[2] { 1 } else { 2 }),
+        }
+    }
+
+    //.......................................................................//
+
+    /// Removes the item at `index`, filling the gap with the last leaf
+    fn pop_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.0.len() {
+            return None;
+        }
+
+        let item = self.0.swap_remove(index);
+
+        if index < self.0.len() {
+            self.trickle_down(index);
+        }
+
+        Some(item)
+    }
+
+    //.......................................................................//
+
+    /// Moves the item at `index` up through its grandparents, which are the
+    /// same kind of level (`min` for even levels)
+    fn bubble_up(&mut self, mut index: usize, min: bool) {
+        while index > 2 {
+            let grandparent = ((index - 1) / 2 - 1) / 2;
+
+            let out_of_order = if min {
+                self.0[index] < self.0[grandparent]
+            } else {
+                self.0[index] > self.0[grandparent]
+            };
+
+            if !out_of_order {
+                break;
+            }
+
+            self.0.swap(index, grandparent);
+            index = grandparent;
+        }
+    }
+
+    //.......................................................................//
+
+    /// Moves the item at `index` down until its sub-tree is back in order
+    fn trickle_down(&mut self, mut index: usize) {
+        let min = Self::is_min_level(index);
+
+        // whether `a` belongs above `b` on this kind of level
+        let before = |a: &T, b: &T| if min { a < b } else { a > b };
+
+        loop {
+            // the most extreme of the (up to 6) children and grandchildren
+            let first_child = 2 * index + 1;
+            let first_grandchild = 2 * first_child + 1;
+
+            let Some(best) = (first_child..first_child + 2)
+                .chain(first_grandchild..first_grandchild + 4)
+                .filter(|i| *i < self.0.len())
+                .reduce(|best, i| {
+                    if before(&self.0[i], &self.0[best]) {
+                        i
+                    } else {
+                        best
+                    }
+                })
+            else {
+                break;
+            };
+
+            if !before(&self.0[best], &self.0[index]) {
+                break;
+            }
+
+            self.0.swap(index, best);
+
+            // a child is on the other kind of level and has no grandchildren
+            // to worry about, so we're done
+            if best < first_grandchild {
+                break;
+            }
+
+            // the item we just moved down might not fit with its new parent
+            // (on the other kind of level), in which case they trade places
+            let parent = (best - 1) / 2;
+            if before(&self.0[parent], &self.0[best]) {
+                self.0.swap(best, parent);
+            }
+
+            index = best;
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+//---------------------------------------------------------------------------//
+
+impl<T: Ord> Default for MinMaxHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for MinMaxHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = MinMaxHeap::new();
+
+        for item in iter {
+            heap.push(item);
+        }
+
+        heap
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
 
@@ -401,8 +788,10 @@ mod tests {
         let mut heap = BinaryHeap::new();
 
         assert_eq!(heap.len(), 0);
+        assert!(heap.is_empty());
         heap.insert(5);
         assert_eq!(heap.len(), 1);
+        assert!(!heap.is_empty());
         heap.insert(3);
         assert_eq!(heap.len(), 2);
         heap.insert(7);
@@ -490,6 +879,41 @@ mod tests {
 
     //-----------------------------------------------------------------------//
 
+    #[test]
+    fn validity() {
+        for i in 0..100 {
+            let list: Vec<usize> = (0..i).map(|j| (j * 37 + 11) % 53).collect();
+
+            let mut heap = BinaryHeap::from_slice(&list);
+            assert!(heap.is_valid());
+
+            for j in 0..i {
+                heap.insert((j * 17) % 29);
+                assert!(heap.is_valid());
+            }
+
+            for j in 0..i {
+                heap.remove(&((j * 37 + 11) % 53));
+                assert!(heap.is_valid());
+
+                if j % 3 == 0 && !heap.is_empty() {
+                    heap.extract_min();
+                    assert!(heap.is_valid());
+                }
+            }
+        }
+
+        // removing a leaf from one sub-tree moves a leaf from another sub-tree
+        // into its spot, which has to bubble up
+        let mut heap = BinaryHeap::from_slice(&[1, 10, 2, 11, 12, 3, 4]);
+        assert!(heap.is_valid());
+        heap.remove(&11);
+        assert!(heap.is_valid());
+        assert_eq!(heap.into_sorted_vec(), vec![1, 2, 3, 4, 10, 12]);
+    }
+
+    //-----------------------------------------------------------------------//
+
     #[test]
     fn sorting() {
         for i in 0..1000 {
@@ -514,6 +938,221 @@ mod tests {
         }
     }
 
+    #[test]
+    fn heapsort_degenerate() {
+        for case in [vec![], vec![1], vec![4, 4], vec![7; 1000]] {
+            let mut real = case.clone();
+            heapsort(&mut real);
+            assert_eq!(real, case);
+        }
+
+        // zero-sized elements
+        for len in [0, 1, 1000] {
+            let mut real = vec![(); len];
+            heapsort(&mut real);
+            assert_eq!(real.len(), len);
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    thread_local! {
+        static CLONES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    // counts every clone made on the current thread
+    #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+    struct Counted(i32);
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            CLONES.with(|clones| clones.set(clones.get() + 1));
+            Counted(self.0)
+        }
+    }
+
+    #[test]
+    fn from_vec() {
+        for i in 0..100 {
+            let arr: Vec<i32> = (0..i).map(|j| (j * 7919) % 53).collect();
+
+            let from_slice = BinaryHeap::from_slice(&arr);
+            let from_vec = BinaryHeap::from_vec(arr);
+
+            assert!(from_vec.is_valid());
+            assert_eq!(from_vec.into_sorted_vec(), from_slice.into_sorted_vec());
+        }
+
+        let arr: Vec<Counted> = (0..1000).map(|j| Counted((j * 7919) % 53)).collect();
+
+        CLONES.with(|clones| clones.set(0));
+        let heap = BinaryHeap::from_vec(arr);
+        assert_eq!(CLONES.with(|clones| clones.get()), 0);
+
+        assert!(heap.is_valid());
+        assert_eq!(heap.len(), 1000);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn large_extract() {
+        // extracting stays O(log(n)), so draining a big heap is quick
+        let n = 100_000;
+        let mut heap = BinaryHeap::from_vec((0..n).map(|i| (i * 7919) % n).collect());
+
+        for i in 0..n {
+            assert_eq!(heap.extract_min(), i);
+        }
+
+        assert!(heap.is_empty());
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn try_extract_min() {
+        // drains the heap, then tries once more
+        fn drain(heap: &mut BinaryHeap<i32>, out: &mut Vec<i32>) -> Result<(), HeapEmpty> {
+            loop {
+                out.push(heap.try_extract_min()?);
+            }
+        }
+
+        let mut heap = BinaryHeap::from_vec(vec![5, 3, 9, 1]);
+        let mut out = vec![];
+
+        assert_eq!(drain(&mut heap, &mut out), Err(HeapEmpty));
+        assert_eq!(out, vec![1, 3, 5, 9]);
+        assert_eq!(heap.try_extract_min(), Err(HeapEmpty));
+        assert_eq!(HeapEmpty.to_string(), "the heap is empty");
+    }
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn heapify_every_length() {
+        for n in 0..=256 {
+            let cases: [Vec<i32>; 4] = [
+                (0..n).collect(),
+                (0..n).rev().collect(),
+                (0..n).map(|i| (i * 7919) % 17).collect(),
+                // the smallest item last, so it has to climb all the way up
+                // from the very last (possibly lone) leaf
+                (0..n).map(|i| if i == n - 1 { -1 } else { i }).collect(),
+            ];
+
+            for case in cases {
+                let mut expected = case.clone();
+                expected.sort();
+
+                let heap = BinaryHeap::from_slice(&case);
+                assert!(heap.is_valid(), "from_slice, len {}: {:?}", n, case);
+                assert_eq!(heap.len(), n as usize);
+                assert_eq!(heap.into_sorted_vec(), expected);
+
+                let heap = BinaryHeap::from_vec(case.clone());
+                assert!(heap.is_valid(), "from_vec, len {}: {:?}", n, case);
+                assert_eq!(heap.into_sorted_vec(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn min_max_heap() {
+        let mut heap = MinMaxHeap::new();
+
+        assert_eq!(heap.peek_min(), None);
+        assert_eq!(heap.peek_max(), None);
+        assert_eq!(heap.pop_min(), None);
+        assert_eq!(heap.pop_max(), None);
+
+        for item in [5, 1, 9, 3, 7, 3, 0, 8] {
+            heap.push(item);
+        }
+
+        assert_eq!(heap.len(), 8);
+        assert_eq!(heap.peek_min(), Some(&0));
+        assert_eq!(heap.peek_max(), Some(&9));
+
+        // take from both ends, interleaved with more pushes
+        assert_eq!(heap.pop_min(), Some(0));
+        assert_eq!(heap.pop_max(), Some(9));
+        heap.push(10);
+        heap.push(-1);
+        assert_eq!(heap.pop_max(), Some(10));
+        assert_eq!(heap.pop_min(), Some(-1));
+        assert_eq!(heap.pop_min(), Some(1));
+
+        let rest: Vec<i32> = std::iter::from_fn(|| heap.pop_max()).collect();
+        assert_eq!(rest, vec![8, 7, 5, 3, 3]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn min_max_heap_random() {
+        let mut rng = crate::meta::rng::XorShift::new(13);
+
+        for len in 0..200 {
+            let items: Vec<usize> = (0..len).map(|_| rng.next_index(50)).collect();
+            let mut sorted = items.clone();
+            sorted.sort();
+
+            let mut heap: MinMaxHeap<usize> = items.iter().copied().collect();
+            let ascending: Vec<usize> = std::iter::from_fn(|| heap.pop_min()).collect();
+            assert_eq!(ascending, sorted);
+
+            let mut heap: MinMaxHeap<usize> = items.iter().copied().collect();
+            let descending: Vec<usize> = std::iter::from_fn(|| heap.pop_max()).collect();
+            assert_eq!(descending, sorted.iter().rev().copied().collect::<Vec<_>>());
+
+            // pops from random ends, checked against the sorted copy
+            let mut heap: MinMaxHeap<usize> = items.iter().copied().collect();
+            let mut expected: std::collections::VecDeque<usize> = sorted.into();
+
+            while !expected.is_empty() {
+                assert_eq!(heap.peek_min(), expected.front());
+                assert_eq!(heap.peek_max(), expected.back());
+
+                if rng.next_index(2) == 0 {
+                    assert_eq!(heap.pop_min(), expected.pop_front());
+                } else {
+                    assert_eq!(heap.pop_max(), expected.pop_back());
+                }
+            }
+            assert!(heap.is_empty());
+        }
+    }
+
+    #[test]
+    fn into_vec_sorted_by() {
+        // derived ordering goes by priority first
+        #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+        struct Task {
+            priority: u32,
+            name: &'static str,
+        }
+
+        let heap: BinaryHeap<Task> = [(3, "c"), (1, "e"), (2, "a"), (5, "d"), (4, "b")]
+            .into_iter()
+            .map(|(priority, name)| Task { priority, name })
+            .collect();
+
+        let by_name = heap.into_vec_sorted_by(|a, b| a.name.cmp(b.name));
+
+        assert_eq!(
+            by_name.iter().map(|task| task.name).collect::<Vec<_>>(),
+            vec!["a", "b", "c", "d", "e"]
+        );
+        assert_eq!(
+            by_name.iter().map(|task| task.priority).collect::<Vec<_>>(),
+            vec![2, 4, 3, 5, 1]
+        );
+
+        assert_eq!(
+            BinaryHeap::<i32>::new().into_vec_sorted_by(i32::cmp),
+            Vec::<i32>::new()
+        );
+    }
     //-----------------------------------------------------------------------//
 }
 
@@ -0,0 +1,183 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A Fenwick tree (binary indexed tree) keeps running totals of an array so that
+both changing an element and summing a prefix take O(log n).
+
+A plain array makes updates O(1) but sums O(n), and a prefix sum array is the
+other way around. The Fenwick tree sits in between: each slot stores the sum
+of a block of elements whose size is the lowest set bit of its (1-based)
+index, so any prefix splits into at most log(n) blocks, and any element is in
+at most log(n) blocks.
+
+    index (1-based):  1    2    3    4    5    6    7    8
+    covers:          [1] [1-2] [3] [1-4] [5] [5-6] [7] [1-8]
+
+See https://en.wikipedia.org/wiki/Fenwick_tree
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use std::ops::{Add, Sub};
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct FenwickTree<T> {
+    // tree[i - 1] holds the block for the 1-based index i
+    tree: Vec<T>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> FenwickTree<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Default + Clone,
+{
+    //-----------------------------------------------------------------------//
+
+    /// Creates a tree over `n` elements, all `T::default()`
+    ///
+    /// - Time complexity: O(n)
+    pub fn new(n: usize) -> Self {
+        Self {
+            tree: vec![T::default(); n],
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Adds `delta` to the element at index `i`
+    ///
+    /// - Inputs:
+    ///     - `i: usize` The (0-based) index to change, panics if out of
+    ///       bounds
+    ///     - `delta: T` The amount to add
+    /// - Time complexity: O(log(n))
+    pub fn update(&mut self, i: usize, delta: T) {
+        assert!(i < self.len(), "index {} out of bounds", i);
+
+        // walk up through every block that contains i, each time jumping
+        // past the current block by adding its lowest set bit
+        let mut index = i + 1;
+
+        while index <= self.len() {
+            self.tree[index - 1] = self.tree[index - 1].clone() + delta.clone();
+            index += lowest_bit(index);
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the sum of the first `i` elements (indices `0..i`)
+    ///
+    /// - Inputs:
+    ///     - `i: usize` How many elements to sum, panics if more than `len`
+    /// - Time complexity: O(log(n))
+    pub fn prefix_sum(&self, i: usize) -> T {
+        assert!(i <= self.len(), "prefix {} out of bounds", i);
+
+        // walk down, each time stripping the block we just added
+        let mut sum = T::default();
+        let mut index = i;
+
+        while index > 0 {
+            sum = sum + self.tree[index - 1].clone();
+            index -= lowest_bit(index);
+        }
+
+        sum
+    }
+
+    //.......................................................................//
+
+    /// Returns the sum of the elements at indices `lo..hi`
+    ///
+    /// - Time complexity: O(log(n))
+    pub fn range_sum(&self, lo: usize, hi: usize) -> T {
+        assert!(lo <= hi, "range {}..{} is backwards", lo, hi);
+
+        self.prefix_sum(hi) - self.prefix_sum(lo)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returns the lowest set bit of `n` (e.g. 12 = 0b1100 -> 4)
+fn lowest_bit(n: usize) -> usize {
+    n & n.wrapping_neg()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use crate::meta::rng::XorShift;
+
+    use super::*;
+
+    #[test]
+    fn basics() {
+        let mut tree = FenwickTree::new(8);
+
+        assert_eq!(tree.len(), 8);
+        assert_eq!(tree.prefix_sum(8), 0);
+
+        for (i, value) in [5, 2, 9, -3, 4, 0, 1, 7].into_iter().enumerate() {
+            tree.update(i, value);
+        }
+
+        assert_eq!(tree.prefix_sum(0), 0);
+        assert_eq!(tree.prefix_sum(1), 5);
+        assert_eq!(tree.prefix_sum(4), 13);
+        assert_eq!(tree.prefix_sum(8), 25);
+        assert_eq!(tree.range_sum(2, 5), 10);
+        assert_eq!(tree.range_sum(3, 3), 0);
+
+        assert!(FenwickTree::<i32>::new(0).is_empty());
+    }
+
+    #[test]
+    fn random_cases() {
+        let mut rng = XorShift::new(5);
+
+        for n in [1, 2, 7, 64, 100] {
+            let mut tree = FenwickTree::new(n);
+            let mut reference = vec![0i64; n];
+
+            for _ in 0..1000 {
+                let i = rng.next_index(n);
+                let delta = rng.next_index(201) as i64 - 100;
+
+                tree.update(i, delta);
+                reference[i] += delta;
+
+                let lo = rng.next_index(n + 1);
+                let hi = lo + rng.next_index(n + 1 - lo);
+
+                assert_eq!(tree.prefix_sum(hi), reference[..hi].iter().sum::<i64>());
+                assert_eq!(
+                    tree.range_sum(lo, hi),
+                    reference[lo..hi].iter().sum::<i64>()
+                );
+            }
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -1,25 +1,44 @@
 ///////////////////////////////////////////////////////////////////////////////
 
 use std::{
-    collections::{HashMap, HashSet},
-    fmt::{self, Debug},
-    hash::Hash,
+    collections::{hash_map::RandomState, HashMap, HashSet},
+    fmt::{self, Debug, Display},
+    hash::{BuildHasher, Hash},
+    str::FromStr,
 };
 
-use super::{undirected_graph::UndirectedGraph, IDefiniteGraph, IGraph, IGraphEdgeMut, IGraphMut};
+use super::{
+    frozen_graph::FrozenGraph, undirected_graph::UndirectedGraph, IDefiniteGraph, IGraph,
+    IGraphEdgeMut, IGraphMut, ParseError,
+};
+use crate::meta::fnv::FnvBuildHasher;
 
 ///////////////////////////////////////////////////////////////////////////////
 
+/// A directed graph
+///
+/// `S` is the hasher used for the adjacency map, see `new_deterministic`.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct DirectedGraph<T>
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, S: Default"
+    ))
+)]
+pub struct DirectedGraph<T, S = RandomState>
 where
     T: Ord + fmt::Debug + Hash + Clone,
+    S: BuildHasher,
 {
     // Map (node -> set of adj nodes)
-    adj: HashMap<T, HashSet<T>>,
+    adj: HashMap<T, HashSet<T, S>, S>,
 }
 
+/// A directed graph that iterates in the same order every run
+pub type DeterministicDirectedGraph<T> = DirectedGraph<T, FnvBuildHasher>;
+
 ///////////////////////////////////////////////////////////////////////////////
 
 impl<T> DirectedGraph<T>
@@ -35,18 +54,325 @@ where
     }
 
     //-----------------------------------------------------------------------//
+
+    /// Builds a graph from an adjacency map
+    ///
+    /// Neighbors that aren't keys of the map are added as nodes too, so no
+    /// edges are lost.
+    pub fn from_adjacency_map(map: HashMap<T, Vec<T>>) -> Self {
+        let mut adj: HashMap<T, HashSet<T>> = HashMap::new();
+
+        for (node, links) in map {
+            for link in &links {
+                adj.entry(link.clone()).or_default();
+            }
+
+            adj.entry(node).or_default().extend(links);
+        }
+
+        Self { adj }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Parses a graph from the format written by `to_edge_list`
+    ///
+    /// Each line is either `from to` (an edge, creating both nodes if
+    /// needed) or a lone `node`. Blank lines and lines starting with `#` are
+    /// skipped.
+    ///
+    /// - Output:
+    ///     - `Err(ParseError)` for the first line with the wrong number of
+    ///       fields or a node that doesn't parse as `T`
+    pub fn from_edge_list(s: &str) -> Result<Self, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut adj: HashMap<T, HashSet<T>> = HashMap::new();
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |message: String| ParseError {
+                line: index + 1,
+                message,
+            };
+
+            let parse = |field: &str| {
+                field
+                    .parse::<T>()
+                    .map_err(|err| error(format!("couldn't parse node `{}`: {}", field, err)))
+            };
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+
+            match fields[..] {
+                [node] => {
+                    adj.entry(parse(node)?).or_default();
+                }
+                [from, to] => {
+                    let (from, to) = (parse(from)?, parse(to)?);
+
+                    adj.entry(to.clone()).or_default();
+                    adj.entry(from).or_default().insert(to);
+                }
+                _ => {
+                    return Err(error(format!(
+                        "expected `from to` or a single node, found {} fields in `{}`",
+                        fields.len(),
+                        line
+                    )))
+                }
+            }
+        }
+
+        Ok(Self { adj })
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+//---------------------------------------------------------------------------//
+
+impl<T> DeterministicDirectedGraph<T>
+where
+    T: Ord + fmt::Debug + Hash + Clone,
+{
+    //-----------------------------------------------------------------------//
+
+    /// Creates an empty graph that hashes with a fixed (unseeded) hasher
+    ///
+    /// The default hasher is randomly seeded per process, so the order of
+    /// `get_all` (and everything built on it, like the DFS roots) changes
+    /// from run to run. Graphs from this constructor that are built the same
+    /// way always list their nodes in the same order.
+    ///
+    /// `get_adj` still hands back a standard `HashSet`, so the order
+    /// neighbors are visited in isn't covered.
+    pub fn new_deterministic() -> Self {
+        Self {
+            adj: HashMap::default(),
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T, S> DirectedGraph<T, S>
+where
+    T: Ord + fmt::Debug + Hash + Clone,
+    S: BuildHasher + Default + Clone,
+{
+    //-----------------------------------------------------------------------//
+
+    /// Wraps the graph so it can't be changed any more, see `FrozenGraph`
+    pub fn freeze(self) -> FrozenGraph<Self> {
+        FrozenGraph::new(self)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the subgraph made of only the given nodes, keeping just the
+    /// edges with both endpoints in `nodes`
+    ///
+    /// Nodes in `nodes` that aren't in the graph are ignored.
+    pub fn induced_subgraph(&self, nodes: &HashSet<T>) -> Self {
+        Self {
+            adj: self
+                .adj
+                .iter()
+                .filter(|(node, _)| nodes.contains(node))
+                .map(|(node, links)| {
+                    let links = links
+                        .iter()
+                        .filter(|link| nodes.contains(link))
+                        .cloned()
+                        .collect();
+                    (node.clone(), links)
+                })
+                .collect(),
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the fraction of possible edges that are present
+    ///
+    /// Each ordered pair of distinct nodes can have an edge, so there are
+    /// `V * (V - 1)` possible edges. Self-loops are ignored.
+    /// Graphs with fewer than two nodes have a density of 0.
+    pub fn density(&self) -> f64 {
+        let nodes = self.adj.len();
+
+        if nodes < 2 {
+            return 0.0;
+        }
+
+        let edges = self
+            .adj
+            .iter()
+            .map(|(node, links)| links.len() - links.contains(node) as usize)
+            .sum::<usize>();
+
+        edges as f64 / (nodes * (nodes - 1)) as f64
+    }
+
+    //.......................................................................//
+
+    /// Returns whether any node has an edge to itself
+    pub fn has_self_loops(&self) -> bool {
+        self.adj.iter().any(|(node, links)| links.contains(node))
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns every node with no incoming edges, sorted
+    ///
+    /// Nodes don't track their incoming edges, so this has to scan every
+    /// edge in the graph.
+    ///
+    /// - Time complexity: O(V + E)
+    pub fn sources(&self) -> Vec<T> {
+        let targets: HashSet<&T> = self.adj.values().flatten().collect();
+
+        let mut sources: Vec<T> = self
+            .adj
+            .keys()
+            .filter(|node| !targets.contains(node))
+            .cloned()
+            .collect();
+        sources.sort();
+        sources
+    }
+
+    //.......................................................................//
+
+    /// Returns every node with no outgoing edges, sorted
+    ///
+    /// - Time complexity: O(V)
+    pub fn sinks(&self) -> Vec<T> {
+        let mut sinks: Vec<T> = self
+            .adj
+            .iter()
+            .filter(|(_, links)| links.is_empty())
+            .map(|(node, _)| node.clone())
+            .collect();
+        sinks.sort();
+        sinks
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Writes the graph as text, one `from to` edge per line
+    ///
+    /// Nodes without any edges get a line to themselves so they aren't lost.
+    /// Lines are sorted, so the output doesn't depend on hash order. Nodes
+    /// are written with `Display` and fields are separated by spaces, so
+    /// nodes shouldn't display with whitespace in them.
+    ///
+    /// See `from_edge_list` to read it back.
+    pub fn to_edge_list(&self) -> String
+    where
+        T: Display,
+    {
+        let targets: HashSet<&T> = self.adj.values().flatten().collect();
+
+        let mut lines: Vec<(&T, Option<&T>)> = vec![];
+
+        for (node, links) in &self.adj {
+            if links.is_empty() && !targets.contains(node) {
+                lines.push((node, None));
+            }
+
+            lines.extend(links.iter().map(|link| (node, Some(link))));
+        }
+
+        lines.sort();
+
+        let mut res = String::new();
+
+        for line in lines {
+            match line {
+                (node, None) => res += &format!("{}\n", node),
+                (from, Some(to)) => res += &format!("{} {}\n", from, to),
+            }
+        }
+
+        res
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns a copy of the adjacency map, with each node's neighbors sorted
+    pub fn to_adjacency_map(&self) -> HashMap<T, Vec<T>> {
+        self.adj
+            .iter()
+            .map(|(node, links)| {
+                let mut links: Vec<T> = links.iter().cloned().collect();
+                links.sort();
+                (node.clone(), links)
+            })
+            .collect()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns a copy of the graph with every node relabeled by `f`
+    ///
+    /// Edges follow their endpoints to the new labels. If `f` gives two
+    /// distinct nodes the same label they're merged into one node with the
+    /// edges of both (debug builds print a warning when this happens).
+    pub fn map_nodes<U, F>(&self, f: F) -> DirectedGraph<U, S>
+    where
+        U: Ord + fmt::Debug + Hash + Clone,
+        F: Fn(&T) -> U,
+    {
+        let labels: HashMap<&T, U> = self.adj.keys().map(|node| (node, f(node))).collect();
+
+        let mut adj: HashMap<U, HashSet<U, S>, S> = HashMap::default();
+
+        for (node, links) in &self.adj {
+            adj.entry(labels[node].clone())
+                .or_default()
+                .extend(links.iter().map(|link| labels[link].clone()));
+        }
+
+        #[cfg(debug_assertions)]
+        if adj.len() < self.adj.len() {
+            eprintln!(
+                "warning: map_nodes merged {} nodes into {} labels",
+                self.adj.len(),
+                adj.len()
+            );
+        }
+
+        DirectedGraph { adj }
+    }
+
+    //-----------------------------------------------------------------------//
 }
 
 ///////////////////////////////////////////////////////////////////////////////
 
-impl<T> IGraph for DirectedGraph<T>
+impl<T, S> IGraph for DirectedGraph<T, S>
 where
     T: Ord + fmt::Debug + Hash + Clone + Default + fmt::Debug,
+    S: BuildHasher + Default + Clone,
 {
     type Node = T;
 
     fn get_adj(&self, node: &Self::Node) -> HashSet<Self::Node> {
-        self.adj.get(&node).cloned().unwrap_or_default()
+        self.adj
+            .get(node)
+            .map(|links| links.iter().cloned().collect())
+            .unwrap_or_default()
     }
 
     fn contains(&self, item: &Self::Node) -> bool {
@@ -56,9 +382,10 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
-impl<T> IDefiniteGraph for DirectedGraph<T>
+impl<T, S> IDefiniteGraph for DirectedGraph<T, S>
 where
     T: Ord + fmt::Debug + Hash + Clone + Default + fmt::Debug,
+    S: BuildHasher + Default + Clone,
 {
     fn get_all(&self) -> Vec<Self::Node> {
         self.adj.keys().cloned().collect()
@@ -71,14 +398,15 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
-impl<T> IGraphMut for DirectedGraph<T>
+impl<T, S> IGraphMut for DirectedGraph<T, S>
 where
     T: Ord + fmt::Debug + Hash + Clone + Default + fmt::Debug,
+    S: BuildHasher + Default + Clone,
 {
     //-----------------------------------------------------------------------//
 
     fn insert_node(&mut self, node: Self::Node) {
-        self.adj.insert(node.clone(), HashSet::new());
+        self.adj.insert(node.clone(), HashSet::default());
     }
 
     fn remove_node(&mut self, node: Self::Node) {
@@ -90,9 +418,10 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
-impl<T> IGraphEdgeMut for DirectedGraph<T>
+impl<T, S> IGraphEdgeMut for DirectedGraph<T, S>
 where
     T: Ord + fmt::Debug + Hash + Clone + Default + fmt::Debug,
+    S: BuildHasher + Default + Clone,
 {
     //-----------------------------------------------------------------------//
     fn insert_edge(&mut self, from: Self::Node, to: Self::Node) {
@@ -101,9 +430,11 @@ where
         }
     }
 
-    fn remove_edge(&mut self, from: Self::Node, to: Self::Node) {
+    fn remove_edge(&mut self, from: Self::Node, to: Self::Node) -> bool {
         if let Some(links) = self.adj.get_mut(&from) {
-            links.remove(&to);
+            links.remove(&to)
+        } else {
+            false
         }
     }
 
@@ -112,6 +443,25 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
+impl<T, S> PartialEq for DirectedGraph<T, S>
+where
+    T: Ord + fmt::Debug + Hash + Clone,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.adj == other.adj
+    }
+}
+
+impl<T, S> Eq for DirectedGraph<T, S>
+where
+    T: Ord + fmt::Debug + Hash + Clone,
+    S: BuildHasher,
+{
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 impl<T> From<UndirectedGraph<T>> for DirectedGraph<T>
 where
     T: Ord + fmt::Debug + Hash + Clone,
@@ -125,6 +475,42 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
+/// Adds an edge for every `(from, to)` pair, creating any missing
+/// endpoints along the way
+impl<T, S> Extend<(T, T)> for DirectedGraph<T, S>
+where
+    T: Ord + fmt::Debug + Hash + Clone + Default + fmt::Debug,
+    S: BuildHasher + Default + Clone,
+{
+    fn extend<I: IntoIterator<Item = (T, T)>>(&mut self, iter: I) {
+        for (from, to) in iter {
+            // `insert_node` would clear the edges of existing nodes
+            self.adj.entry(from.clone()).or_default();
+            self.adj.entry(to.clone()).or_default();
+
+            self.insert_edge(from, to);
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<T, S> FromIterator<(T, T)> for DirectedGraph<T, S>
+where
+    T: Ord + fmt::Debug + Hash + Clone + Default + fmt::Debug,
+    S: BuildHasher + Default + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        let mut graph = Self {
+            adj: HashMap::default(),
+        };
+        graph.extend(iter);
+        graph
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
     //-----------------------------------------------------------------------//
@@ -198,7 +584,7 @@ mod tests {
                 assert_eq!(graph.len(), i - j - 1);
             }
 
-            // TODO: fix, this currently test non-existent nodes
+            // these nodes are gone, so `get_adj` can only give back nothing
             for j in 1..i / 2 {
                 println!("> {:?}", graph);
                 let adj = graph.get_adj(&j);
@@ -206,6 +592,7 @@ mod tests {
 
                 assert!(!adj.contains(&(i - j)));
                 assert_eq!(adj.len(), 0);
+                assert_eq!(graph.try_get_adj(&j), None);
             }
 
             let mut graph = DirectedGraph::new();
@@ -274,6 +661,22 @@ mod tests {
 
     //-----------------------------------------------------------------------//
 
+    #[test]
+    fn remove_edge_result() {
+        let mut graph = DirectedGraph::new();
+
+        graph.insert_node(1);
+        graph.insert_node(2);
+        graph.insert_edge(1, 2);
+
+        assert!(!graph.remove_edge(2, 1));
+        assert!(graph.remove_edge(1, 2));
+        assert!(!graph.remove_edge(1, 2));
+        assert!(!graph.remove_edge(3, 1));
+    }
+
+    //-----------------------------------------------------------------------//
+
     #[test]
     fn bfs_search() {
         for i in vec![0, 1, 2, 3] {
@@ -352,6 +755,254 @@ mod tests {
     }
 
     //-----------------------------------------------------------------------//
+
+    #[test]
+    fn adjacency_map() {
+        let mut graph = DirectedGraph::new();
+
+        for i in 0..20 {
+            graph.insert_node(i);
+        }
+
+        for i in 0..20 {
+            graph.insert_edge(i, (i * 7) % 20);
+            graph.insert_edge(i, (i * 3 + 1) % 20);
+        }
+
+        let map = graph.to_adjacency_map();
+
+        assert_eq!(map.len(), 20);
+        assert_eq!(map[&3], vec![1, 10]);
+        assert!(map
+            .values()
+            .all(|links| links.windows(2).all(|w| w[0] < w[1])));
+
+        assert_eq!(DirectedGraph::from_adjacency_map(map), graph);
+
+        // neighbors missing from the keys still become nodes
+        let graph = DirectedGraph::from_adjacency_map(HashMap::from([(1, vec![2])]));
+
+        assert_eq!(graph.len(), 2);
+        assert!(graph.get_adj(&1).contains(&2));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn induced_subgraph() {
+        let mut graph = DirectedGraph::new();
+
+        for i in 0..10 {
+            graph.insert_node(i);
+        }
+
+        for i in 0..10 {
+            graph.insert_edge(i, (i + 1) % 10);
+            graph.insert_edge(i, (i * 3) % 10);
+        }
+
+        let sub = graph.induced_subgraph(&HashSet::from([1, 2, 3, 42]));
+
+        assert_eq!(sub.len(), 3);
+        assert!(!sub.contains(&42));
+        assert_eq!(sub.get_adj(&1), HashSet::from([2, 3]));
+        assert_eq!(sub.get_adj(&2), HashSet::from([3]));
+        assert_eq!(sub.get_adj(&3), HashSet::new());
+
+        assert!(graph.induced_subgraph(&HashSet::new()).is_empty());
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn density() {
+        let mut graph = DirectedGraph::new();
+
+        assert_eq!(graph.density(), 0.0);
+        assert!(!graph.has_self_loops());
+
+        for i in 0..5 {
+            graph.insert_node(i);
+        }
+
+        assert_eq!(graph.density(), 0.0);
+
+        for i in 0..5 {
+            for j in 0..5 {
+                if i != j {
+                    graph.insert_edge(i, j);
+                }
+            }
+        }
+
+        assert!((graph.density() - 1.0).abs() < 1e-9);
+        assert!(!graph.has_self_loops());
+
+        graph.insert_edge(3, 3);
+
+        assert!(graph.has_self_loops());
+        assert!((graph.density() - 1.0).abs() < 1e-9);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn map_nodes() {
+        let mut graph = DirectedGraph::new();
+
+        for i in 0..6 {
+            graph.insert_node(i);
+        }
+
+        graph.insert_edge(0, 1);
+        graph.insert_edge(1, 2);
+        graph.insert_edge(2, 0);
+        graph.insert_edge(3, 4);
+        graph.insert_edge(4, 4);
+
+        let doubled = graph.map_nodes(|node| node * 2);
+
+        assert_eq!(doubled.len(), graph.len());
+
+        for node in graph.get_all() {
+            let expected: HashSet<i32> = graph.get_adj(&node).iter().map(|n| n * 2).collect();
+            assert_eq!(doubled.get_adj(&(node * 2)), expected);
+        }
+
+        // collapsing labels merges the nodes and their edges
+        let parity = graph.map_nodes(|node| node % 2);
+
+        assert_eq!(parity.len(), 2);
+        assert_eq!(parity.get_adj(&0), HashSet::from([0, 1]));
+        assert_eq!(parity.get_adj(&1), HashSet::from([0]));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn sources_and_sinks() {
+        let mut graph = DirectedGraph::new();
+
+        assert!(graph.sources().is_empty());
+        assert!(graph.sinks().is_empty());
+
+        graph.insert_node("A");
+        graph.insert_node("B");
+        graph.insert_node("C");
+        graph.insert_edge("A", "B");
+        graph.insert_edge("B", "C");
+
+        assert_eq!(graph.sources(), vec!["A"]);
+        assert_eq!(graph.sinks(), vec!["C"]);
+
+        // an isolated node is both
+        graph.insert_node("D");
+
+        assert_eq!(graph.sources(), vec!["A", "D"]);
+        assert_eq!(graph.sinks(), vec!["C", "D"]);
+
+        // a cycle has neither
+        graph.insert_edge("C", "A");
+
+        assert_eq!(graph.sources(), vec!["D"]);
+        assert_eq!(graph.sinks(), vec!["D"]);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn from_edges() {
+        let mut graph: DirectedGraph<i32> =
+            vec![(1, 2), (1, 3), (2, 3), (3, 1)].into_iter().collect();
+
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.get_adj(&1), HashSet::from([2, 3]));
+        assert_eq!(graph.get_adj(&2), HashSet::from([3]));
+        assert_eq!(graph.get_adj(&3), HashSet::from([1]));
+
+        // existing nodes keep their edges
+        graph.extend([(3, 4), (4, 4)]);
+
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.get_adj(&1), HashSet::from([2, 3]));
+        assert_eq!(graph.get_adj(&3), HashSet::from([1, 4]));
+        assert_eq!(graph.get_adj(&4), HashSet::from([4]));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn deterministic() {
+        let build = || {
+            let mut graph = DirectedGraph::new_deterministic();
+
+            for i in 0..200 {
+                graph.insert_node(i * 7919);
+            }
+
+            for i in 0..199 {
+                graph.insert_edge(i * 7919, (i + 1) * 7919);
+            }
+
+            graph
+        };
+
+        let a = build();
+        let b = build();
+
+        assert_eq!(format!("{:?}", a.get_all()), format!("{:?}", b.get_all()));
+        assert_eq!(a, b);
+
+        // everything else still works the same
+        assert_eq!(a.len(), 200);
+        assert_eq!(a.get_adj(&0), HashSet::from([7919]));
+        assert_eq!(a.sources(), vec![0]);
+        assert_eq!(a.sinks(), vec![199 * 7919]);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn edge_list() {
+        let mut graph: DirectedGraph<i32> =
+            vec![(1, 2), (2, 3), (3, 1), (3, 3)].into_iter().collect();
+        graph.insert_node(4);
+        graph.insert_node(-5);
+
+        let text = graph.to_edge_list();
+
+        assert_eq!(text, "-5\n1 2\n2 3\n3 1\n3 3\n4\n");
+        assert_eq!(DirectedGraph::from_edge_list(&text), Ok(graph));
+
+        let text = "# a comment\n\n  a b \nb c\nd\n";
+        let graph: DirectedGraph<String> = DirectedGraph::from_edge_list(text).unwrap();
+
+        assert_eq!(graph.len(), 4);
+        assert_eq!(
+            graph.get_adj(&String::from("a")),
+            HashSet::from([String::from("b")])
+        );
+        assert_eq!(graph.get_adj(&String::from("c")), HashSet::new());
+    }
+
+    #[test]
+    fn edge_list_errors() {
+        let err = DirectedGraph::<i32>::from_edge_list("1 2\n2 3 4\n").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(
+            err.to_string(),
+            "line 2: expected `from to` or a single node, found 3 fields in `2 3 4`"
+        );
+
+        let err = DirectedGraph::<i32>::from_edge_list("1 2\n\n3 x\n").unwrap_err();
+        assert_eq!(err.line, 3);
+        assert_eq!(
+            err.to_string(),
+            "line 3: couldn't parse node `x`: invalid digit found in string"
+        );
+    }
+
+    //-----------------------------------------------------------------------//
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,166 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::HashSet, sync::Arc};
+
+use super::{IDefiniteGraph, IGraph, IWeightedGraph};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// A read-only graph
+///
+/// Wraps any graph, passing through the read traits (`IGraph`,
+/// `IDefiniteGraph` and `IWeightedGraph`, whichever the graph has) but none
+/// of the `*Mut` ones. So it can be handed to anything that only needs to
+/// look at the graph, like the search algorithms, without worrying about it
+/// being changed.
+///
+/// The graph lives behind an `Arc`, so cloning a frozen graph (which the
+/// algorithms do freely) is cheap, and it can be shared between threads.
+///
+/// Read methods work as usual:
+///
+/// ```
+/// use cs_240_library::data_structures::graphs::{
+///     directed_graph::DirectedGraph, IDefiniteGraph, IGraph, IGraphEdgeMut, IGraphMut,
+/// };
+///
+/// let mut graph = DirectedGraph::new();
+/// graph.insert_node(1);
+/// graph.insert_node(2);
+/// graph.insert_edge(1, 2);
+///
+/// let frozen = graph.freeze();
+/// assert!(frozen.get_adj(&1).contains(&2));
+/// assert_eq!(frozen.len(), 2);
+///
+/// // and it can be thawed to change it again
+/// let mut graph = frozen.thaw();
+/// graph.insert_node(3);
+/// ```
+///
+/// but the mutating ones don't exist:
+///
+/// ```compile_fail
+/// use cs_240_library::data_structures::graphs::{directed_graph::DirectedGraph, IGraphMut};
+///
+/// let mut frozen = DirectedGraph::<i32>::new().freeze();
+/// frozen.insert_node(1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrozenGraph<G> {
+    graph: Arc<G>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<G: Clone> FrozenGraph<G> {
+    //-----------------------------------------------------------------------//
+
+    pub fn new(graph: G) -> Self {
+        Self {
+            graph: Arc::new(graph),
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the wrapped graph, so it can be changed again
+    ///
+    /// If this is the last clone of the frozen graph the graph is just
+    /// moved out, otherwise it's copied (the other clones still need it).
+    pub fn thaw(self) -> G {
+        Arc::try_unwrap(self.graph).unwrap_or_else(|shared| (*shared).clone())
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<G: IGraph> IGraph for FrozenGraph<G> {
+    type Node = G::Node;
+
+    fn get_adj(&self, node: &Self::Node) -> HashSet<Self::Node> {
+        self.graph.get_adj(node)
+    }
+
+    fn try_get_adj(&self, node: &Self::Node) -> Option<HashSet<Self::Node>> {
+        self.graph.try_get_adj(node)
+    }
+
+    fn contains(&self, item: &Self::Node) -> bool {
+        self.graph.contains(item)
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<G: IDefiniteGraph> IDefiniteGraph for FrozenGraph<G> {
+    fn get_all(&self) -> Vec<Self::Node> {
+        self.graph.get_all()
+    }
+
+    fn len(&self) -> usize {
+        self.graph.len()
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<G: IWeightedGraph> IWeightedGraph for FrozenGraph<G> {
+    type Weight = G::Weight;
+
+    fn get_adj_weighted(&self, node: &Self::Node) -> HashSet<(Self::Node, Self::Weight)> {
+        self.graph.get_adj_weighted(node)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        algorithms::graphs::{bfs::bfs_depths, dijkstras::dijkstras},
+        data_structures::graphs::{
+            weighted_graph::WeightedGraph, IGraphEdgeWeightedMut, IGraphMut,
+        },
+    };
+
+    #[test]
+    fn read_only() {
+        let graph: WeightedGraph<&str, i32> =
+            [("A", "B", 4), ("A", "C", 1), ("C", "B", 1), ("B", "D", 2)]
+                .into_iter()
+                .collect();
+
+        let frozen = graph.clone().freeze();
+        let shared = frozen.clone();
+
+        // the algorithms only need the read traits
+        assert_eq!(frozen.len(), 4);
+        assert!(frozen.contains(&"D"));
+        assert_eq!(frozen.try_get_adj(&"E"), None);
+        assert_eq!(frozen.get_adj_weighted(&"A"), graph.get_adj_weighted(&"A"));
+        assert_eq!(
+            dijkstras(&frozen, &"A", &"D"),
+            Some(vec!["A", "C", "B", "D"])
+        );
+        assert_eq!(bfs_depths(&frozen, "A")[&"D"], 2);
+
+        // thawing a shared graph copies it, leaving the other clone alone
+        let mut thawed = frozen.thaw();
+        thawed.insert_node("E");
+        thawed.insert_edge_weighted("D", "E", 1);
+
+        assert_eq!(thawed.len(), 5);
+        assert_eq!(shared.len(), 4);
+        assert!(!shared.contains(&"E"));
+
+        // the last clone just hands the graph back
+        assert_eq!(shared.thaw().len(), 4);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
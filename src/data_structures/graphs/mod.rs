@@ -8,6 +8,7 @@ use crate::algorithms::graphs::dfs;
 ///////////////////////////////////////////////////////////////////////////////
 
 pub mod directed_graph;
+pub mod frozen_graph;
 pub mod undirected_graph;
 pub mod weighted_graph;
 
@@ -19,6 +20,12 @@ pub trait IGraph: Clone {
 
     fn get_adj(&self, node: &Self::Node) -> HashSet<Self::Node>;
 
+    /// Like `get_adj`, but returns `None` if `node` isn't in the graph rather
+    /// than an empty set
+    fn try_get_adj(&self, node: &Self::Node) -> Option<HashSet<Self::Node>> {
+        self.contains(node).then(|| self.get_adj(node))
+    }
+
     fn contains(&self, item: &Self::Node) -> bool;
 }
 
@@ -46,6 +53,10 @@ pub trait IDefiniteGraph: IGraph {
     fn get_all(&self) -> Vec<Self::Node>;
 
     fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -61,17 +72,47 @@ pub trait IGraphMut: IGraph {
 /// Extends the core graph trait with the ability to create and remove edges
 pub trait IGraphEdgeMut: IGraph {
     fn insert_edge(&mut self, from: Self::Node, to: Self::Node);
-    fn remove_edge(&mut self, from: Self::Node, to: Self::Node);
+
+    /// Returns whether the edge existed before removal
+    fn remove_edge(&mut self, from: Self::Node, to: Self::Node) -> bool;
 }
 
 //---------------------------------------------------------------------------//
 
 /// Extends the core graph trait with the ability to create and remove weighted edges
 pub trait IGraphEdgeWeightedMut: IWeightedGraph {
+    /// Graphs that restrict which weights are allowed (see `WeightPolicy`)
+    /// panic when given one they reject
     fn insert_edge_weighted(&mut self, from: Self::Node, to: Self::Node, weight: Self::Weight);
-    fn remove_edge_weighted(&mut self, from: Self::Node, to: Self::Node, weight: Self::Weight);
+
+    /// Returns whether the edge existed before removal
+    fn remove_edge_weighted(
+        &mut self,
+        from: Self::Node,
+        to: Self::Node,
+        weight: Self::Weight,
+    ) -> bool;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Returned when parsing a graph from text fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line the problem is on (starting from 1)
+    pub line: usize,
+    /// What went wrong
+    pub message: String,
 }
 
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 pub fn fmt<T: IDefiniteGraph<Node = U>, U: Debug>(graph: T) {
@@ -85,3 +126,66 @@ pub fn fmt<T: IDefiniteGraph<Node = U>, U: Debug>(graph: T) {
 }
 
 ///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    //-----------------------------------------------------------------------//
+
+    use super::*;
+
+    use self::{
+        directed_graph::DirectedGraph, undirected_graph::UndirectedGraph,
+        weighted_graph::WeightedGraph,
+    };
+
+    //-----------------------------------------------------------------------//
+
+    fn check_empty<T: IDefiniteGraph + IGraphMut<Node = i32>>(mut graph: T) {
+        assert!(graph.is_empty());
+
+        graph.insert_node(1);
+        assert!(!graph.is_empty());
+
+        graph.remove_node(1);
+        assert!(graph.is_empty());
+    }
+
+    #[test]
+    fn is_empty() {
+        check_empty(DirectedGraph::new());
+        check_empty(UndirectedGraph::new());
+        check_empty(WeightedGraph::<i32, i32>::new());
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn check_try_get_adj<T: IGraphMut<Node = i32> + IGraphEdgeMut>(mut graph: T) {
+        graph.insert_node(1);
+        graph.insert_node(2);
+        graph.insert_node(3);
+        graph.insert_edge(1, 2);
+
+        assert_eq!(graph.try_get_adj(&4), None);
+        assert_eq!(graph.try_get_adj(&3), Some(HashSet::new()));
+        assert_eq!(graph.try_get_adj(&1), Some(HashSet::from([2])));
+    }
+
+    #[test]
+    fn try_get_adj() {
+        check_try_get_adj(DirectedGraph::new());
+        check_try_get_adj(UndirectedGraph::new());
+
+        let mut weighted = WeightedGraph::new();
+        weighted.insert_node(1);
+        weighted.insert_node(2);
+        weighted.insert_edge_weighted(1, 2, 5);
+
+        assert_eq!(weighted.try_get_adj(&3), None);
+        assert_eq!(weighted.try_get_adj(&2), Some(HashSet::new()));
+        assert_eq!(weighted.try_get_adj(&1), Some(HashSet::from([2])));
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -6,18 +6,51 @@ use std::{
     hash::Hash,
 };
 
-use super::{IDefiniteGraph, IGraph, IGraphEdgeMut, IGraphMut};
+use super::{frozen_graph::FrozenGraph, IDefiniteGraph, IGraph, IGraphEdgeMut, IGraphMut};
 
 ///////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "UndirectedGraphData<T>"))]
 pub struct UndirectedGraph<T>
 where
     T: Ord + fmt::Debug + Hash + Clone,
 {
     // Map (node -> set of adj nodes)
     adj: HashMap<T, HashSet<T>>,
+    // Number of edges, kept up to date by every mutation
+    #[cfg_attr(feature = "serde", serde(skip))]
+    edge_count: usize,
+}
+
+//---------------------------------------------------------------------------//
+
+/// What an `UndirectedGraph` is deserialized from
+///
+/// Only the adjacency sets are read, the edge count is worked out from them
+/// instead of trusting the input (which may not even have one, if it was
+/// saved by an older version).
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UndirectedGraphData<T>
+where
+    T: Ord + fmt::Debug + Hash + Clone,
+{
+    adj: HashMap<T, HashSet<T>>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> From<UndirectedGraphData<T>> for UndirectedGraph<T>
+where
+    T: Ord + fmt::Debug + Hash + Clone,
+{
+    fn from(data: UndirectedGraphData<T>) -> Self {
+        Self {
+            edge_count: Self::count_edges(&data.adj),
+            adj: data.adj,
+        }
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -31,20 +64,172 @@ where
     pub fn new() -> Self {
         Self {
             adj: HashMap::new(),
+            edge_count: 0,
         }
     }
 
     //-----------------------------------------------------------------------//
 
-    fn inner_insert_edge(&mut self, from: &T, to: &T) {
+    /// Wraps the graph so it can't be changed any more, see `FrozenGraph`
+    pub fn freeze(self) -> FrozenGraph<Self> {
+        FrozenGraph::new(self)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the subgraph made of only the given nodes, keeping just the
+    /// edges with both endpoints in `nodes`
+    ///
+    /// Nodes in `nodes` that aren't in the graph are ignored.
+    pub fn induced_subgraph(&self, nodes: &HashSet<T>) -> Self {
+        let adj = self
+            .adj
+            .iter()
+            .filter(|(node, _)| nodes.contains(node))
+            .map(|(node, links)| {
+                let links = links
+                    .iter()
+                    .filter(|link| nodes.contains(link))
+                    .cloned()
+                    .collect();
+                (node.clone(), links)
+            })
+            .collect();
+
+        Self {
+            edge_count: Self::count_edges(&adj),
+            adj,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the number of edges in the graph
+    ///
+    /// A self-loop counts as one edge.
+    ///
+    /// - Time complexity: O(1), the count is updated as edges are added and
+    ///   removed
+    pub fn num_edges(&self) -> usize {
+        self.edge_count
+    }
+
+    //.......................................................................//
+
+    /// Counts the edges in `adj` from scratch
+    ///
+    /// - Time complexity: O(V + E)
+    fn count_edges(adj: &HashMap<T, HashSet<T>>) -> usize {
+        // every edge shows up in the sets of both of its endpoints, except
+        // self-loops which only show up once, so count those twice too
+        let ends: usize = adj
+            .iter()
+            .map(|(node, links)| links.len() + links.contains(node) as usize)
+            .sum();
+
+        ends / 2
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the fraction of possible edges that are present
+    ///
+    /// Each unordered pair of distinct nodes can have an edge, so there are
+    /// `V * (V - 1) / 2` possible edges. Self-loops are ignored.
+    /// Graphs with fewer than two nodes have a density of 0.
+    pub fn density(&self) -> f64 {
+        let nodes = self.adj.len();
+
+        if nodes < 2 {
+            return 0.0;
+        }
+
+        // every edge is stored in both directions, so each one gets counted
+        // twice, which cancels out with the / 2 in the possible edges
+        let edges = self
+            .adj
+            .iter()
+            .map(|(node, links)| links.len() - links.contains(node) as usize)
+            .sum::<usize>();
+
+        edges as f64 / (nodes * (nodes - 1)) as f64
+    }
+
+    //.......................................................................//
+
+    /// Returns whether any node has an edge to itself
+    pub fn has_self_loops(&self) -> bool {
+        self.adj.iter().any(|(node, links)| links.contains(node))
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Contracts the edge between `u` and `v`, merging `v` into `u`
+    ///
+    /// Every edge `v` had is moved over to `u` and `v` is removed. The edge
+    /// between them (and any self-loop on `v`) would become a self-loop on
+    /// `u`, so it's dropped instead. A self-loop `u` already had is kept.
+    ///
+    /// Caveat: this isn't a multigraph. Adjacency is a `HashSet`, so if `u`
+    /// and `v` share a neighbor, the two edges to it collapse into one.
+    /// Algorithms that rely on counting parallel edges (like Karger's
+    /// min-cut) need to keep track of them some other way.
+    ///
+    /// Inputs:
+    /// - `u: &T` The node that's kept
+    /// - `v: &T` The node merged into `u`
+    ///
+    /// Output:
+    /// - `bool` Whether the edge existed (and so was contracted)
+    ///
+    /// Side-effects: Removes `v` and moves its edges to `u`
+    ///
+    pub fn contract_edge(&mut self, u: &T, v: &T) -> bool {
+        if u == v || !self.adj.get(u).is_some_and(|links| links.contains(v)) {
+            return false;
+        }
+
+        let links = self.adj.remove(v).unwrap_or_default();
+
+        // this takes every edge `v` had out of the graph, including the one
+        // to `u`, before adding the ones that survive back onto `u`
+        self.edge_count -= links.len();
+
+        for neighbor in &links {
+            if let Some(adj) = self.adj.get_mut(neighbor) {
+                adj.remove(v);
+            }
+        }
+
+        for neighbor in links {
+            if neighbor == *u || neighbor == *v {
+                continue;
+            }
+
+            if self.inner_insert_edge(u, &neighbor) {
+                self.inner_insert_edge(&neighbor, u);
+                self.edge_count += 1;
+            }
+        }
+
+        true
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn inner_insert_edge(&mut self, from: &T, to: &T) -> bool {
         if let Some(links) = self.adj.get_mut(from) {
-            links.insert(to.clone());
+            links.insert(to.clone())
+        } else {
+            false
         }
     }
 
-    fn inner_remove_edge(&mut self, from: &T, to: &T) {
+    fn inner_remove_edge(&mut self, from: &T, to: &T) -> bool {
         if let Some(links) = self.adj.get_mut(from) {
-            links.remove(to);
+            links.remove(to)
+        } else {
+            false
         }
     }
 
@@ -97,6 +282,12 @@ where
     //-----------------------------------------------------------------------//
 
     fn insert_node(&mut self, node: Self::Node) {
+        // re-inserting a node clears its edges, so make sure its neighbors
+        // (and the edge count) forget about them too
+        if self.contains(&node) {
+            self.remove_node(node.clone());
+        }
+
         self.adj.insert(node.clone(), HashSet::new());
     }
 
@@ -109,6 +300,9 @@ where
             }
         }
 
+        // a self-loop is only in the set once, so this is exactly the number
+        // of edges touching `node`
+        self.edge_count -= adj.len();
         self.adj.remove(&node);
     }
 
@@ -124,13 +318,29 @@ where
     //-----------------------------------------------------------------------//
 
     fn insert_edge(&mut self, left: Self::Node, right: Self::Node) {
-        self.inner_insert_edge(&left, &right);
+        // edges need both endpoints to exist, otherwise we'd only store half
+        // of one
+        if !self.contains(&left) || !self.contains(&right) {
+            return;
+        }
+
+        if self.inner_insert_edge(&left, &right) {
+            self.edge_count += 1;
+        }
         self.inner_insert_edge(&right, &left);
     }
 
-    fn remove_edge(&mut self, left: Self::Node, right: Self::Node) {
-        self.inner_remove_edge(&left, &right);
+    fn remove_edge(&mut self, left: Self::Node, right: Self::Node) -> bool {
+        // both directions are always kept in sync, so we only need to report
+        // on one of them
+        let existed = self.inner_remove_edge(&left, &right);
         self.inner_remove_edge(&right, &left);
+
+        if existed {
+            self.edge_count -= 1;
+        }
+
+        existed
     }
 
     //-----------------------------------------------------------------------//
@@ -138,6 +348,38 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
+/// Adds an edge in both directions for every `(from, to)` pair, creating any missing
+/// endpoints along the way
+impl<T> Extend<(T, T)> for UndirectedGraph<T>
+where
+    T: Ord + fmt::Debug + Hash + Clone + Default + fmt::Debug,
+{
+    fn extend<I: IntoIterator<Item = (T, T)>>(&mut self, iter: I) {
+        for (from, to) in iter {
+            // `insert_node` would clear the edges of existing nodes
+            self.adj.entry(from.clone()).or_default();
+            self.adj.entry(to.clone()).or_default();
+
+            self.insert_edge(from, to);
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<T> FromIterator<(T, T)> for UndirectedGraph<T>
+where
+    T: Ord + fmt::Debug + Hash + Clone + Default + fmt::Debug,
+{
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        let mut graph = Self::new();
+        graph.extend(iter);
+        graph
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
     //-----------------------------------------------------------------------//
@@ -298,6 +540,22 @@ mod tests {
 
     //-----------------------------------------------------------------------//
 
+    #[test]
+    fn remove_edge_result() {
+        let mut graph = UndirectedGraph::new();
+
+        graph.insert_node(1);
+        graph.insert_node(2);
+        graph.insert_edge(1, 2);
+
+        assert!(graph.remove_edge(2, 1));
+        assert!(!graph.remove_edge(1, 2));
+        assert!(!graph.remove_edge(2, 1));
+        assert!(!graph.remove_edge(3, 1));
+    }
+
+    //-----------------------------------------------------------------------//
+
     #[test]
     fn bfs_search() {
         for i in vec![0, 1, 2, 3] {
@@ -352,6 +610,164 @@ mod tests {
     }
 
     //-----------------------------------------------------------------------//
+
+    #[test]
+    fn induced_subgraph() {
+        let mut graph = UndirectedGraph::new();
+
+        for i in 0..10 {
+            graph.insert_node(i);
+        }
+
+        for i in 0..10 {
+            graph.insert_edge(i, (i + 1) % 10);
+            graph.insert_edge(i, (i * 3) % 10);
+        }
+
+        let sub = graph.induced_subgraph(&HashSet::from([1, 2, 3, 42]));
+
+        assert_eq!(sub.len(), 3);
+        assert!(!sub.contains(&42));
+        assert_eq!(sub.get_adj(&1), HashSet::from([2, 3]));
+        assert_eq!(sub.get_adj(&2), HashSet::from([1, 3]));
+        assert_eq!(sub.get_adj(&3), HashSet::from([1, 2]));
+
+        assert!(graph.induced_subgraph(&HashSet::new()).is_empty());
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn density() {
+        let mut graph = UndirectedGraph::new();
+
+        assert_eq!(graph.density(), 0.0);
+        assert!(!graph.has_self_loops());
+
+        for i in 0..5 {
+            graph.insert_node(i);
+        }
+
+        assert_eq!(graph.density(), 0.0);
+
+        for i in 0..5 {
+            for j in 0..5 {
+                if i != j {
+                    graph.insert_edge(i, j);
+                }
+            }
+        }
+
+        assert!((graph.density() - 1.0).abs() < 1e-9);
+        assert!(!graph.has_self_loops());
+
+        graph.insert_edge(3, 3);
+
+        assert!(graph.has_self_loops());
+        assert!((graph.density() - 1.0).abs() < 1e-9);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn num_edges() {
+        let mut graph = UndirectedGraph::new();
+        let mut rng = crate::meta::rng::XorShift::new(42);
+
+        for i in 0..30 {
+            graph.insert_node(i);
+        }
+
+        for _ in 0..5000 {
+            let left = rng.next_index(32) as i32;
+            let right = rng.next_index(32) as i32;
+
+            match rng.next_index(10) {
+                0 => graph.remove_node(left),
+                1 => graph.insert_node(left),
+                2..=5 => {
+                    graph.remove_edge(left, right);
+                }
+                _ => graph.insert_edge(left, right),
+            }
+
+            assert_eq!(graph.num_edges(), UndirectedGraph::count_edges(&graph.adj));
+        }
+
+        let half: HashSet<i32> = (0..15).collect();
+        let sub = graph.induced_subgraph(&half);
+        assert_eq!(sub.num_edges(), UndirectedGraph::count_edges(&sub.adj));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn from_edges() {
+        let mut graph: UndirectedGraph<i32> = vec![(1, 2), (2, 3)].into_iter().collect();
+
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.num_edges(), 2);
+        assert_eq!(graph.get_adj(&2), HashSet::from([1, 3]));
+
+        graph.extend([(3, 1), (1, 2)]);
+
+        assert_eq!(graph.num_edges(), 3);
+        assert_eq!(graph.get_adj(&1), HashSet::from([2, 3]));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn contract_edge() {
+        //  1 - 2
+        //  | / |
+        //  3   4   (plus a self-loop on 2)
+        let mut graph: UndirectedGraph<i32> = [(1, 2), (1, 3), (2, 3), (2, 4), (2, 2)]
+            .into_iter()
+            .collect();
+
+        assert!(!graph.contract_edge(&1, &4));
+        assert!(!graph.contract_edge(&1, &1));
+        assert!(!graph.contract_edge(&1, &5));
+
+        assert!(graph.contract_edge(&1, &2));
+
+        assert!(!graph.contains(&2));
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.get_adj(&1), HashSet::from([3, 4]));
+        assert_eq!(graph.get_adj(&3), HashSet::from([1]));
+        assert_eq!(graph.get_adj(&4), HashSet::from([1]));
+        assert!(!graph.has_self_loops());
+
+        // 1 - 3 and 2 - 3 collapsed into a single edge
+        assert_eq!(graph.num_edges(), 2);
+        assert_eq!(graph.num_edges(), UndirectedGraph::count_edges(&graph.adj));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_recounts_edges() {
+        let graph: UndirectedGraph<i32> = [(1, 2), (2, 3), (3, 3)].into_iter().collect();
+
+        let json = serde_json::to_string(&graph).unwrap();
+        assert!(!json.contains("edge_count"));
+
+        let loaded: UndirectedGraph<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.num_edges(), 3);
+
+        // a stale count (or none at all, from before it was cached) is
+        // ignored, so removing nodes can't underflow it
+        for json in [
+            r#"{"adj":{"1":[2],"2":[1]},"edge_count":0}"#,
+            r#"{"adj":{"1":[2],"2":[1]}}"#,
+        ] {
+            let mut loaded: UndirectedGraph<i32> = serde_json::from_str(json).unwrap();
+            assert_eq!(loaded.num_edges(), 1);
+
+            loaded.remove_node(1);
+            assert_eq!(loaded.num_edges(), 0);
+        }
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
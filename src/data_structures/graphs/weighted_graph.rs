@@ -2,12 +2,16 @@
 
 use std::{
     collections::{HashMap, HashSet},
-    fmt::{self, Debug},
+    fmt::{self, Debug, Display},
     hash::Hash,
     ops::Add,
+    str::FromStr,
 };
 
-use super::{IDefiniteGraph, IGraph, IGraphEdgeWeightedMut, IGraphMut, IWeightedGraph};
+use super::{
+    frozen_graph::FrozenGraph, IDefiniteGraph, IGraph, IGraphEdgeWeightedMut, IGraphMut,
+    IWeightedGraph, ParseError,
+};
 
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -20,6 +24,78 @@ where
 {
     // Map (node -> set of adj nodes)
     adj: HashMap<T, HashSet<(T, W)>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    policy: WeightPolicy,
+    #[cfg_attr(feature = "serde", serde(default))]
+    parallel: ParallelEdges,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Which edge weights a `WeightedGraph` accepts
+///
+/// Some algorithms only work for some weights (Dijkstra's needs them to be
+/// non-negative, while MSTs are fine with anything), so a graph can be built
+/// to reject the ones that would break whatever it's going to be used with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WeightPolicy {
+    /// Every weight is allowed
+    #[default]
+    Any,
+    /// Weights below zero are rejected
+    NonNegative,
+}
+
+impl WeightPolicy {
+    /// Returns whether `weight` is allowed under this policy
+    pub fn allows<W: Ord + From<i32>>(&self, weight: &W) -> bool {
+        match self {
+            WeightPolicy::Any => true,
+            WeightPolicy::NonNegative => *weight >= W::from(0),
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+/// Returned when an edge weight isn't allowed by the graph's `WeightPolicy`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyViolation<W> {
+    /// The rejected weight
+    pub weight: W,
+    /// The policy it broke
+    pub policy: WeightPolicy,
+}
+
+impl<W: fmt::Debug> fmt::Display for PolicyViolation<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "weight {:?} is not allowed by the {:?} policy",
+            self.weight, self.policy
+        )
+    }
+}
+
+impl<W: fmt::Debug> std::error::Error for PolicyViolation<W> {}
+
+//---------------------------------------------------------------------------//
+
+/// What inserting an edge does when there's already one between the same
+/// two nodes (with a different weight)
+///
+/// Edges are stored as `(to, weight)` pairs, so by default the new edge is
+/// just added alongside the old one. That's a real multigraph, but most
+/// algorithms expect a single weight per edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParallelEdges {
+    /// Keep both edges
+    #[default]
+    Keep,
+    /// Throw the old edge away, the new weight takes its place
+    Replace,
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -32,9 +108,400 @@ where
     //-----------------------------------------------------------------------//
 
     pub fn new() -> Self {
+        Self::with_policy(WeightPolicy::Any)
+    }
+
+    /// Creates an empty graph that only accepts weights allowed by `policy`
+    ///
+    /// `try_insert_edge_weighted` reports rejected weights, while the plain
+    /// `insert_edge_weighted` (and so `Extend`) panics on them.
+    pub fn with_policy(policy: WeightPolicy) -> Self {
         Self {
             adj: HashMap::new(),
+            policy,
+            parallel: ParallelEdges::Keep,
+        }
+    }
+
+    /// Returns the policy this graph checks edge weights against
+    pub fn policy(&self) -> WeightPolicy {
+        self.policy
+    }
+
+    /// Sets what inserting an edge that already exists (under another
+    /// weight) does, see `ParallelEdges`
+    ///
+    /// Only affects later inserts, use `collapse_parallel_edges` to clean up
+    /// the edges already in the graph.
+    pub fn with_parallel_edges(mut self, parallel: ParallelEdges) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Returns what inserting a parallel edge does in this graph
+    pub fn parallel_edges(&self) -> ParallelEdges {
+        self.parallel
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Parses a graph from the format written by `to_edge_list`
+    ///
+    /// Each line is either `from to weight` (an edge, creating both nodes if
+    /// needed) or a lone `node`. Blank lines and lines starting with `#` are
+    /// skipped. Repeated `from to` pairs are kept as parallel edges.
+    ///
+    /// - Output:
+    ///     - `Err(ParseError)` for the first line with the wrong number of
+    ///       fields, or a node or weight that doesn't parse
+    pub fn from_edge_list(s: &str) -> Result<Self, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+        W: FromStr,
+        W::Err: Display,
+    {
+        let mut graph = Self::new();
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |message: String| ParseError {
+                line: index + 1,
+                message,
+            };
+
+            let parse = |field: &str| {
+                field
+                    .parse::<T>()
+                    .map_err(|err| error(format!("couldn't parse node `{}`: {}", field, err)))
+            };
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+
+            match fields[..] {
+                [node] => {
+                    graph.adj.entry(parse(node)?).or_default();
+                }
+                [from, to, weight] => {
+                    let (from, to) = (parse(from)?, parse(to)?);
+                    let weight = weight.parse::<W>().map_err(|err| {
+                        error(format!("couldn't parse weight `{}`: {}", weight, err))
+                    })?;
+
+                    graph.adj.entry(to.clone()).or_default();
+                    graph.adj.entry(from).or_default().insert((to, weight));
+                }
+                _ => {
+                    return Err(error(format!(
+                        "expected `from to weight` or a single node, found {} fields in `{}`",
+                        fields.len(),
+                        line
+                    )))
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Wraps the graph so it can't be changed any more, see `FrozenGraph`
+    pub fn freeze(self) -> FrozenGraph<Self> {
+        FrozenGraph::new(self)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Merges every set of parallel edges (same `from` and `to`) into one
+    ///
+    /// Inputs:
+    /// - `keep: fn(W, W) -> W` Combines two weights into the one to keep,
+    ///   e.g. `std::cmp::min` for the cheapest edge, or adding them up for
+    ///   total capacity
+    ///
+    /// Side-effects: Leaves at most one edge between each ordered pair of
+    /// nodes
+    ///
+    pub fn collapse_parallel_edges(&mut self, keep: fn(W, W) -> W) {
+        for links in self.adj.values_mut() {
+            let mut merged: HashMap<T, W> = HashMap::new();
+
+            for (to, weight) in links.drain() {
+                let weight = match merged.remove(&to) {
+                    Some(other) => keep(other, weight),
+                    None => weight,
+                };
+
+                merged.insert(to, weight);
+            }
+
+            links.extend(merged);
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the subgraph made of only the given nodes, keeping just the
+    /// edges with both endpoints in `nodes`
+    ///
+    /// Nodes in `nodes` that aren't in the graph are ignored.
+    pub fn induced_subgraph(&self, nodes: &HashSet<T>) -> Self {
+        Self {
+            adj: self
+                .adj
+                .iter()
+                .filter(|(node, _)| nodes.contains(node))
+                .map(|(node, links)| {
+                    let links = links
+                        .iter()
+                        .filter(|(link, _)| nodes.contains(link))
+                        .cloned()
+                        .collect();
+                    (node.clone(), links)
+                })
+                .collect(),
+            policy: self.policy,
+            parallel: self.parallel,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the fraction of possible edges that are present
+    ///
+    /// Edges are directed, so each ordered pair of distinct nodes can have an
+    /// edge, giving `V * (V - 1)` possible edges. Parallel edges with
+    /// different weights only count once, and self-loops are ignored.
+    /// Graphs with fewer than two nodes have a density of 0.
+    pub fn density(&self) -> f64 {
+        let nodes = self.adj.len();
+
+        if nodes < 2 {
+            return 0.0;
+        }
+
+        let edges = self
+            .adj
+            .iter()
+            .map(|(node, links)| {
+                links
+                    .iter()
+                    .map(|(link, _)| link)
+                    .filter(|link| *link != node)
+                    .collect::<HashSet<&T>>()
+                    .len()
+            })
+            .sum::<usize>();
+
+        edges as f64 / (nodes * (nodes - 1)) as f64
+    }
+
+    //.......................................................................//
+
+    /// Returns whether any node has an edge to itself
+    pub fn has_self_loops(&self) -> bool {
+        self.adj
+            .iter()
+            .any(|(node, links)| links.iter().any(|(link, _)| link == node))
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns every edge in the graph as a `(from, to, weight)` triple
+    pub fn weighted_edges(&self) -> impl Iterator<Item = (T, T, W)> + '_ {
+        self.adj.iter().flat_map(|(from, links)| {
+            links
+                .iter()
+                .map(move |(to, weight)| (from.clone(), to.clone(), weight.clone()))
+        })
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the sum of every edge's weight
+    ///
+    /// Edges are directed, so an edge stored both ways counts twice.
+    pub fn total_weight(&self) -> W
+    where
+        W: Add<W, Output = W> + From<i32>,
+    {
+        self.weighted_edges()
+            .fold(W::from(0), |total, (_, _, weight)| total + weight)
+    }
+
+    //.......................................................................//
+
+    /// Returns the sum of the weights of the edges leaving `node`
+    /// (zero if it isn't in the graph)
+    pub fn weighted_out_degree(&self, node: &T) -> W
+    where
+        W: Add<W, Output = W> + From<i32>,
+    {
+        self.adj
+            .get(node)
+            .into_iter()
+            .flatten()
+            .fold(W::from(0), |total, (_, weight)| total + weight.clone())
+    }
+
+    //.......................................................................//
+
+    /// Returns the heaviest edge as `(from, to, weight)`, or `None` if there
+    /// are no edges
+    ///
+    /// Ties go to the smallest `(from, to)` pair, so the result doesn't
+    /// depend on hash order.
+    pub fn max_weight_edge(&self) -> Option<(T, T, W)> {
+        self.weighted_edges()
+            .max_by(|(a, b, x), (c, d, y)| x.cmp(y).then_with(|| (c, d).cmp(&(a, b))))
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the graph with every edge reversed, keeping its weight
+    ///
+    /// Every node is kept, including isolated ones.
+    pub fn transpose(&self) -> Self {
+        let mut adj: HashMap<T, HashSet<(T, W)>> = self
+            .adj
+            .keys()
+            .map(|node| (node.clone(), HashSet::new()))
+            .collect();
+
+        for (from, to, weight) in self.weighted_edges() {
+            adj.entry(to).or_default().insert((from, weight));
+        }
+
+        Self {
+            adj,
+            policy: self.policy,
+            parallel: self.parallel,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the graph in Graphviz DOT format, with edges labelled by weight
+    ///
+    /// Nodes and weights are written using their `Debug` representation.
+    /// Output is sorted so that equal graphs always produce the same text.
+    ///
+    pub fn to_dot(&self) -> String {
+        let mut nodes: Vec<&T> = self.adj.keys().collect();
+        nodes.sort();
+
+        let mut edges: Vec<(T, T, W)> = self.weighted_edges().collect();
+        edges.sort();
+
+        let mut res = String::from("digraph {\n");
+
+        for node in nodes {
+            res += &format!("    {:?};\n", node);
+        }
+
+        for (from, to, weight) in edges {
+            res += &format!("    {:?} -> {:?} [label=\"{:?}\"];\n", from, to, weight);
+        }
+
+        res += "}\n";
+
+        res
+    }
+
+    //.......................................................................//
+
+    /// Writes the graph as text, one `from to weight` edge per line
+    ///
+    /// Nodes without any edges get a line to themselves so they aren't lost.
+    /// Lines are sorted, so the output doesn't depend on hash order. Nodes
+    /// and weights are written with `Display` and fields are separated by
+    /// spaces, so they shouldn't display with whitespace in them.
+    ///
+    /// See `from_edge_list` to read it back.
+    pub fn to_edge_list(&self) -> String
+    where
+        T: Display,
+        W: Display,
+    {
+        let targets: HashSet<&T> = self.adj.values().flatten().map(|(to, _)| to).collect();
+
+        let mut lines: Vec<(&T, Option<(&T, &W)>)> = vec![];
+
+        for (node, links) in &self.adj {
+            if links.is_empty() && !targets.contains(node) {
+                lines.push((node, None));
+            }
+
+            lines.extend(links.iter().map(|(to, weight)| (node, Some((to, weight)))));
+        }
+
+        lines.sort();
+
+        let mut res = String::new();
+
+        for line in lines {
+            match line {
+                (node, None) => res += &format!("{}\n", node),
+                (from, Some((to, weight))) => res += &format!("{} {} {}\n", from, to, weight),
+            }
+        }
+
+        res
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T, W> WeightedGraph<T, W>
+where
+    T: Ord + fmt::Debug + Hash + Clone,
+    W: Ord + fmt::Debug + Hash + Clone + From<i32>,
+{
+    //-----------------------------------------------------------------------//
+
+    /// Inserts an edge, unless its weight isn't allowed by the graph's policy
+    ///
+    /// Like `insert_edge_weighted`, nothing happens if `from` isn't in the
+    /// graph. Any existing `from -> to` edge is replaced rather than kept
+    /// if the graph was set up with `ParallelEdges::Replace`.
+    ///
+    /// Inputs:
+    /// - `from`, `to` The endpoints of the edge
+    /// - `weight` The edge weight
+    ///
+    /// Output:
+    /// - `Err(PolicyViolation)` if the weight was rejected
+    ///
+    pub fn try_insert_edge_weighted(
+        &mut self,
+        from: T,
+        to: T,
+        weight: W,
+    ) -> Result<(), PolicyViolation<W>> {
+        if !self.policy.allows(&weight) {
+            return Err(PolicyViolation {
+                weight,
+                policy: self.policy,
+            });
+        }
+
+        if let Some(links) = self.adj.get_mut(&from) {
+            if self.parallel == ParallelEdges::Replace {
+                links.retain(|(link, _)| *link != to);
+            }
+
+            links.insert((to, weight));
         }
+
+        Ok(())
     }
 
     //-----------------------------------------------------------------------//
@@ -122,15 +589,30 @@ where
     W: Ord + fmt::Debug + Hash + Clone + Add<W, Output = W> + From<i32> + Default + fmt::Debug,
 {
     //-----------------------------------------------------------------------//
+
+    /// Panics if `weight` isn't allowed by the graph's policy, use
+    /// `try_insert_edge_weighted` to handle that instead
     fn insert_edge_weighted(&mut self, from: Self::Node, to: Self::Node, weight: Self::Weight) {
-        if let Some(links) = self.adj.get_mut(&from) {
-            links.insert((to.clone(), weight));
+        /*
+        Generic code (`Extend`, the algorithms that build graphs) can't know
+        about the policy, and quietly dropping the edge would leave a graph
+        that's missing paths with no sign of why, so we panic instead.
+        */
+        if let Err(violation) = self.try_insert_edge_weighted(from, to, weight) {
+            panic!("{}", violation);
         }
     }
 
-    fn remove_edge_weighted(&mut self, from: Self::Node, to: Self::Node, weight: Self::Weight) {
+    fn remove_edge_weighted(
+        &mut self,
+        from: Self::Node,
+        to: Self::Node,
+        weight: Self::Weight,
+    ) -> bool {
         if let Some(links) = self.adj.get_mut(&from) {
-            links.remove(&(to, weight));
+            links.remove(&(to, weight))
+        } else {
+            false
         }
     }
 
@@ -139,6 +621,40 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
+/// Adds an edge for every `(from, to, weight)` triple, creating any missing
+/// endpoints along the way
+impl<T, W> Extend<(T, T, W)> for WeightedGraph<T, W>
+where
+    T: Ord + fmt::Debug + Hash + Clone + Default + fmt::Debug,
+    W: Ord + fmt::Debug + Hash + Clone + Add<W, Output = W> + From<i32> + Default + fmt::Debug,
+{
+    fn extend<I: IntoIterator<Item = (T, T, W)>>(&mut self, iter: I) {
+        for (from, to, weight) in iter {
+            // `insert_node` would clear the edges of existing nodes
+            self.adj.entry(from.clone()).or_default();
+            self.adj.entry(to.clone()).or_default();
+
+            self.insert_edge_weighted(from, to, weight);
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<T, W> FromIterator<(T, T, W)> for WeightedGraph<T, W>
+where
+    T: Ord + fmt::Debug + Hash + Clone + Default + fmt::Debug,
+    W: Ord + fmt::Debug + Hash + Clone + Add<W, Output = W> + From<i32> + Default + fmt::Debug,
+{
+    fn from_iter<I: IntoIterator<Item = (T, T, W)>>(iter: I) -> Self {
+        let mut graph = Self::new();
+        graph.extend(iter);
+        graph
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
     //-----------------------------------------------------------------------//
@@ -210,7 +726,7 @@ mod tests {
                 assert_eq!(graph.len(), i - j - 1);
             }
 
-            // TODO: fix, this currently test non-existent nodes
+            // these nodes are gone, so `get_adj` can only give back nothing
             for j in 1..i / 2 {
                 println!("> {:?}", graph);
                 let adj = graph.get_adj(&j);
@@ -218,6 +734,7 @@ mod tests {
 
                 assert!(!adj.contains(&(i - j)));
                 assert_eq!(adj.len(), 0);
+                assert_eq!(graph.try_get_adj(&j), None);
             }
 
             let mut graph = WeightedGraph::new();
@@ -285,6 +802,347 @@ mod tests {
     }
 
     //-----------------------------------------------------------------------//
+
+    #[test]
+    fn remove_edge_result() {
+        let mut graph = WeightedGraph::new();
+
+        graph.insert_node(1);
+        graph.insert_node(2);
+        graph.insert_edge_weighted(1, 2, 5);
+
+        assert!(!graph.remove_edge_weighted(2, 1, 5));
+        assert!(!graph.remove_edge_weighted(1, 2, 4));
+        assert!(graph.remove_edge_weighted(1, 2, 5));
+        assert!(!graph.remove_edge_weighted(1, 2, 5));
+        assert!(!graph.remove_edge_weighted(3, 1, 5));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn weighted_edges() {
+        let mut graph = WeightedGraph::new();
+
+        for i in 1..6 {
+            graph.insert_node(i);
+        }
+
+        let expected = vec![(1, 2, 5), (1, 2, 7), (1, 3, 1), (2, 3, 2), (4, 1, 3)];
+
+        for (from, to, weight) in expected.iter().cloned() {
+            graph.insert_edge_weighted(from, to, weight);
+        }
+
+        let mut edges: Vec<(i32, i32, i32)> = graph.weighted_edges().collect();
+        edges.sort();
+
+        assert_eq!(edges, expected);
+
+        graph.remove_edge_weighted(1, 2, 7);
+        assert_eq!(graph.weighted_edges().count(), expected.len() - 1);
+    }
+
+    #[test]
+    fn to_dot() {
+        let mut graph = WeightedGraph::new();
+
+        graph.insert_node("A");
+        graph.insert_node("B");
+        graph.insert_node("C");
+        graph.insert_edge_weighted("A", "B", 3);
+        graph.insert_edge_weighted("B", "C", 4);
+
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {
+    \"A\";
+    \"B\";
+    \"C\";
+    \"A\" -> \"B\" [label=\"3\"];
+    \"B\" -> \"C\" [label=\"4\"];
+}
+"
+        );
+    }
+
+    #[test]
+    fn edge_list() {
+        let mut graph: WeightedGraph<i32, i32> = vec![(1, 2, 5), (1, 2, -7), (2, 3, 0), (3, 3, 4)]
+            .into_iter()
+            .collect();
+        graph.insert_node(4);
+        graph.insert_node(-5);
+
+        let text = graph.to_edge_list();
+
+        assert_eq!(text, "-5\n1 2 -7\n1 2 5\n2 3 0\n3 3 4\n4\n");
+
+        let parsed = WeightedGraph::from_edge_list(&text).unwrap();
+        assert_eq!(parsed.len(), graph.len());
+        assert_eq!(
+            parsed.get_adj_weighted(&1),
+            HashSet::from([(2, 5), (2, -7)])
+        );
+        assert_eq!(parsed.to_edge_list(), text);
+
+        let text = "# a comment\n\n  a b 15 \nb c 2\nd\n";
+        let graph: WeightedGraph<String, i64> = WeightedGraph::from_edge_list(text).unwrap();
+
+        assert_eq!(graph.len(), 4);
+        assert_eq!(
+            graph.get_adj_weighted(&String::from("a")),
+            HashSet::from([(String::from("b"), 15)])
+        );
+        assert_eq!(graph.get_adj_weighted(&String::from("c")), HashSet::new());
+    }
+
+    #[test]
+    fn edge_list_errors() {
+        let err = WeightedGraph::<i32, i32>::from_edge_list("1 2 3\n2 3\n").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(
+            err.to_string(),
+            "line 2: expected `from to weight` or a single node, found 2 fields in `2 3`"
+        );
+
+        let err = WeightedGraph::<i32, i32>::from_edge_list("1 2 3\n\n3 x 1\n").unwrap_err();
+        assert_eq!(err.line, 3);
+        assert_eq!(
+            err.to_string(),
+            "line 3: couldn't parse node `x`: invalid digit found in string"
+        );
+
+        let err = WeightedGraph::<i32, i32>::from_edge_list("1 2 heavy\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: couldn't parse weight `heavy`: invalid digit found in string"
+        );
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn induced_subgraph() {
+        let mut graph = WeightedGraph::new();
+
+        for i in 0..10 {
+            graph.insert_node(i);
+        }
+
+        for i in 0..10 {
+            graph.insert_edge_weighted(i, (i + 1) % 10, i);
+            graph.insert_edge_weighted(i, (i * 3) % 10, 2 * i);
+        }
+
+        let sub = graph.induced_subgraph(&HashSet::from([1, 2, 3, 42]));
+
+        assert_eq!(sub.len(), 3);
+        assert!(!sub.contains(&42));
+        assert_eq!(sub.get_adj_weighted(&1), HashSet::from([(2, 1), (3, 2)]));
+        assert_eq!(sub.get_adj_weighted(&2), HashSet::from([(3, 2)]));
+        assert_eq!(sub.get_adj_weighted(&3), HashSet::new());
+
+        assert!(graph.induced_subgraph(&HashSet::new()).is_empty());
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn density() {
+        let mut graph = WeightedGraph::new();
+
+        assert_eq!(graph.density(), 0.0);
+        assert!(!graph.has_self_loops());
+
+        for i in 0..5 {
+            graph.insert_node(i);
+        }
+
+        assert_eq!(graph.density(), 0.0);
+
+        for i in 0..5 {
+            for j in 0..5 {
+                if i != j {
+                    graph.insert_edge_weighted(i, j, i + j);
+                }
+            }
+        }
+
+        assert!((graph.density() - 1.0).abs() < 1e-9);
+        assert!(!graph.has_self_loops());
+
+        // parallel edges only count once
+        graph.insert_edge_weighted(0, 1, 100);
+        assert!((graph.density() - 1.0).abs() < 1e-9);
+
+        graph.insert_edge_weighted(3, 3, 1);
+
+        assert!(graph.has_self_loops());
+        assert!((graph.density() - 1.0).abs() < 1e-9);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn from_edges() {
+        let mut graph: WeightedGraph<&str, i32> = vec![("A", "B", 1), ("A", "C", 4), ("B", "C", 2)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(graph.len(), 3);
+        assert_eq!(
+            graph.get_adj_weighted(&"A"),
+            HashSet::from([("B", 1), ("C", 4)])
+        );
+        assert_eq!(graph.get_adj_weighted(&"C"), HashSet::new());
+
+        graph.extend([("C", "A", 3)]);
+
+        assert_eq!(
+            graph.get_adj_weighted(&"A"),
+            HashSet::from([("B", 1), ("C", 4)])
+        );
+        assert_eq!(graph.get_adj_weighted(&"C"), HashSet::from([("A", 3)]));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn transpose() {
+        let mut graph: WeightedGraph<&str, i32> = vec![("A", "B", 1), ("A", "C", 4), ("B", "C", 2)]
+            .into_iter()
+            .collect();
+        graph.insert_node("D");
+
+        let transposed = graph.transpose();
+
+        assert_eq!(transposed.len(), 4);
+        assert_eq!(transposed.get_adj_weighted(&"A"), HashSet::new());
+        assert_eq!(
+            transposed.get_adj_weighted(&"C"),
+            HashSet::from([("A", 4), ("B", 2)])
+        );
+
+        let mut twice: Vec<_> = transposed.transpose().weighted_edges().collect();
+        let mut edges: Vec<_> = graph.weighted_edges().collect();
+        twice.sort();
+        edges.sort();
+
+        assert_eq!(twice, edges);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn weight_policy() {
+        let mut strict: WeightedGraph<i32, i32> =
+            WeightedGraph::with_policy(WeightPolicy::NonNegative);
+        strict.insert_node(1);
+        strict.insert_node(2);
+
+        assert_eq!(
+            strict.try_insert_edge_weighted(1, 2, -1),
+            Err(PolicyViolation {
+                weight: -1,
+                policy: WeightPolicy::NonNegative
+            })
+        );
+        assert_eq!(strict.try_insert_edge_weighted(1, 2, 0), Ok(()));
+        assert_eq!(strict.get_adj_weighted(&1), HashSet::from([(2, 0)]));
+
+        // the policy carries over to derived graphs
+        assert_eq!(strict.transpose().policy(), WeightPolicy::NonNegative);
+
+        let mut any: WeightedGraph<i32, i32> = WeightedGraph::with_policy(WeightPolicy::Any);
+        any.insert_node(1);
+        any.insert_node(2);
+
+        assert_eq!(any.try_insert_edge_weighted(1, 2, -1), Ok(()));
+        assert_eq!(any.get_adj_weighted(&1), HashSet::from([(2, -1)]));
+        assert_eq!(WeightedGraph::<i32, i32>::new().policy(), WeightPolicy::Any);
+    }
+
+    #[test]
+    #[should_panic(expected = "weight -3 is not allowed by the NonNegative policy")]
+    fn weight_policy_panics() {
+        let mut graph = WeightedGraph::with_policy(WeightPolicy::NonNegative);
+        graph.extend([(1, 2, 3), (2, 1, -3), (2, 3, 0)]);
+    }
+
+    #[test]
+    fn weight_policy_checked() {
+        // to keep the allowed edges, check each one instead
+        let mut graph = WeightedGraph::with_policy(WeightPolicy::NonNegative);
+        let mut rejected = vec![];
+
+        for node in 1..=3 {
+            graph.insert_node(node);
+        }
+
+        for (from, to, weight) in [(1, 2, 3), (2, 1, -3), (2, 3, 0)] {
+            if let Err(violation) = graph.try_insert_edge_weighted(from, to, weight) {
+                rejected.push(violation.weight);
+            }
+        }
+
+        assert_eq!(rejected, vec![-3]);
+        assert_eq!(graph.get_adj_weighted(&1), HashSet::from([(2, 3)]));
+        assert_eq!(graph.get_adj_weighted(&2), HashSet::from([(3, 0)]));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn parallel_edges() {
+        // by default both edges are kept...
+        let mut graph: WeightedGraph<&str, i32> = WeightedGraph::new();
+        graph.insert_node("A");
+        graph.insert_node("B");
+        graph.insert_node("C");
+
+        graph.insert_edge_weighted("A", "B", 5);
+        graph.insert_edge_weighted("A", "B", 3);
+        graph.insert_edge_weighted("A", "C", 1);
+        graph.insert_edge_weighted("A", "C", 4);
+        graph.insert_edge_weighted("A", "C", 2);
+        graph.insert_edge_weighted("B", "C", 7);
+
+        assert_eq!(graph.get_adj_weighted(&"A").len(), 5);
+        assert_eq!(graph.get_adj(&"A"), HashSet::from(["B", "C"]));
+
+        // ...until they're collapsed
+        let mut summed = graph.clone();
+
+        graph.collapse_parallel_edges(std::cmp::min);
+        assert_eq!(
+            graph.get_adj_weighted(&"A"),
+            HashSet::from([("B", 3), ("C", 1)])
+        );
+        assert_eq!(graph.get_adj_weighted(&"B"), HashSet::from([("C", 7)]));
+
+        summed.collapse_parallel_edges(|a, b| a + b);
+        assert_eq!(
+            summed.get_adj_weighted(&"A"),
+            HashSet::from([("B", 8), ("C", 7)])
+        );
+
+        // or never made in the first place
+        let mut graph: WeightedGraph<&str, i32> =
+            WeightedGraph::new().with_parallel_edges(ParallelEdges::Replace);
+        graph.insert_node("A");
+        graph.insert_node("B");
+
+        graph.insert_edge_weighted("A", "B", 5);
+        graph.insert_edge_weighted("A", "B", 3);
+
+        assert_eq!(graph.get_adj_weighted(&"A"), HashSet::from([("B", 3)]));
+        assert_eq!(graph.transpose().parallel_edges(), ParallelEdges::Replace);
+        assert_eq!(
+            WeightedGraph::<i32, i32>::new().parallel_edges(),
+            ParallelEdges::Keep
+        );
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
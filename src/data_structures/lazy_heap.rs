@@ -0,0 +1,341 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A binary heap where removals are deferred.
+
+Taking an arbitrary item out of a `BinaryHeap` means moving the last leaf into
+its spot and re-ordering around it. With lots of removals it's cheaper to just
+remember what's been removed (a tombstone) and throw it away once it reaches
+the root and would have been extracted anyway.
+
+To tell whether there's still a copy to remove without searching the heap, it
+also counts the live copies of each item.
+
+Dead items still take up space though, so once they make up more than half of
+the heap it gets rebuilt without them (compacted). Each compaction is paid for
+by the removals that triggered it.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use core::fmt;
+use std::collections::BTreeMap;
+
+use super::binary_heap::{BinaryHeap, HeapEmpty};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// A binary min-heap with lazy deletion
+pub struct LazyHeap<T>
+where
+    T: Ord + Clone + Default + fmt::Debug,
+{
+    heap: BinaryHeap<T>,
+    // (item -> how many copies of it in `heap` are still alive)
+    alive: BTreeMap<T, usize>,
+    // (item -> how many copies of it in `heap` are dead)
+    tombstones: BTreeMap<T, usize>,
+    // total of all the counts in `tombstones`
+    dead: usize,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> LazyHeap<T>
+where
+    T: Ord + Clone + Default + fmt::Debug,
+{
+    //-----------------------------------------------------------------------//
+
+    pub fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            alive: BTreeMap::new(),
+            tombstones: BTreeMap::new(),
+            dead: 0,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Inserts the given item in the correct spot
+    ///
+    /// - Time complexity: O(log(n))
+    pub fn insert(&mut self, item: T) {
+        *self.alive.entry(item.clone()).or_default() += 1;
+        self.heap.insert(item);
+    }
+
+    //.......................................................................//
+
+    /// Marks one copy of `item` as removed
+    ///
+    /// - Output: `bool`
+    ///     - Whether there was a (not already removed) copy of `item`
+    /// - Time complexity: O(log(n)) amortized
+    pub fn remove(&mut self, item: &T) -> bool {
+        if !Self::take_alive(&mut self.alive, item) {
+            return false;
+        }
+
+        *self.tombstones.entry(item.clone()).or_default() += 1;
+        self.dead += 1;
+
+        if self.dead > self.heap.len() / 2 {
+            self.compact();
+        } else {
+            self.purge();
+        }
+
+        true
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the root (smallest item)
+    ///
+    /// - Time complexity: O(1)
+    pub fn min(&self) -> Option<&T> {
+        // `purge` keeps dead items away from the root
+        self.heap.min()
+    }
+
+    //.......................................................................//
+
+    /// Removes and returns the root (smallest item)
+    ///
+    /// Panics if the heap is empty
+    ///
+    /// - Time complexity: O(log(n)) amortized
+    pub fn extract_min(&mut self) -> T {
+        let min = self.heap.extract_min();
+        Self::take_alive(&mut self.alive, &min);
+        self.purge();
+        min
+    }
+
+    /// Removes and returns the root (smallest item), without panicking on an
+    /// empty heap
+    ///
+    /// - Time complexity: O(log(n)) amortized
+    pub fn try_extract_min(&mut self) -> Result<T, HeapEmpty> {
+        if self.is_empty() {
+            Err(HeapEmpty)
+        } else {
+            Ok(self.extract_min())
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Takes one copy of `item` off the live counts, returning whether there
+    /// was one
+    fn take_alive(alive: &mut BTreeMap<T, usize>, item: &T) -> bool {
+        let Some(count) = alive.get_mut(item) else {
+            return false;
+        };
+
+        *count -= 1;
+        if *count == 0 {
+            alive.remove(item);
+        }
+
+        true
+    }
+
+    //.......................................................................//
+
+    /// Throws away dead items until the root is alive (or the heap is empty)
+    fn purge(&mut self) {
+        while let Some(min) = self.heap.min() {
+            let Some(count) = self.tombstones.get_mut(min) else {
+                break;
+            };
+
+            *count -= 1;
+            if *count == 0 {
+                let min = min.clone();
+                self.tombstones.remove(&min);
+            }
+
+            self.dead -= 1;
+            self.heap.extract_min();
+        }
+    }
+
+    //.......................................................................//
+
+    /// Rebuilds the heap without any of the dead items
+    ///
+    /// - Time complexity: O(n log(n))
+    fn compact(&mut self) {
+        let heap = std::mem::replace(&mut self.heap, BinaryHeap::new());
+        let mut tombstones = std::mem::take(&mut self.tombstones);
+
+        let alive: Vec<T> = heap
+            .into_sorted_vec()
+            .into_iter()
+            .filter(|item| match tombstones.get_mut(item) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            })
+            .collect();
+
+        // `from_vec` heapifies, the sorting above is just how we get the
+        // items back out of the old heap
+        self.heap = BinaryHeap::from_vec(alive);
+        self.dead = 0;
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the number of items, not counting removed ones
+    pub fn len(&self) -> usize {
+        self.heap.len() - self.dead
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the remaining (not removed) items as a sorted vector
+    ///
+    /// - Time complexity: O(n log(n))
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut res = Vec::with_capacity(self.len());
+
+        while !self.is_empty() {
+            res.push(self.extract_min());
+        }
+
+        res
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> Default for LazyHeap<T>
+where
+    T: Ord + Clone + Default + fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<T> FromIterator<T> for LazyHeap<T>
+where
+    T: Ord + Clone + Default + fmt::Debug,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+
+        for item in iter {
+            heap.insert(item);
+        }
+
+        heap
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::meta::rng::XorShift;
+
+    #[test]
+    fn remove_middle() {
+        let mut heap: LazyHeap<i32> = (0..1000).rev().collect();
+
+        // every other item from the middle of the heap
+        for i in (200..800).step_by(2) {
+            assert!(heap.remove(&i));
+            assert!(!heap.remove(&i));
+        }
+        assert!(!heap.remove(&1000));
+
+        assert_eq!(heap.len(), 700);
+        assert_eq!(heap.min(), Some(&0));
+
+        let expected: Vec<i32> = (0..1000)
+            .filter(|i| !(200..800).contains(i) || i % 2 == 1)
+            .collect();
+
+        assert_eq!(heap.into_sorted_vec(), expected);
+    }
+
+    #[test]
+    fn remove_root_and_duplicates() {
+        let mut heap: LazyHeap<i32> = [3, 1, 1, 2, 1].into_iter().collect();
+
+        assert!(heap.remove(&1));
+        assert!(heap.remove(&1));
+        assert_eq!(heap.len(), 3);
+        assert_eq!(heap.min(), Some(&1));
+
+        // the last 1 is now the root, removing it exposes 2
+        assert!(heap.remove(&1));
+        assert!(!heap.remove(&1));
+        assert_eq!(heap.min(), Some(&2));
+
+        heap.insert(1);
+        assert_eq!(heap.extract_min(), 1);
+        assert_eq!(heap.extract_min(), 2);
+        assert_eq!(heap.extract_min(), 3);
+        assert_eq!(heap.try_extract_min(), Err(HeapEmpty));
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn random() {
+        // against a sorted vector, with lots of duplicates
+        let mut rng = XorShift::new(3);
+
+        let mut heap = LazyHeap::new();
+        let mut expected: Vec<usize> = vec![];
+
+        for _ in 0..2000 {
+            let item = rng.next_index(20);
+
+            match rng.next_index(3) {
+                0 => {
+                    let found = expected.iter().position(|x| *x == item);
+                    assert_eq!(heap.remove(&item), found.is_some());
+
+                    if let Some(i) = found {
+                        expected.remove(i);
+                    }
+                }
+                1 if !expected.is_empty() => {
+                    assert_eq!(heap.extract_min(), expected.remove(0));
+                }
+                _ => {
+                    heap.insert(item);
+                    expected.push(item);
+                    expected.sort();
+                }
+            }
+
+            assert_eq!(heap.len(), expected.len());
+            assert_eq!(heap.min(), expected.first());
+        }
+
+        assert_eq!(heap.into_sorted_vec(), expected);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -328,6 +328,168 @@ where
     }
 
     //-----------------------------------------------------------------------//
+
+    /// Appends `data` to the back of the list, unless an equal item is
+    /// already in it
+    ///
+    /// - Inputs:
+    ///     - `data: T` The item to append
+    /// - Output: `bool`
+    ///     - Whether `data` was appended
+    /// - Side-effects:
+    ///     - If `data` was already present, the list is left unchanged
+    /// - Time complexity: O(n)
+    ///     - the whole list is checked for duplicates
+    pub fn push_back_unique(&mut self, data: T) -> bool {
+        if self.iter().any(|item| *item == data) {
+            false
+        } else {
+            self.push_back(data);
+            true
+        }
+    }
+
+    //.......................................................................//
+
+    /// Removes consecutive duplicates, keeping the first of each run (like
+    /// `Vec::dedup`)
+    ///
+    /// - Inputs:
+    ///     - `&mut self`
+    /// - Output: N/A
+    /// - Side-effects:
+    ///     - Only *adjacent* equal items are removed, so unsorted lists can
+    ///       still have duplicates afterwards
+    /// - Time complexity: O(n)
+    pub fn dedup(&mut self) {
+        unsafe {
+            let mut cursor = self.front;
+
+            while let Some(curr) = cursor {
+                match (*curr.as_ptr()).back {
+                    Some(next) if (*next.as_ptr()).data == (*curr.as_ptr()).data => {
+                        let boxed_node = Box::from_raw(next.as_ptr());
+
+                        (*curr.as_ptr()).back = boxed_node.back;
+                        if let Some(after) = boxed_node.back {
+                            (*after.as_ptr()).front = Some(curr);
+                        } else {
+                            self.back = Some(curr);
+                        }
+
+                        self.len -= 1;
+                    }
+                    next => cursor = next,
+                }
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Sorts the list in ascending order, keeping equal elements in their
+    /// original order
+    ///
+    /// Bottom-up merge sort that relinks the existing nodes, so no elements
+    /// are moved or cloned.
+    ///
+    /// - Time complexity: O(n log(n))
+    /// - Space complexity: O(log(n))
+    pub fn sort(&mut self) {
+        unsafe {
+            // bins[i] is either empty or a sorted run of 2^i nodes, linked
+            // only through `back`
+            // bins further along always hold nodes from earlier in the list
+            let mut bins: Vec<Cursor<T>> = vec![];
+
+            let mut cursor = self.front;
+
+            while let Some(node) = cursor {
+                cursor = (*node.as_ptr()).back;
+                (*node.as_ptr()).back = None;
+
+                // like adding one to a binary counter, merge the new node
+                // with every full bin until we find an empty one
+                let mut carry = Some(node);
+
+                for bin in bins.iter_mut() {
+                    match bin.take() {
+                        Some(run) => carry = Self::merge(Some(run), carry),
+                        None => {
+                            *bin = carry.take();
+                            break;
+                        }
+                    }
+                }
+
+                if carry.is_some() {
+                    bins.push(carry);
+                }
+            }
+
+            // merge the leftover runs, from the latest nodes to the earliest
+            let mut sorted = None;
+
+            for bin in bins {
+                sorted = Self::merge(bin, sorted);
+            }
+
+            // the runs were only linked forwards, so fix up the `front` links
+            self.front = sorted;
+            self.back = None;
+
+            let mut prev = None;
+            let mut cursor = sorted;
+
+            while let Some(node) = cursor {
+                (*node.as_ptr()).front = prev;
+                prev = Some(node);
+                cursor = (*node.as_ptr()).back;
+            }
+
+            self.back = prev;
+        }
+    }
+
+    //.......................................................................//
+
+    /// Merges two sorted runs linked through `back`, preferring `left` on
+    /// ties
+    unsafe fn merge(mut left: Cursor<T>, mut right: Cursor<T>) -> Cursor<T> {
+        let mut head = None;
+        let mut tail: Cursor<T> = None;
+
+        loop {
+            let next = match (left, right) {
+                (Some(l), Some(r)) => {
+                    if (*l.as_ptr()).data <= (*r.as_ptr()).data {
+                        left = (*l.as_ptr()).back;
+                        l
+                    } else {
+                        right = (*r.as_ptr()).back;
+                        r
+                    }
+                }
+                // one side ran out, the rest of the other is already sorted
+                (rest, None) | (None, rest) => {
+                    match tail {
+                        Some(tail) => (*tail.as_ptr()).back = rest,
+                        None => head = rest,
+                    }
+                    return head;
+                }
+            };
+
+            match tail {
+                Some(tail) => (*tail.as_ptr()).back = Some(next),
+                None => head = Some(next),
+            }
+
+            tail = Some(next);
+        }
+    }
+
+    //-----------------------------------------------------------------------//
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -471,6 +633,23 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
+impl<T> FromIterator<T> for LinkedList<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+
+        for item in iter {
+            list.push_back(item);
+        }
+
+        list
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 impl<T> Debug for LinkedList<T>
 where
     T: Ord + Debug,
@@ -1,4 +1,10 @@
 use super::solution::*;
+use crate::data_structures::{
+    binary_heap::BinaryHeap,
+    linked_list::single_linked_list::solution::LinkedList as SingleLinkedList,
+    maps::{bst::BST, Map},
+    sets::hashset::HashSet,
+};
 
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -163,7 +169,7 @@ fn search() {
 
     assert_eq!(list.search(8), None);
 }
-/*
+
 #[test]
 fn sort() {
     let mut list = LinkedList::new();
@@ -195,7 +201,130 @@ fn sort() {
     list.sort();
     assert_eq!(list.pop_front(), None);
 }
-*/
+
+#[test]
+fn sort_degenerate() {
+    let mut list = LinkedList::new();
+    list.push_back(4);
+    list.sort();
+    assert_eq!(list.pop_front(), Some(4));
+
+    for _ in 0..100 {
+        list.push_back(7);
+    }
+    list.sort();
+    assert_eq!(list.len(), 100);
+    assert!((0..100).all(|_| list.pop_back() == Some(7)));
+
+    // zero-sized elements
+    let mut list = LinkedList::new();
+    for _ in 0..100 {
+        list.push_front(());
+    }
+    list.sort();
+    assert_eq!(list.len(), 100);
+    assert!((0..100).all(|_| list.pop_back() == Some(())));
+}
+
+#[test]
+fn sort_without_clone() {
+    // deliberately not Clone, so sorting has to move nodes around
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct NoClone(i32, usize);
+
+    let mut list = LinkedList::new();
+
+    for i in 0..200 {
+        list.push_back(NoClone((i as i32 * 7919) % 37, i));
+    }
+
+    list.sort();
+
+    assert_eq!(list.len(), 200);
+
+    let forward: Vec<&NoClone> = list.iter().collect();
+    assert!(forward.windows(2).all(|pair| pair[0] <= pair[1]));
+
+    // every front link should mirror a back link
+    let mut backward = vec![];
+    while let Some(item) = list.pop_back() {
+        backward.push(item);
+    }
+    backward.reverse();
+
+    assert_eq!(backward.len(), 200);
+    assert!(backward.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn dedup() {
+    let mut list = LinkedList::new();
+
+    list.push_back(1);
+    list.push_back(1);
+    list.push_back(1);
+    list.push_back(3);
+    list.push_back(2);
+    list.push_back(3);
+    list.push_back(3);
+
+    list.dedup();
+
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.back(), Some(&3));
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.pop_front(), None);
+
+    list.dedup();
+    assert!(list.is_empty());
+}
+
+#[test]
+fn push_back_unique() {
+    let mut list = LinkedList::new();
+
+    assert!(list.push_back_unique(1));
+    assert!(list.push_back_unique(2));
+    assert!(!list.push_back_unique(1));
+    assert!(list.push_back_unique(3));
+    assert!(!list.push_back_unique(3));
+    assert!(!list.push_back_unique(2));
+
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.pop_front(), None);
+}
+
+#[test]
+fn collect_bridges() {
+    let words = vec!["pear", "fig", "apple", "kiwi", "fig", "date"];
+
+    let mut expected = words.clone();
+    expected.sort();
+
+    // heap -> double linked list -> single linked list -> vec
+    let heap: BinaryHeap<&str> = words.iter().cloned().collect();
+    let list = LinkedList::from_iter(heap.into_sorted_vec());
+    let single: SingleLinkedList<&str> = list.into_iter().collect();
+    let res: Vec<&str> = single.into_iter().collect();
+
+    assert_eq!(res, expected);
+
+    // vec -> hash set -> map -> vec
+    expected.dedup();
+
+    let set: HashSet<&str> = words.iter().cloned().collect();
+    let map: BST<&str, usize> = set.items().into_iter().map(|w| (w, w.len())).collect();
+    let res: Vec<&str> = map.keys().into_iter().cloned().collect();
+
+    assert_eq!(res, expected);
+}
+
 #[test]
 fn miri_testing_from_book_ref() {
     let mut list = LinkedList::new();
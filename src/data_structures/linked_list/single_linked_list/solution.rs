@@ -151,6 +151,12 @@ where
 
     //-----------------------------------------------------------------------//
 
+    pub fn is_empty(&self) -> bool {
+        self.head.is_null()
+    }
+
+    //-----------------------------------------------------------------------//
+
     fn get(&self, index: usize) -> Cursor<T> {
         /*
         --- unsafe code!
@@ -311,6 +317,92 @@ where
 
     //-----------------------------------------------------------------------//
 
+    /// Appends `data` to the back of the list, unless an equal item is
+    /// already in it
+    ///
+    /// - Inputs:
+    ///     - `data: T` The item to append
+    /// - Output: `bool`
+    ///     - Whether `data` was appended
+    /// - Side-effects:
+    ///     - If `data` was already present, the list is left unchanged
+    /// - Time complexity: O(n)
+    ///     - the whole list is checked for duplicates
+    pub fn push_back_unique(&mut self, data: T) -> bool {
+        /*
+        We need to walk the whole list anyway to check for duplicates, so
+        we'll keep track of the last node we saw and append after it.
+
+        Returns whether we actually inserted anything.
+        */
+        unsafe {
+            let mut last: Cursor<T> = ptr::null_mut();
+            let mut cursor = self.head;
+
+            while !cursor.is_null() {
+                if (*cursor).data == data {
+                    // already in the list, nothing to do
+                    return false;
+                }
+                last = cursor;
+                cursor = (*cursor).next;
+            }
+
+            let node = Box::into_raw(Box::new(Node {
+                data,
+                next: ptr::null_mut(),
+            }));
+
+            // an empty list means our new node is also the head
+            if last.is_null() {
+                self.head = node;
+            } else {
+                (*last).next = node;
+            }
+
+            true
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Removes consecutive duplicates, keeping the first of each run (like
+    /// `Vec::dedup`)
+    ///
+    /// - Inputs:
+    ///     - `&mut self`
+    /// - Output: N/A
+    /// - Side-effects:
+    ///     - Only *adjacent* equal items are removed, so unsorted lists can
+    ///       still have duplicates afterwards
+    /// - Time complexity: O(n)
+    pub fn dedup(&mut self) {
+        /*
+        Same idea as `Vec::dedup`, we only remove *consecutive* duplicates.
+
+        For every node, we'll keep unlinking (and freeing) the next node while
+        it holds the same value as us. Once the next node is different, we
+        can move onto it.
+        */
+        unsafe {
+            let mut cursor = self.head;
+
+            while !cursor.is_null() {
+                let next = (*cursor).next;
+
+                if !next.is_null() && (*next).data == (*cursor).data {
+                    // skip over the duplicate and give its memory back
+                    (*cursor).next = (*next).next;
+                    drop(Box::from_raw(next));
+                } else {
+                    cursor = next;
+                }
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
     /* ==Sorting==
 
     We have a problem. Both insertion and selection sort get a lot of their
@@ -414,6 +506,41 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
+impl<T> FromIterator<T> for LinkedList<T>
+where
+    T: Ord + std::fmt::Debug,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        /*
+        `push` adds to the front, which would reverse our items.
+        Instead, we'll remember the last node and keep appending after it.
+        */
+        let mut list = LinkedList::new();
+        let mut tail: Cursor<T> = ptr::null_mut();
+
+        for data in iter {
+            let node = Box::into_raw(Box::new(Node {
+                data,
+                next: ptr::null_mut(),
+            }));
+
+            if tail.is_null() {
+                list.head = node;
+            } else {
+                unsafe {
+                    (*tail).next = node;
+                }
+            }
+
+            tail = node;
+        }
+
+        list
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 pub struct IntoIter<T>(LinkedList<T>)
 where
     T: Ord + std::fmt::Debug + Clone;
@@ -9,8 +9,10 @@ fn base() {
     let mut list = LinkedList::new();
 
     assert_eq!(list.pop(), None);
+    assert!(list.is_empty());
 
     list.push(30);
+    assert!(!list.is_empty());
     list.push(0);
 
     assert_eq!(list.pop(), Some(0));
@@ -24,6 +26,7 @@ fn base() {
     assert_eq!(list.pop(), Some(-91));
     assert_eq!(list.pop(), Some(12));
     assert_eq!(list.pop(), None);
+    assert!(list.is_empty());
 }
 
 #[test]
@@ -201,6 +204,71 @@ fn sort() {
     assert_eq!(list.pop(), None);
 }
 
+#[test]
+fn sort_degenerate() {
+    let mut list = LinkedList::new();
+    list.push(4);
+    list.sort();
+    assert_eq!(list.pop(), Some(4));
+
+    for _ in 0..100 {
+        list.push(7);
+    }
+    list.sort();
+    assert!((0..100).all(|_| list.pop() == Some(7)));
+    assert_eq!(list.pop(), None);
+
+    // zero-sized elements
+    let mut list = LinkedList::new();
+    for _ in 0..100 {
+        list.push(());
+    }
+    list.sort();
+    assert!((0..100).all(|_| list.pop() == Some(())));
+    assert_eq!(list.pop(), None);
+}
+
+#[test]
+fn dedup() {
+    let mut list = LinkedList::new();
+
+    list.push(3);
+    list.push(3);
+    list.push(2);
+    list.push(3);
+    list.push(1);
+    list.push(1);
+    list.push(1);
+
+    list.dedup();
+
+    assert_eq!(list.pop(), Some(1));
+    assert_eq!(list.pop(), Some(3));
+    assert_eq!(list.pop(), Some(2));
+    assert_eq!(list.pop(), Some(3));
+    assert_eq!(list.pop(), None);
+
+    list.dedup();
+    assert_eq!(list.pop(), None);
+}
+
+#[test]
+fn push_back_unique() {
+    let mut list = LinkedList::new();
+
+    assert!(list.push_back_unique(1));
+    assert!(list.push_back_unique(2));
+    assert!(!list.push_back_unique(1));
+    assert!(list.push_back_unique(3));
+    assert!(!list.push_back_unique(3));
+    assert!(!list.push_back_unique(2));
+
+    assert_eq!(list.pop(), Some(1));
+    assert_eq!(list.pop(), Some(2));
+    assert_eq!(list.pop(), Some(3));
+    assert_eq!(list.pop(), None);
+}
+
 #[test]
 fn miri_testing_from_book_ref() {
     let mut list = LinkedList::new();
@@ -1,14 +1,17 @@
 ///////////////////////////////////////////////////////////////////////////////
 
 use core::fmt;
-use std::{fmt::Debug, marker::PhantomData, ptr::NonNull};
+use std::{cmp::Ordering, fmt::Debug, marker::PhantomData, ptr::NonNull};
 
 use super::Map;
+use crate::data_structures::queue::array_queue::solution::ArrayQueue;
 
 ///////////////////////////////////////////////////////////////////////////////
 
 pub struct AVL<T: Ord, U> {
     root: Cursor<T, U>,
+    // the ordering every descent uses, `None` for `Ord::cmp`
+    cmp: Option<Comparator<T>>,
 
     _ghost: PhantomData<T>,
 }
@@ -19,6 +22,10 @@ type Cursor<T, U> = Option<NonNull<Node<T, U>>>;
 
 //---------------------------------------------------------------------------//
 
+type Comparator<T> = Box<dyn Fn(&T, &T) -> Ordering + Send + Sync>;
+
+//---------------------------------------------------------------------------//
+
 struct Node<T: Ord, U> {
     key: T,
     value: U,
@@ -30,22 +37,62 @@ struct Node<T: Ord, U> {
     skew: i32,
 }
 
+//---------------------------------------------------------------------------//
+
+/// A lazy in-order iterator over the keys of an `AVL`, see `AVL::keys_iter`
+pub struct Keys<'a, T: Ord, U> {
+    // the nodes still to visit, the next one on top, each with its right
+    // subtree left to go
+    stack: Vec<NonNull<Node<T, U>>>,
+
+    _ghost: PhantomData<&'a AVL<T, U>>,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 impl<T: Ord, U> AVL<T, U> {
     //-----------------------------------------------------------------------//
 
+    /// Creates an empty map ordering its keys by `cmp` instead of `Ord`
+    ///
+    /// Saves wrapping the keys in a newtype just to change how they sort,
+    /// e.g. to ignore case. Keys `cmp` calls equal are the same key, so
+    /// inserting one replaces the other's value.
+    ///
+    /// - Inputs:
+    ///     - `cmp: F` Must be a total order, like `Ord::cmp`
+    ///
+    pub fn new_by<F>(cmp: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
+        AVL {
+            root: None,
+            cmp: Some(Box::new(cmp)),
+            _ghost: PhantomData,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        match &self.cmp {
+            Some(cmp) => cmp(a, b),
+            None => a.cmp(b),
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
     fn get_node(&self, key: &T, cursor: Cursor<T, U>) -> Cursor<T, U> {
         unsafe {
             cursor.and_then(|curr| {
                 let data = &(*curr.as_ptr()).key;
 
-                if data == key {
-                    cursor
-                } else if data > key {
-                    self.get_node(key, (*curr.as_ptr()).left)
-                } else {
-                    self.get_node(key, (*curr.as_ptr()).right)
+                match self.compare(data, key) {
+                    Ordering::Equal => cursor,
+                    Ordering::Greater => self.get_node(key, (*curr.as_ptr()).left),
+                    Ordering::Less => self.get_node(key, (*curr.as_ptr()).right),
                 }
             })
         }
@@ -84,7 +131,7 @@ impl<T: Ord, U> AVL<T, U> {
                     }))));
                     true
                 }
-                (None, Some(par)) if (*par.as_ptr()).key > key => {
+                (None, Some(par)) if self.compare(&(*par.as_ptr()).key, &key).is_gt() => {
                     (*par.as_ptr()).left =
                         Some(NonNull::new_unchecked(Box::into_raw(Box::new(Node {
                             key,
@@ -115,11 +162,11 @@ impl<T: Ord, U> AVL<T, U> {
 
                     true
                 }
-                (Some(curr), _) if (*curr.as_ptr()).key == key => {
+                (Some(curr), _) if self.compare(&(*curr.as_ptr()).key, &key).is_eq() => {
                     (*curr.as_ptr()).value = value;
                     false
                 }
-                (Some(curr), _) if (*curr.as_ptr()).key > key => {
+                (Some(curr), _) if self.compare(&(*curr.as_ptr()).key, &key).is_gt() => {
                     self.insert_rec((*curr.as_ptr()).left, key, value, cursor)
                 }
                 (Some(curr), _) => self.insert_rec((*curr.as_ptr()).right, key, value, cursor),
@@ -144,6 +191,138 @@ impl<T: Ord, U> AVL<T, U> {
 
     //-----------------------------------------------------------------------//
 
+    fn in_order_depth<'a>(
+        &'a self,
+        cursor: Cursor<T, U>,
+        depth: usize,
+        res: &mut Vec<(usize, &'a T, &'a U)>,
+    ) {
+        unsafe {
+            if let Some(curr) = cursor {
+                self.in_order_depth((*curr.as_ptr()).left, depth + 1, res);
+                res.push((depth, &(*curr.as_ptr()).key, &(*curr.as_ptr()).value));
+                self.in_order_depth((*curr.as_ptr()).right, depth + 1, res);
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// In-order traversal that stops as soon as `pred` returns true
+    fn in_order_any<F>(&self, cursor: Cursor<T, U>, pred: F) -> bool
+    where
+        F: Fn(NonNull<Node<T, U>>) -> bool + Copy,
+    {
+        unsafe {
+            match cursor {
+                Some(curr) => {
+                    self.in_order_any((*curr.as_ptr()).left, pred)
+                        || pred(curr)
+                        || self.in_order_any((*curr.as_ptr()).right, pred)
+                }
+                None => false,
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns every entry in order, along with its depth (the root is at 0)
+    ///
+    /// - Time complexity: O(n)
+    ///
+    pub fn iter_with_depth(&self) -> Vec<(usize, &T, &U)> {
+        let mut res = vec![];
+
+        self.in_order_depth(self.root, 0, &mut res);
+
+        res
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns every entry grouped by depth, the root's level first, and
+    /// each level in key order
+    ///
+    /// - Time complexity: O(n)
+    ///
+    pub fn level_order(&self) -> Vec<Vec<(&T, &U)>> {
+        /*
+        Breadth first, one level at a time ---
+
+            The queue starts with just the root.
+            Everything in the queue at the start of a round is on the same
+            level, so we take exactly that many nodes off the front, and
+            put their children on the back for the next round.
+            Left children go in before right ones, so each level comes out
+            in order.
+
+        */
+
+        let mut levels = vec![];
+        let mut queue = ArrayQueue::new();
+
+        if let Some(root) = self.root {
+            queue.enqueue(root);
+        }
+
+        while !queue.is_empty() {
+            let mut level = Vec::with_capacity(queue.len());
+
+            for _ in 0..queue.len() {
+                let node = queue.dequeue().expect("counted from the queue length");
+
+                unsafe {
+                    let node = &*node.as_ptr();
+
+                    level.push((&node.key, &node.value));
+
+                    for child in [node.left, node.right].into_iter().flatten() {
+                        queue.enqueue(child);
+                    }
+                }
+            }
+
+            levels.push(level);
+        }
+
+        levels
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns an iterator over the keys in order, without collecting them
+    /// first like `keys` does
+    ///
+    /// - Time complexity: O(1) amortized per key, holding O(log n) nodes
+    ///
+    pub fn keys_iter(&self) -> Keys<'_, T, U> {
+        let mut keys = Keys {
+            stack: vec![],
+            _ghost: PhantomData,
+        };
+
+        keys.push_left(self.root);
+
+        keys
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns whether any key maps to `value`
+    ///
+    /// - Time complexity: O(n), values aren't ordered so every node may need
+    ///   to be checked
+    ///
+    pub fn contains_value(&self, value: &U) -> bool
+    where
+        U: PartialEq,
+    {
+        unsafe { self.in_order_any(self.root, |node| (*node.as_ptr()).value == *value) }
+    }
+
+    //-----------------------------------------------------------------------//
+
     fn subtree_at(&self, cursor: Cursor<T, U>, index: usize) -> Cursor<T, U> {
         unsafe {
             cursor.and_then(|node| {
@@ -162,6 +341,71 @@ impl<T: Ord, U> AVL<T, U> {
         }
     }
 
+    //.......................................................................//
+
+    /// Returns the entries at sorted positions `start..end`, in order
+    ///
+    /// Positions past the end of the map are ignored, so asking for too much
+    /// just gives fewer entries (handy for paging through the map).
+    ///
+    /// - Time complexity: O(log n + k), where k is the number of entries
+    ///   returned
+    ///
+    pub fn range_by_index(&self, start: usize, end: usize) -> Vec<(&T, &U)> {
+        /*
+        The sizes let `subtree_at` jump straight to the node at `start`,
+        without visiting anything before it.
+
+        From there we step to each next node in order ---
+
+            If it has a right subtree, the next node is the smallest one in
+            there.
+            Otherwise it's the first ancestor we reach from its left side.
+
+        Each step can take O(log n) on its own, but every edge is only
+        walked down once and up once, so it's O(log n + k) overall.
+        */
+
+        let end = end.min(self.len());
+        let mut res = Vec::with_capacity(end.saturating_sub(start));
+
+        let mut cursor = if start < end {
+            self.subtree_at(self.root, start)
+        } else {
+            None
+        };
+
+        unsafe {
+            while let Some(node) = cursor {
+                if res.len() == end - start {
+                    break;
+                }
+
+                res.push((&(*node.as_ptr()).key, &(*node.as_ptr()).value));
+
+                cursor = if (*node.as_ptr()).right.is_some() {
+                    self.get_min_node((*node.as_ptr()).right)
+                } else {
+                    let mut child = node;
+                    let mut parent = (*node.as_ptr()).parent;
+
+                    while let Some(up) = parent {
+                        if (*up.as_ptr()).left == Some(child) {
+                            break;
+                        }
+
+                        child = up;
+                        parent = (*up.as_ptr()).parent;
+                    }
+
+                    parent
+                };
+            }
+        }
+
+        res
+    }
+
     //-----------------------------------------------------------------------//
 
     fn bubble_up(&mut self, cursor: Cursor<T, U>) {
@@ -169,29 +413,40 @@ impl<T: Ord, U> AVL<T, U> {
             if let Some(node) = cursor {
                 self.update_props(node);
 
-                if (*node.as_ptr()).skew > 1 || (*node.as_ptr()).skew < -1 {
-                    let (right_height, right_skew) = (*node.as_ptr())
-                        .right
-                        .and_then(|node| Some(((*node.as_ptr()).height, (*node.as_ptr()).skew)))
-                        .unwrap_or((0, 0));
+                if (*node.as_ptr()).skew > 1 {
+                    let right = (*node.as_ptr()).right.expect("something went very wrong");
 
-                    if right_skew == 1 || right_skew == 0 {
-                        self.right_rotate(
-                            node,
-                            (*node.as_ptr()).right.expect("something went very wrong"),
+                    // right-left case, straighten it out into a right-right case
+                    if (*right.as_ptr()).skew < 0 {
+                        self.left_rotate(
+                            right,
+                            (*right.as_ptr()).left.expect("something went very wrong"),
                         );
-                    } else if right_height == -1 {
+                    }
+
+                    self.right_rotate(
+                        node,
+                        (*node.as_ptr())
+                            .right
+                            .expect("something got really messed up"),
+                    );
+                } else if (*node.as_ptr()).skew < -1 {
+                    let left = (*node.as_ptr()).left.expect("something went very wrong");
+
+                    // left-right case, straighten it out into a left-left case
+                    if (*left.as_ptr()).skew > 0 {
                         self.right_rotate(
-                            node,
-                            (*node.as_ptr()).right.expect("something went very wrong"),
-                        );
-                        self.left_rotate(
-                            (*node.as_ptr())
-                                .right
-                                .expect("something got really messed up"),
-                            node,
+                            left,
+                            (*left.as_ptr()).right.expect("something went very wrong"),
                         );
                     }
+
+                    self.left_rotate(
+                        node,
+                        (*node.as_ptr())
+                            .left
+                            .expect("something got really messed up"),
+                    );
                 }
 
                 self.bubble_up((*node.as_ptr()).parent);
@@ -219,6 +474,10 @@ impl<T: Ord, U> AVL<T, U> {
             (*left.as_ptr()).parent = Some(right);
             (*right.as_ptr()).parent = parent;
 
+            if let Some(middle) = middle_child {
+                (*middle.as_ptr()).parent = Some(left);
+            }
+
             (*left.as_ptr()).right = middle_child;
             (*right.as_ptr()).left = Some(left);
 
@@ -240,16 +499,22 @@ impl<T: Ord, U> AVL<T, U> {
                 } else {
                     (*parent.as_ptr()).right = Some(left);
                 }
+            } else {
+                self.root = Some(left);
             }
 
             (*right.as_ptr()).parent = Some(left);
             (*left.as_ptr()).parent = parent;
 
+            if let Some(middle) = middle_child {
+                (*middle.as_ptr()).parent = Some(right);
+            }
+
             (*right.as_ptr()).left = middle_child;
             (*left.as_ptr()).right = Some(right);
 
-            self.update_props(left);
             self.update_props(right);
+            self.update_props(left);
         }
     }
 
@@ -283,6 +548,201 @@ impl<T: Ord, U> AVL<T, U> {
     }
 
     //-----------------------------------------------------------------------//
+
+    /// Takes ownership of every node in the subtree, returning the pairs in
+    /// order and freeing the nodes
+    fn drain(cursor: Cursor<T, U>, res: &mut Vec<(T, U)>) {
+        unsafe {
+            if let Some(node) = cursor {
+                let node = Box::from_raw(node.as_ptr());
+                let Node {
+                    key,
+                    value,
+                    left,
+                    right,
+                    ..
+                } = *node;
+
+                Self::drain(left, res);
+                res.push((key, value));
+                Self::drain(right, res);
+            }
+        }
+    }
+
+    //.......................................................................//
+
+    fn free(cursor: Cursor<T, U>) {
+        unsafe {
+            if let Some(node) = cursor {
+                let node = Box::from_raw(node.as_ptr());
+
+                Self::free(node.left);
+                Self::free(node.right);
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Builds a perfectly balanced subtree out of the next `len` pairs, which
+    /// must be in sorted order
+    fn build(&mut self, pairs: &mut impl Iterator<Item = (T, U)>, len: usize) -> Cursor<T, U> {
+        if len == 0 {
+            return None;
+        }
+
+        unsafe {
+            let left = self.build(pairs, len / 2);
+            let (key, value) = pairs.next().expect("something went very wrong");
+            let right = self.build(pairs, len - len / 2 - 1);
+
+            let node = NonNull::new_unchecked(Box::into_raw(Box::new(Node {
+                key,
+                value,
+                left,
+                right,
+                parent: None,
+                size: 1,
+                height: 1,
+                skew: 0,
+            })));
+
+            if let Some(child) = left {
+                (*child.as_ptr()).parent = Some(node);
+            }
+
+            if let Some(child) = right {
+                (*child.as_ptr()).parent = Some(node);
+            }
+
+            self.update_props(node);
+
+            Some(node)
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the height of the subtree if it is a valid AVL tree
+    fn check(&self, cursor: Cursor<T, U>, parent: Cursor<T, U>) -> Option<i32> {
+        unsafe {
+            match cursor {
+                None => Some(0),
+                Some(node) => {
+                    if (*node.as_ptr()).parent != parent {
+                        return None;
+                    }
+
+                    let left_height = self.check((*node.as_ptr()).left, cursor)?;
+                    let right_height = self.check((*node.as_ptr()).right, cursor)?;
+
+                    let ordered = (*node.as_ptr()).left.is_none_or(|left| {
+                        self.compare(&(*left.as_ptr()).key, &(*node.as_ptr()).key)
+                            .is_lt()
+                    }) && (*node.as_ptr()).right.is_none_or(|right| {
+                        self.compare(&(*right.as_ptr()).key, &(*node.as_ptr()).key)
+                            .is_gt()
+                    });
+
+                    let height = 1 + left_height.max(right_height);
+
+                    if ordered
+                        && (right_height - left_height).abs() <= 1
+                        && (*node.as_ptr()).height == height
+                    {
+                        Some(height)
+                    } else {
+                        None
+                    }
+                }
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns whether every node's subtrees differ in height by at most one
+    /// (and the cached heights and parent links are consistent)
+    ///
+    /// - Time complexity: O(n)
+    ///
+    pub fn is_balanced(&self) -> bool {
+        self.check(self.root, None).is_some()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Moves every pair from `other` into this map
+    ///
+    /// - Inputs:
+    ///     - `other: AVL<T, U>` The map to consume
+    /// - Side-effects:
+    ///     - If a key is in both maps, the value from `other` wins
+    /// - Time complexity: O(m log(n + m)), where m is the size of `other`
+    ///
+    pub fn extend_from(&mut self, mut other: AVL<T, U>) {
+        let mut pairs = vec![];
+
+        Self::drain(other.root.take(), &mut pairs);
+
+        self.extend(pairs);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Inserts every pair into the map
+    ///
+    /// - Inputs:
+    ///     - `pairs` The pairs to insert
+    /// - Side-effects:
+    ///     - If the keys arrive in strictly increasing order, they're merged
+    ///       with the existing pairs and the whole tree is rebuilt at once
+    ///     - Otherwise, each pair is inserted one at a time
+    ///     - Like `insert`, new values replace old ones for the same key
+    /// - Time complexity:
+    ///     - Sorted: O(n + m)
+    ///     - Unsorted: O(m log(n + m))
+    ///
+    pub fn insert_many(&mut self, pairs: impl IntoIterator<Item = (T, U)>) {
+        let pairs: Vec<(T, U)> = pairs.into_iter().collect();
+
+        if !pairs
+            .windows(2)
+            .all(|pair| self.compare(&pair[0].0, &pair[1].0).is_lt())
+        {
+            self.extend(pairs);
+            return;
+        }
+
+        let mut existing = vec![];
+        Self::drain(self.root.take(), &mut existing);
+
+        // merge the two sorted lists, letting the new pairs win
+        let mut merged = Vec::with_capacity(existing.len() + pairs.len());
+        let mut existing = existing.into_iter().peekable();
+        let mut pairs = pairs.into_iter().peekable();
+
+        while let (Some(old), Some(new)) = (existing.peek(), pairs.peek()) {
+            match self.compare(&old.0, &new.0) {
+                Ordering::Less => merged.extend(existing.next()),
+                order => {
+                    if order.is_eq() {
+                        existing.next();
+                    }
+                    merged.extend(pairs.next());
+                }
+            }
+        }
+
+        merged.extend(existing);
+        merged.extend(pairs);
+
+        let len = merged.len();
+        self.root = self.build(&mut merged.into_iter(), len);
+    }
+
+    //-----------------------------------------------------------------------//
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -299,6 +759,7 @@ impl<T: Ord, U> Map for AVL<T, U> {
     fn new() -> Self {
         AVL {
             root: None,
+            cmp: None,
             _ghost: PhantomData,
         }
     }
@@ -312,37 +773,46 @@ impl<T: Ord, U> Map for AVL<T, U> {
     fn remove(&mut self, key: &Self::Key) -> bool {
         unsafe {
             self.get_node(key, self.root)
-                .and_then(|node| {
-                    let replacement = match ((*node.as_ptr()).left, (*node.as_ptr()).right) {
-                        (None, None) => None,
-                        (None, Some(child)) => Some(child),
-                        (Some(child), None) => Some(child),
-                        (Some(_), Some(right)) => self.get_min_node(Some(right)),
+                .map(|node| {
+                    // if the node has two children, swap it with its successor
+                    // (which has at most one child) and remove that instead
+                    let target = match ((*node.as_ptr()).left, (*node.as_ptr()).right) {
+                        (Some(_), Some(right)) => {
+                            let succ = self
+                                .get_min_node(Some(right))
+                                .expect("something went very wrong");
+
+                            std::mem::swap(&mut (*node.as_ptr()).key, &mut (*succ.as_ptr()).key);
+                            std::mem::swap(
+                                &mut (*node.as_ptr()).value,
+                                &mut (*succ.as_ptr()).value,
+                            );
+
+                            succ
+                        }
+                        _ => node,
                     };
 
-                    (*node.as_ptr()).left.and_then(|child| {
-                        (*child.as_ptr()).parent = (*node.as_ptr()).parent;
-                        Some(())
-                    });
+                    let child = (*target.as_ptr()).left.or((*target.as_ptr()).right);
+                    let parent = (*target.as_ptr()).parent;
 
-                    (*node.as_ptr()).right.and_then(|child| {
-                        (*child.as_ptr()).parent = (*node.as_ptr()).parent;
-                        Some(())
-                    });
+                    if let Some(child) = child {
+                        (*child.as_ptr()).parent = parent;
+                    }
 
-                    if let Some(par) = (*node.as_ptr()).parent {
-                        if (*par.as_ptr()).left == Some(node) {
-                            (*par.as_ptr()).left = replacement;
+                    if let Some(par) = parent {
+                        if (*par.as_ptr()).left == Some(target) {
+                            (*par.as_ptr()).left = child;
                         } else {
-                            (*par.as_ptr()).right = replacement;
+                            (*par.as_ptr()).right = child;
                         }
                     } else {
-                        self.root = replacement;
+                        self.root = child;
                     }
 
-                    self.bubble_up(Some(node));
+                    drop(Box::from_raw(target.as_ptr()));
 
-                    Some(())
+                    self.bubble_up(parent);
                 })
                 .is_some()
         }
@@ -363,6 +833,13 @@ impl<T: Ord, U> Map for AVL<T, U> {
         }
     }
 
+    fn get_entry(&self, key: &Self::Key) -> Option<(&Self::Key, &Self::Value)> {
+        unsafe {
+            self.get_node(key, self.root)
+                .map(|node| (&(*node.as_ptr()).key, &(*node.as_ptr()).value))
+        }
+    }
+
     fn get_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Value> {
         unsafe {
             self.get_node(key, self.root)
@@ -407,6 +884,119 @@ impl<T: Ord, U> Map for AVL<T, U> {
 
 ///////////////////////////////////////////////////////////////////////////////
 
+impl<T: Ord, U> Extend<(T, U)> for AVL<T, U> {
+    fn extend<I: IntoIterator<Item = (T, U)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<T: Ord, U> Drop for AVL<T, U> {
+    fn drop(&mut self) {
+        Self::free(self.root.take());
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<T: Ord, U> FromIterator<(T, U)> for AVL<T, U> {
+    fn from_iter<I: IntoIterator<Item = (T, U)>>(iter: I) -> Self {
+        let mut map = Self::new();
+
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+
+        map
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T: Ord, U> Keys<'_, T, U> {
+    /// Pushes `cursor` and its chain of left children
+    fn push_left(&mut self, mut cursor: Cursor<T, U>) {
+        while let Some(node) = cursor {
+            self.stack.push(node);
+            cursor = unsafe { (*node.as_ptr()).left };
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<'a, T: Ord, U> Iterator for Keys<'a, T, U> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+
+        unsafe {
+            self.push_left((*node.as_ptr()).right);
+            Some(&(*node.as_ptr()).key)
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+/// Walks the keys of both maps together, in order and without duplicates
+///
+/// - Inputs:
+///     | `a: &AVL<K, V>`, `b: &AVL<K, V>`
+///     | The maps to merge the keys of
+///
+/// - Output:
+///     | A lazy iterator over every key in either map, sorted, with keys in
+///     | both maps given once (the one from `a`)
+///
+/// - Keys are compared the way `a` orders them (see `AVL::new_by`), so both
+///   maps should use the same ordering
+///
+/// - Time complexity: O(n + m) to walk the whole thing
+///
+pub fn merge_keys<'a, K: Ord, V>(
+    a: &'a AVL<K, V>,
+    b: &'a AVL<K, V>,
+) -> impl Iterator<Item = &'a K> {
+    let map = a;
+    let mut a = a.keys_iter().peekable();
+    let mut b = b.keys_iter().peekable();
+
+    std::iter::from_fn(move || match (a.peek(), b.peek()) {
+        (Some(left), Some(right)) if map.compare(left, right).is_lt() => a.next(),
+        (Some(left), Some(right)) if map.compare(left, right).is_gt() => b.next(),
+        (Some(_), Some(_)) => {
+            b.next();
+            a.next()
+        }
+        (Some(_), None) => a.next(),
+        (None, _) => b.next(),
+    })
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+// SAFETY: the raw node pointers stop the compiler from working this out on its
+// own, but they behave like `Box`es: every node is owned by exactly one AVL
+// (reachable only through its root) and is never shared with anything else.
+// So sending an AVL to another thread moves its keys and values along with
+// it, just like a `Box<Node>` would. The comparator is required to be `Send`
+// and `Sync` for this.
+unsafe impl<T: Ord + Send, U: Send> Send for AVL<T, U> {}
+
+// SAFETY: nothing reachable through a `&AVL` can change the tree. Every
+// `&self` method only reads through the node pointers, and changes need a
+// `&mut AVL`, which the borrow checker (or a lock, see `SyncMap`) makes
+// exclusive. So sharing one between threads is as safe as sharing its keys
+// and values.
+unsafe impl<T: Ord + Sync, U: Sync> Sync for AVL<T, U> {}
+
+///////////////////////////////////////////////////////////////////////////////
+
 impl<T: Ord + fmt::Debug, U: fmt::Debug> Debug for AVL<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         unsafe {
@@ -445,3 +1035,273 @@ impl<T: Ord + fmt::Debug, U: fmt::Debug> Debug for Node<T, U> {
 }
 
 ///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    //-----------------------------------------------------------------------//
+
+    use super::*;
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn balance() {
+        let mut map = AVL::new();
+
+        // a scrambled insertion order hits all four rotation cases
+        for i in 0..200 {
+            assert!(map.insert((i * 37) % 200, i));
+            assert!(map.is_balanced());
+        }
+
+        assert_eq!(map.len(), 200);
+        assert_eq!(
+            map.keys().into_iter().cloned().collect::<Vec<i32>>(),
+            (0..200).collect::<Vec<i32>>()
+        );
+
+        for i in 0..100 {
+            assert!(map.remove(&((i * 13) % 200)));
+            assert!(!map.contains_key(&((i * 13) % 200)));
+            assert!(map.is_balanced());
+        }
+
+        assert_eq!(map.len(), 100);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn extend_from() {
+        let mut left: AVL<i32, &str> = (0..50).map(|i| (i * 2, "left")).collect();
+        let right: AVL<i32, &str> = (0..50).map(|i| (i * 2 + 1, "right")).collect();
+
+        left.extend_from(right);
+
+        assert_eq!(left.len(), 100);
+        assert!(left.is_balanced());
+
+        for i in 0..100 {
+            assert_eq!(
+                left.get(&i),
+                Some(if i % 2 == 0 { &"left" } else { &"right" })
+            );
+        }
+
+        // overlapping keys, other wins
+        let other: AVL<i32, &str> = (25..75).map(|i| (i, "other")).collect();
+
+        left.extend_from(other);
+
+        assert_eq!(left.len(), 100);
+        assert!(left.is_balanced());
+
+        for i in 25..75 {
+            assert_eq!(left.get(&i), Some(&"other"));
+        }
+
+        assert_eq!(left.get(&24), Some(&"left"));
+        assert_eq!(left.get(&75), Some(&"right"));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn insert_many() {
+        let sorted: Vec<(i32, i32)> = (0..1000).map(|i| (i, i * i)).collect();
+        let shuffled: Vec<(i32, i32)> = (0..1000)
+            .map(|i| (i * 379) % 1000)
+            .map(|i| (i, i * i))
+            .collect();
+
+        let mut from_sorted = AVL::new();
+        from_sorted.insert_many(sorted.clone());
+
+        let mut from_shuffled = AVL::new();
+        from_shuffled.insert_many(shuffled);
+
+        assert!(from_sorted.is_balanced());
+        assert!(from_shuffled.is_balanced());
+
+        assert_eq!(from_sorted.len(), 1000);
+        assert_eq!(from_sorted.keys(), from_shuffled.keys());
+        assert_eq!(from_sorted.values(), from_shuffled.values());
+
+        // sorted input merges with what's already there, new values winning
+        let mut map: AVL<i32, i32> = (0..1000).step_by(2).map(|i| (i, -1)).collect();
+        map.insert_many(sorted.iter().cloned().filter(|(key, _)| key % 3 == 0));
+
+        assert!(map.is_balanced());
+
+        for i in 0..1000 {
+            let expected = if i % 3 == 0 {
+                Some(&(i * i))
+            } else if i % 2 == 0 {
+                Some(&-1)
+            } else {
+                None
+            };
+
+            assert_eq!(map.get(&i), expected);
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn iter_with_depth() {
+        let mut map = AVL::new();
+        map.insert_many((1..=7).map(|i| (i, i * 10)));
+
+        assert_eq!(
+            map.iter_with_depth(),
+            vec![
+                (2, &1, &10),
+                (1, &2, &20),
+                (2, &3, &30),
+                (0, &4, &40),
+                (2, &5, &50),
+                (1, &6, &60),
+                (2, &7, &70),
+            ]
+        );
+
+        assert!(AVL::<i32, i32>::new().iter_with_depth().is_empty());
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn keys_iter() {
+        let map: AVL<i32, ()> = (0..100).map(|i| ((i * 37) % 100, ())).collect();
+
+        assert!(map.keys_iter().eq(map.keys()));
+        assert_eq!(AVL::<i32, ()>::new().keys_iter().next(), None);
+    }
+
+    #[test]
+    fn merge_keys() {
+        let a: AVL<i32, &str> = (0..50).map(|i| (i * 3, "a")).collect();
+        let b: AVL<i32, &str> = (0..50).map(|i| (i * 5, "b")).collect();
+
+        let merged: Vec<i32> = super::merge_keys(&a, &b).cloned().collect();
+
+        let mut expected: Vec<i32> = (0..50).flat_map(|i| [i * 3, i * 5]).collect();
+        expected.sort();
+        expected.dedup();
+
+        assert_eq!(merged, expected);
+        assert!(merged.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let empty = AVL::new();
+        assert!(super::merge_keys(&a, &empty).eq(a.keys()));
+        assert!(super::merge_keys(&empty, &b).eq(b.keys()));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn level_order() {
+        let mut map = AVL::new();
+        map.insert_many((1..=7).map(|i| (i, i * 10)));
+
+        let levels = map.level_order();
+
+        assert_eq!(
+            levels.iter().map(|level| level.len()).collect::<Vec<usize>>(),
+            vec![1, 2, 4]
+        );
+        assert_eq!(levels[0], vec![(&4, &40)]);
+        assert_eq!(levels[1], vec![(&2, &20), (&6, &60)]);
+        assert_eq!(levels[2], vec![(&1, &10), (&3, &30), (&5, &50), (&7, &70)]);
+
+        // agrees with the depths from iter_with_depth
+        for (depth, key, value) in map.iter_with_depth() {
+            assert!(levels[depth].contains(&(key, value)));
+        }
+
+        assert!(AVL::<i32, i32>::new().level_order().is_empty());
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn range_by_index() {
+        let map: AVL<i32, i32> = (0..100).map(|i| (i, i * 10)).collect();
+
+        let page = map.range_by_index(10, 20);
+        let expected: Vec<(i32, i32)> = (10..20).map(|i| (i, i * 10)).collect();
+        assert_eq!(
+            page.into_iter()
+                .map(|(key, value)| (*key, *value))
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        assert_eq!(map.range_by_index(0, 1), vec![(&0, &0)]);
+        assert_eq!(map.range_by_index(95, 1000).len(), 5);
+        assert_eq!(map.range_by_index(20, 10), vec![]);
+        assert_eq!(map.range_by_index(100, 110), vec![]);
+        assert_eq!(AVL::<i32, i32>::new().range_by_index(0, 10), vec![]);
+
+        // every page of a map built up out of order and then thinned out
+        let mut map = AVL::new();
+        for i in 0..200 {
+            map.insert((i * 37) % 200, i);
+        }
+        for i in (0..200).step_by(3) {
+            map.remove(&i);
+        }
+
+        let keys = map.keys();
+        for start in 0..keys.len() {
+            for len in [0, 1, 7, 50] {
+                let page: Vec<&i32> = map
+                    .range_by_index(start, start + len)
+                    .into_iter()
+                    .map(|(key, _)| key)
+                    .collect();
+
+                assert_eq!(page, keys[start..(start + len).min(keys.len())]);
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn new_by() {
+        let mut map: AVL<String, i32> =
+            AVL::new_by(|a: &String, b: &String| a.to_lowercase().cmp(&b.to_lowercase()));
+
+        assert!(map.insert("hello".to_string(), 1));
+        assert!(map.insert("World".to_string(), 2));
+        assert!(map.insert("apple".to_string(), 3));
+        assert!(map.insert("Banana".to_string(), 4));
+
+        assert_eq!(map.get(&"HELLO".to_string()), Some(&1));
+        assert!(map.contains_key(&"world".to_string()));
+
+        // the same key in a different case just replaces the value
+        assert!(!map.insert("HeLLo".to_string(), 5));
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.get(&"hello".to_string()), Some(&5));
+
+        // sorted ignoring case, where `Ord` would put the capitals first
+        assert_eq!(map.keys(), vec!["apple", "Banana", "hello", "World"]);
+
+        for i in 0..100 {
+            map.insert(format!("key{}", i), i);
+        }
+        assert!(map.is_balanced());
+
+        assert!(map.remove(&"APPLE".to_string()));
+        assert!(!map.contains_key(&"apple".to_string()));
+
+        map.insert_many([("ALPHA".to_string(), 0), ("banana".to_string(), 0)]);
+        assert_eq!(map.get(&"Banana".to_string()), Some(&0));
+        assert!(map.is_balanced());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
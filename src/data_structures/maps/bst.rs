@@ -66,6 +66,28 @@ impl<T: Ord, U> BST<T, U> {
 
     //-----------------------------------------------------------------------//
 
+    /// Puts `replacement` (and its subtree) where `node` is in the tree,
+    /// leaving `node` detached from its parent
+    fn replace_node(&mut self, node: NonNull<Node<T, U>>, replacement: Cursor<T, U>) {
+        unsafe {
+            let parent = (*node.as_ptr()).parent;
+
+            if let Some(child) = replacement {
+                (*child.as_ptr()).parent = parent;
+            }
+
+            match parent {
+                Some(par) if (*par.as_ptr()).left == Some(node) => {
+                    (*par.as_ptr()).left = replacement;
+                }
+                Some(par) => (*par.as_ptr()).right = replacement,
+                None => self.root = replacement,
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
     fn insert_rec(&mut self, cursor: Cursor<T, U>, key: T, value: U, parent: Cursor<T, U>) -> bool {
         unsafe {
             match (cursor, parent) {
@@ -132,6 +154,139 @@ impl<T: Ord, U> BST<T, U> {
     }
 
     //-----------------------------------------------------------------------//
+
+    /// Takes ownership of every node in the subtree, returning the pairs in
+    /// order and freeing the nodes
+    fn drain(cursor: Cursor<T, U>, res: &mut Vec<(T, U)>) {
+        unsafe {
+            if let Some(node) = cursor {
+                let node = Box::from_raw(node.as_ptr());
+                let Node {
+                    key,
+                    value,
+                    left,
+                    right,
+                    ..
+                } = *node;
+
+                Self::drain(left, res);
+                res.push((key, value));
+                Self::drain(right, res);
+            }
+        }
+    }
+
+    //.......................................................................//
+
+    /// Builds a height-balanced subtree out of the next `len` pairs, which
+    /// must be in sorted order
+    ///
+    /// The middle pair becomes the root, and each half is built the same way
+    fn build(pairs: &mut impl Iterator<Item = (T, U)>, len: usize) -> Cursor<T, U> {
+        if len == 0 {
+            return None;
+        }
+
+        unsafe {
+            let left = Self::build(pairs, len / 2);
+            let (key, value) = pairs.next().expect("ran out of pairs while building");
+            let right = Self::build(pairs, len - len / 2 - 1);
+
+            let node = NonNull::new_unchecked(Box::into_raw(Box::new(Node {
+                key,
+                value,
+                left,
+                right,
+                parent: None,
+            })));
+
+            if let Some(child) = left {
+                (*child.as_ptr()).parent = Some(node);
+            }
+
+            if let Some(child) = right {
+                (*child.as_ptr()).parent = Some(node);
+            }
+
+            Some(node)
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn height_rec(&self, cursor: Cursor<T, U>) -> usize {
+        unsafe {
+            cursor
+                .map(|node| {
+                    1 + self
+                        .height_rec((*node.as_ptr()).left)
+                        .max(self.height_rec((*node.as_ptr()).right))
+                })
+                .unwrap_or(0)
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// In-order traversal that stops as soon as `pred` returns true
+    fn in_order_any<F>(&self, cursor: Cursor<T, U>, pred: F) -> bool
+    where
+        F: Fn(NonNull<Node<T, U>>) -> bool + Copy,
+    {
+        unsafe {
+            match cursor {
+                Some(curr) => {
+                    self.in_order_any((*curr.as_ptr()).left, pred)
+                        || pred(curr)
+                        || self.in_order_any((*curr.as_ptr()).right, pred)
+                }
+                None => false,
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the number of nodes on the longest path from the root to a leaf
+    ///
+    /// - Time complexity: O(n)
+    ///
+    pub fn height(&self) -> usize {
+        self.height_rec(self.root)
+    }
+
+    //.......................................................................//
+
+    /// Rebuilds the tree so that it's height-balanced
+    ///
+    /// Inserting already sorted keys leaves a BST as one long O(n) height
+    /// chain, this brings it back down to O(log n).
+    ///
+    /// - Time complexity: O(n)
+    ///
+    pub fn rebalance(&mut self) {
+        let mut pairs = Vec::with_capacity(self.size);
+
+        Self::drain(self.root.take(), &mut pairs);
+
+        self.root = Self::build(&mut pairs.into_iter(), self.size);
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns whether any key maps to `value`
+    ///
+    /// - Time complexity: O(n), values aren't ordered so every node may need
+    ///   to be checked
+    ///
+    pub fn contains_value(&self, value: &U) -> bool
+    where
+        U: PartialEq,
+    {
+        unsafe { self.in_order_any(self.root, |node| (*node.as_ptr()).value == *value) }
+    }
+
+    //-----------------------------------------------------------------------//
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -161,40 +316,44 @@ impl<T: Ord, U> Map for BST<T, U> {
 
     fn remove(&mut self, key: &Self::Key) -> bool {
         unsafe {
-            self.get_node(key, self.root)
-                .and_then(|node| {
-                    self.size -= 1;
-
-                    let replacement = match ((*node.as_ptr()).left, (*node.as_ptr()).right) {
-                        (None, None) => None,
-                        (None, Some(child)) => Some(child),
-                        (Some(child), None) => Some(child),
-                        (Some(_), Some(right)) => self.get_min_node(Some(right)),
-                    };
-
-                    (*node.as_ptr()).left.and_then(|child| {
-                        (*child.as_ptr()).parent = (*node.as_ptr()).parent;
-                        Some(())
-                    });
-
-                    (*node.as_ptr()).right.and_then(|child| {
-                        (*child.as_ptr()).parent = (*node.as_ptr()).parent;
-                        Some(())
-                    });
-
-                    if let Some(par) = (*node.as_ptr()).parent {
-                        if (*par.as_ptr()).left == Some(node) {
-                            (*par.as_ptr()).left = replacement;
-                        } else {
-                            (*par.as_ptr()).right = replacement;
-                        }
-                    } else {
-                        self.root = replacement;
+            let Some(node) = self.get_node(key, self.root) else {
+                return false;
+            };
+
+            self.size -= 1;
+
+            let (left, right) = ((*node.as_ptr()).left, (*node.as_ptr()).right);
+
+            let replacement = match (left, right) {
+                (None, child) | (child, None) => child,
+                (Some(left), Some(right)) => {
+                    /*
+                    The smallest key in the right subtree is the next one up,
+                    so it can take the removed node's place with both
+                    subtrees still on the correct sides.
+                    It has no left child, so its right child (if any) just
+                    moves up into the spot it leaves behind.
+                    */
+                    let min = self.get_min_node(Some(right)).expect("right isn't empty");
+
+                    if min != right {
+                        self.replace_node(min, (*min.as_ptr()).right);
+
+                        (*min.as_ptr()).right = Some(right);
+                        (*right.as_ptr()).parent = Some(min);
                     }
 
-                    Some(())
-                })
-                .is_some()
+                    (*min.as_ptr()).left = Some(left);
+                    (*left.as_ptr()).parent = Some(min);
+
+                    Some(min)
+                }
+            };
+
+            self.replace_node(node, replacement);
+            drop(Box::from_raw(node.as_ptr()));
+
+            true
         }
     }
 
@@ -213,6 +372,13 @@ impl<T: Ord, U> Map for BST<T, U> {
         }
     }
 
+    fn get_entry(&self, key: &Self::Key) -> Option<(&Self::Key, &Self::Value)> {
+        unsafe {
+            self.get_node(key, self.root)
+                .map(|node| (&(*node.as_ptr()).key, &(*node.as_ptr()).value))
+        }
+    }
+
     fn get_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Value> {
         unsafe {
             self.get_node(key, self.root)
@@ -253,6 +419,36 @@ impl<T: Ord, U> Map for BST<T, U> {
 
 ///////////////////////////////////////////////////////////////////////////////
 
+impl<T: Ord, U> FromIterator<(T, U)> for BST<T, U> {
+    fn from_iter<I: IntoIterator<Item = (T, U)>>(iter: I) -> Self {
+        let mut map = Self::new();
+
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+
+        map
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+// SAFETY: the raw node pointers stop the compiler from working this out on its
+// own, but they behave like `Box`es: every node is owned by exactly one BST
+// (reachable only through its root) and is never shared with anything else.
+// So sending a BST to another thread moves its keys and values along with
+// it, just like a `Box<Node>` would.
+unsafe impl<T: Ord + Send, U: Send> Send for BST<T, U> {}
+
+// SAFETY: nothing reachable through a `&BST` can change the tree. Every
+// `&self` method only reads through the node pointers, and changes need a
+// `&mut BST`, which the borrow checker (or a lock, see `SyncMap`) makes
+// exclusive. So sharing one between threads is as safe as sharing its keys
+// and values.
+unsafe impl<T: Ord + Sync, U: Sync> Sync for BST<T, U> {}
+
+///////////////////////////////////////////////////////////////////////////////
+
 impl<T: Ord + fmt::Debug, U: fmt::Debug> Debug for BST<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("BST")
@@ -276,3 +472,101 @@ impl<T: Ord + fmt::Debug, U: fmt::Debug> Debug for Node<T, U> {
 }
 
 ///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    //-----------------------------------------------------------------------//
+
+    use super::*;
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn rebalance() {
+        for n in [0, 1, 2, 7, 100, 1000] {
+            let mut map = BST::new();
+
+            for i in (0..n).rev() {
+                map.insert(i, i * i);
+            }
+
+            assert_eq!(map.height(), n as usize);
+
+            map.rebalance();
+
+            // ⌈log2(n + 1)⌉
+            let expected = (usize::BITS - (n as usize).leading_zeros()) as usize;
+
+            assert_eq!(map.height(), expected);
+            assert_eq!(map.len(), n as usize);
+
+            for i in 0..n {
+                assert_eq!(map.get(&i), Some(&(i * i)));
+            }
+
+            assert_eq!(
+                map.keys().into_iter().cloned().collect::<Vec<i32>>(),
+                (0..n).collect::<Vec<i32>>()
+            );
+
+            // still a valid tree afterwards
+            assert!(map.insert(n, 0));
+            assert!(map.remove(&0));
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[test]
+    fn remove_two_children() {
+        /*
+                    50
+                 /      \
+               30        70
+              /  \     /    \
+            20    40  60      80
+                        \
+                         65
+        */
+        let keys = [50, 30, 70, 20, 40, 60, 80, 65];
+
+        // the root, whose successor (60) has a right child to move up, then
+        // a node whose successor is its own right child
+        for (removed, remaining) in [
+            (50, vec![20, 30, 40, 60, 65, 70, 80]),
+            (30, vec![20, 40, 50, 60, 65, 70, 80]),
+        ] {
+            let mut map = BST::new();
+
+            for key in keys {
+                map.insert(key, key * 10);
+            }
+
+            assert!(map.remove(&removed));
+            assert!(!map.remove(&removed));
+            assert_eq!(map.len(), keys.len() - 1);
+            assert_eq!(
+                map.keys().into_iter().cloned().collect::<Vec<i32>>(),
+                remaining
+            );
+            assert!(remaining
+                .iter()
+                .all(|key| map.get(key) == Some(&(key * 10))));
+        }
+
+        // removing everything in insertion order hits every case on the way
+        let mut map: BST<i32, i32> = keys.iter().map(|&key| (key, key)).collect();
+
+        for (i, key) in keys.iter().enumerate() {
+            assert!(map.remove(key));
+            assert_eq!(map.len(), keys.len() - i - 1);
+            assert!(keys[i + 1..].iter().all(|key| map.contains_key(key)));
+        }
+
+        assert!(map.is_empty());
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -2,6 +2,10 @@
 
 pub mod avl;
 pub mod bst;
+pub mod persistent_bst;
+pub mod sorted_vec_map;
+pub mod sync_map;
+pub mod transactional;
 
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -11,17 +15,38 @@ pub trait Map {
 
     fn new() -> Self;
     fn insert(&mut self, key: Self::Key, value: Self::Value) -> bool;
+    /// Like `insert`, but leaves an existing value alone and hands the
+    /// rejected pair back instead of overwriting it
+    fn try_insert(
+        &mut self,
+        key: Self::Key,
+        value: Self::Value,
+    ) -> Result<(), (Self::Key, Self::Value)> {
+        if self.contains_key(&key) {
+            return Err((key, value));
+        }
+
+        self.insert(key, value);
+        Ok(())
+    }
     fn remove(&mut self, key: &Self::Key) -> bool;
 
     fn contains_key(&self, key: &Self::Key) -> bool;
 
     fn get(&self, key: &Self::Key) -> Option<&Self::Value>;
+    /// Like `get`, but also returns the key stored in the map, which might
+    /// differ from `key` in ways that don't affect comparisons
+    fn get_entry(&self, key: &Self::Key) -> Option<(&Self::Key, &Self::Value)>;
     fn get_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Value>;
 
     fn keys(&self) -> Vec<&Self::Key>;
     fn values(&self) -> Vec<&Self::Value>;
 
     fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -33,7 +58,7 @@ mod tests {
 
     use tests::bst::BST;
 
-    use self::avl::AVL;
+    use self::{avl::AVL, sorted_vec_map::SortedVecMap, transactional::TransactionalMap};
 
     use super::*;
 
@@ -41,10 +66,91 @@ mod tests {
     fn all() {
         tests(BST::new());
         tests(AVL::new());
+        tests(SortedVecMap::new());
+    }
+
+    #[test]
+    fn contains_value() {
+        let bst: BST<i32, i32> = (0..10).map(|k| (k, k * k)).collect();
+        let avl: AVL<i32, i32> = (0..10).map(|k| (k, k * k)).collect();
+
+        assert!(bst.contains_value(&16));
+        assert!(avl.contains_value(&16));
+
+        assert!(!bst.contains_value(&17));
+        assert!(!avl.contains_value(&17));
+
+        assert!(!BST::<i32, i32>::new().contains_value(&0));
+        assert!(!AVL::<i32, i32>::new().contains_value(&0));
+    }
+
+    // compares on `id` only, so two keys can be equal but still different
+    #[derive(Debug)]
+    struct Tagged {
+        id: i32,
+        tag: &'static str,
+    }
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id
+        }
+    }
+
+    impl Eq for Tagged {}
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.id.cmp(&other.id)
+        }
+    }
+
+    fn check_get_entry<T: Map<Key = Tagged, Value = i32>>(mut map: T) {
+        for id in 0..10 {
+            map.insert(Tagged { id, tag: "stored" }, id * 10);
+        }
+
+        let probe = Tagged {
+            id: 4,
+            tag: "probe",
+        };
+        let (key, value) = map.get_entry(&probe).unwrap();
+
+        assert_eq!(key.tag, "stored");
+        assert_eq!(key.id, 4);
+        assert_eq!(*value, 40);
+
+        assert!(map
+            .get_entry(&Tagged {
+                id: 10,
+                tag: "probe"
+            })
+            .is_none());
+    }
+
+    #[test]
+    fn get_entry() {
+        check_get_entry(BST::new());
+        check_get_entry(AVL::new());
+        check_get_entry(SortedVecMap::new());
     }
 
     fn tests<T: Map<Key = i32, Value = i32> + fmt::Debug>(mut map: T) {
         assert_eq!(map.len(), 0);
+        assert!(map.is_empty());
+
+        map.insert(0, 0);
+        assert!(!map.is_empty());
+
+        map.remove(&0);
+        assert!(map.is_empty());
+
         for i in 0..30 {
             println!("--- {}", i);
             assert!(!map.contains_key(&i));
@@ -54,7 +160,7 @@ mod tests {
             for j in 0..i {
                 assert!(map.insert(j, j * j));
                 println!("{:?}", map);
-                assert_eq!(map.len(), (j + 1).try_into().unwrap());
+                assert_eq!(map.len(), usize::try_from(j + 1).unwrap());
                 assert!(map.contains_key(&j));
                 assert_eq!(map.get(&j), Some(&(j * j)));
             }
@@ -63,7 +169,7 @@ mod tests {
 
             for j in 0..i {
                 assert!(!map.insert(j, j * 2));
-                assert_eq!(map.len(), i.try_into().unwrap());
+                assert_eq!(map.len(), usize::try_from(i).unwrap());
                 assert!(map.contains_key(&j));
                 assert_eq!(map.get(&j), Some(&(j * 2)));
             }
@@ -73,12 +179,33 @@ mod tests {
             for j in 0..i {
                 assert!(map.remove(&j));
                 println!("{:?}", map);
-                assert_eq!(map.len(), (i - j - 1).try_into().unwrap());
+                assert_eq!(map.len(), usize::try_from(i - j - 1).unwrap());
                 assert!(!map.contains_key(&j));
                 assert_eq!(map.get(&j), None);
             }
         }
     }
+
+    fn check_try_insert<T: Map<Key = i32, Value = &'static str>>(mut map: T) {
+        assert_eq!(map.try_insert(1, "one"), Ok(()));
+        assert_eq!(map.try_insert(2, "two"), Ok(()));
+
+        assert_eq!(map.try_insert(1, "uno"), Err((1, "uno")));
+        assert_eq!(map.get(&1), Some(&"one"));
+        assert_eq!(map.len(), 2);
+
+        // plain insert still overwrites
+        assert!(!map.insert(1, "uno"));
+        assert_eq!(map.get(&1), Some(&"uno"));
+    }
+
+    #[test]
+    fn try_insert() {
+        check_try_insert(BST::new());
+        check_try_insert(AVL::new());
+        check_try_insert(SortedVecMap::new());
+        check_try_insert(TransactionalMap::wrap(AVL::new()));
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,398 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A persistent (immutable, versioned) binary search tree.
+
+Nothing is ever changed in place. Inserting or removing copies just the nodes
+on the path from the root down to the change, and every other subtree is
+shared with the old tree through an `Rc`. So each change costs O(depth) new
+nodes, and every older version stays valid and can still be queried.
+
+Because changes hand back a new tree rather than mutating `self`, this
+doesn't implement `Map` (whose methods take `&mut self`).
+
+Like `BST`, it's unbalanced, so depth is O(n) in the worst case (e.g. sorted
+inserts).
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use std::{cmp::Ordering, fmt, rc::Rc};
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct PersistentBST<K: Ord + Clone, V: Clone> {
+    root: Link<K, V>,
+    size: usize,
+}
+
+//---------------------------------------------------------------------------//
+
+type Link<K, V> = Option<Rc<Node<K, V>>>;
+
+//---------------------------------------------------------------------------//
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<K: Ord + Clone, V: Clone> PersistentBST<K, V> {
+    //-----------------------------------------------------------------------//
+
+    pub fn new() -> Self {
+        PersistentBST {
+            root: None,
+            size: 0,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns a new version with `key` set to `value`
+    ///
+    /// `self` is left as it was.
+    ///
+    /// - Time complexity: O(depth), which is also how many nodes get copied
+    ///
+    pub fn insert(&self, key: K, value: V) -> Self {
+        let (root, added) = Self::insert_rec(&self.root, key, value);
+
+        PersistentBST {
+            root: Some(root),
+            size: self.size + usize::from(added),
+        }
+    }
+
+    //.......................................................................//
+
+    /// Returns a new version without `key`
+    ///
+    /// `self` is left as it was. If `key` isn't present, the new version is
+    /// just another handle to the same tree.
+    ///
+    /// - Time complexity: O(depth)
+    ///
+    pub fn remove(&self, key: &K) -> Self {
+        match Self::remove_rec(&self.root, key) {
+            Some(root) => PersistentBST {
+                root,
+                size: self.size - 1,
+            },
+            None => self.clone(),
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Time complexity: O(depth)
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut cursor = &self.root;
+
+        while let Some(node) = cursor {
+            cursor = match key.cmp(&node.key) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+
+        None
+    }
+
+    /// Time complexity: O(depth)
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the keys in order
+    pub fn keys(&self) -> Vec<&K> {
+        let mut nodes = vec![];
+        Self::in_order(&self.root, &mut nodes);
+        nodes.into_iter().map(|node| &node.key).collect()
+    }
+
+    /// Returns the values, ordered by their keys
+    pub fn values(&self) -> Vec<&V> {
+        let mut nodes = vec![];
+        Self::in_order(&self.root, &mut nodes);
+        nodes.into_iter().map(|node| &node.value).collect()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the new subtree, and whether `key` wasn't there before
+    fn insert_rec(link: &Link<K, V>, key: K, value: V) -> (Rc<Node<K, V>>, bool) {
+        let Some(node) = link else {
+            return (
+                Rc::new(Node {
+                    key,
+                    value,
+                    left: None,
+                    right: None,
+                }),
+                true,
+            );
+        };
+
+        // copy this node, pointing at the old children except on the side
+        // we went down
+        match key.cmp(&node.key) {
+            Ordering::Less => {
+                let (left, added) = Self::insert_rec(&node.left, key, value);
+                (
+                    Rc::new(Node {
+                        key: node.key.clone(),
+                        value: node.value.clone(),
+                        left: Some(left),
+                        right: node.right.clone(),
+                    }),
+                    added,
+                )
+            }
+            Ordering::Greater => {
+                let (right, added) = Self::insert_rec(&node.right, key, value);
+                (
+                    Rc::new(Node {
+                        key: node.key.clone(),
+                        value: node.value.clone(),
+                        left: node.left.clone(),
+                        right: Some(right),
+                    }),
+                    added,
+                )
+            }
+            Ordering::Equal => (
+                Rc::new(Node {
+                    key,
+                    value,
+                    left: node.left.clone(),
+                    right: node.right.clone(),
+                }),
+                false,
+            ),
+        }
+    }
+
+    //.......................................................................//
+
+    /// Returns the new subtree, or `None` if `key` wasn't found (so nothing
+    /// needs copying)
+    fn remove_rec(link: &Link<K, V>, key: &K) -> Option<Link<K, V>> {
+        let node = link.as_ref()?;
+
+        match key.cmp(&node.key) {
+            Ordering::Less => Self::remove_rec(&node.left, key).map(|left| {
+                Some(Rc::new(Node {
+                    key: node.key.clone(),
+                    value: node.value.clone(),
+                    left,
+                    right: node.right.clone(),
+                }))
+            }),
+            Ordering::Greater => Self::remove_rec(&node.right, key).map(|right| {
+                Some(Rc::new(Node {
+                    key: node.key.clone(),
+                    value: node.value.clone(),
+                    left: node.left.clone(),
+                    right,
+                }))
+            }),
+            Ordering::Equal => Some(match (&node.left, &node.right) {
+                (None, right) => right.clone(),
+                (left, None) => left.clone(),
+                // replace it with its successor, the smallest key on the right
+                (left, Some(right)) => {
+                    let (key, value, right) = Self::remove_min(right);
+
+                    Some(Rc::new(Node {
+                        key,
+                        value,
+                        left: left.clone(),
+                        right,
+                    }))
+                }
+            }),
+        }
+    }
+
+    //.......................................................................//
+
+    /// Returns the smallest entry under `node`, and the subtree without it
+    fn remove_min(node: &Rc<Node<K, V>>) -> (K, V, Link<K, V>) {
+        match &node.left {
+            None => (node.key.clone(), node.value.clone(), node.right.clone()),
+            Some(left) => {
+                let (key, value, left) = Self::remove_min(left);
+
+                (
+                    key,
+                    value,
+                    Some(Rc::new(Node {
+                        key: node.key.clone(),
+                        value: node.value.clone(),
+                        left,
+                        right: node.right.clone(),
+                    })),
+                )
+            }
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn in_order<'a>(link: &'a Link<K, V>, nodes: &mut Vec<&'a Node<K, V>>) {
+        if let Some(node) = link {
+            Self::in_order(&node.left, nodes);
+            nodes.push(node);
+            Self::in_order(&node.right, nodes);
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Cloning is O(1), the clone is just another handle to the same version
+impl<K: Ord + Clone, V: Clone> Clone for PersistentBST<K, V> {
+    fn clone(&self) -> Self {
+        PersistentBST {
+            root: self.root.clone(),
+            size: self.size,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<K: Ord + Clone, V: Clone> Default for PersistentBST<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<K: Ord + Clone, V: Clone> FromIterator<(K, V)> for PersistentBST<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::new(), |map, (key, value)| map.insert(key, value))
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<K: Ord + Clone + fmt::Debug, V: Clone + fmt::Debug> fmt::Debug for PersistentBST<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.keys().into_iter().zip(self.values()))
+            .finish()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::meta::rng::XorShift;
+
+    #[test]
+    fn versions() {
+        let v1: PersistentBST<i32, &str> = [(5, "five"), (3, "three"), (8, "eight"), (1, "one")]
+            .into_iter()
+            .collect();
+
+        let v2 = v1.insert(9, "nine");
+
+        assert!(!v1.contains_key(&9));
+        assert_eq!(v1.len(), 4);
+        assert_eq!(v2.get(&9), Some(&"nine"));
+        assert_eq!(v2.len(), 5);
+
+        // 9 went down the right, so the whole left subtree is shared
+        let (old, new) = (v1.root.as_ref().unwrap(), v2.root.as_ref().unwrap());
+        let shared = old.left.as_ref().unwrap();
+
+        assert!(Rc::ptr_eq(shared, new.left.as_ref().unwrap()));
+        assert!(Rc::strong_count(shared) > 1);
+
+        // but the path down to it was copied
+        assert!(!Rc::ptr_eq(old, new));
+        assert!(!Rc::ptr_eq(
+            old.right.as_ref().unwrap(),
+            new.right.as_ref().unwrap()
+        ));
+
+        // overwriting and removing leave the older versions alone too
+        let v3 = v2.insert(3, "THREE").remove(&5);
+
+        assert_eq!(v3.get(&3), Some(&"THREE"));
+        assert!(!v3.contains_key(&5));
+        assert_eq!(v3.keys(), vec![&1, &3, &8, &9]);
+
+        assert_eq!(v2.get(&3), Some(&"three"));
+        assert_eq!(v2.keys(), vec![&1, &3, &5, &8, &9]);
+        assert_eq!(v1.keys(), vec![&1, &3, &5, &8]);
+
+        // removing something that isn't there shares everything
+        let v4 = v3.remove(&100);
+        assert!(Rc::ptr_eq(
+            v3.root.as_ref().unwrap(),
+            v4.root.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift::new(23);
+
+        let mut versions = vec![(PersistentBST::new(), BTreeMap::new())];
+
+        for _ in 0..500 {
+            let (tree, expected) = &versions[rng.next_index(versions.len())];
+            let (mut tree, mut expected) = (tree.clone(), expected.clone());
+            let key = rng.next_index(50);
+
+            if rng.next_index(3) == 0 {
+                tree = tree.remove(&key);
+                expected.remove(&key);
+            } else {
+                tree = tree.insert(key, key * 10);
+                expected.insert(key, key * 10);
+            }
+
+            versions.push((tree, expected));
+        }
+
+        for (tree, expected) in &versions {
+            assert_eq!(tree.len(), expected.len());
+            assert_eq!(tree.keys(), expected.keys().collect::<Vec<_>>());
+            assert_eq!(tree.values(), expected.values().collect::<Vec<_>>());
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
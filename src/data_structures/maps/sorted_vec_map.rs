@@ -0,0 +1,159 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A map backed by a single sorted vector of (key, value) pairs.
+
+Lookups binary search the vector, so reads are O(log n) and only ever touch
+one contiguous allocation (no pointer chasing like in the BST or AVL).
+The tradeoff is writes: inserting or removing shifts every element after the
+insertion point, making them O(n).
+
+Good for maps that are built once and then read a lot.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use std::fmt::Debug;
+
+use super::Map;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct SortedVecMap<K: Ord, V> {
+    items: Vec<(K, V)>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<K: Ord, V> SortedVecMap<K, V> {
+    //-----------------------------------------------------------------------//
+
+    /// Binary searches for `key`
+    ///
+    /// - Output:
+    ///     - `Ok(index)` if key is present at index
+    ///     - `Err(index)` if key is absent, index is where it would be inserted
+    /// - Time complexity: O(log n)
+    ///
+    fn search(&self, key: &K) -> Result<usize, usize> {
+        self.items.binary_search_by(|(other, _)| other.cmp(key))
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<K: Ord, V> Map for SortedVecMap<K, V> {
+    //-----------------------------------------------------------------------//
+
+    type Key = K;
+
+    type Value = V;
+
+    //-----------------------------------------------------------------------//
+
+    fn new() -> Self {
+        SortedVecMap { items: Vec::new() }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Time complexity: O(n) worst case, due to shifting
+    fn insert(&mut self, key: Self::Key, value: Self::Value) -> bool {
+        match self.search(&key) {
+            Ok(index) => {
+                self.items[index].1 = value;
+                false
+            }
+            Err(index) => {
+                self.items.insert(index, (key, value));
+                true
+            }
+        }
+    }
+
+    /// Time complexity: O(n) worst case, due to shifting
+    fn remove(&mut self, key: &Self::Key) -> bool {
+        match self.search(key) {
+            Ok(index) => {
+                self.items.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Time complexity: O(log n)
+    fn contains_key(&self, key: &Self::Key) -> bool {
+        self.search(key).is_ok()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Time complexity: O(log n)
+    fn get(&self, key: &Self::Key) -> Option<&Self::Value> {
+        self.search(key).ok().map(|index| &self.items[index].1)
+    }
+
+    /// Time complexity: O(log n)
+    fn get_entry(&self, key: &Self::Key) -> Option<(&Self::Key, &Self::Value)> {
+        self.search(key).ok().map(|index| {
+            let (key, value) = &self.items[index];
+            (key, value)
+        })
+    }
+
+    /// Time complexity: O(log n)
+    fn get_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Value> {
+        self.search(key).ok().map(|index| &mut self.items[index].1)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn keys(&self) -> Vec<&Self::Key> {
+        self.items.iter().map(|(key, _)| key).collect()
+    }
+
+    fn values(&self) -> Vec<&Self::Value> {
+        self.items.iter().map(|(_, value)| value).collect()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<K: Ord, V> FromIterator<(K, V)> for SortedVecMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+
+        map
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<K: Ord + Debug, V: Debug> Debug for SortedVecMap<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SortedVecMap")
+            .field("items", &self.items)
+            .finish()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
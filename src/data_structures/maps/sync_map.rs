@@ -0,0 +1,209 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A map that can be shared between threads.
+
+The wrapped map lives behind a read-write lock: any number of threads can
+read at once, while a write waits for every reader to finish and then has
+the map to itself. That suits maps that are read a lot more than they're
+changed.
+
+Values are cloned out rather than handed back by reference, since a
+reference would have to keep the lock held for as long as it's around.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    fmt::{self, Debug},
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use super::Map;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Wraps any `Map` so it can be used from several threads at once
+///
+/// `SyncMap<M>` is `Send`/`Sync` whenever `M` is `Send` (and `Sync` for
+/// sharing), which includes `BST` and `AVL` with thread-safe keys and
+/// values.
+pub struct SyncMap<M: Map> {
+    map: RwLock<M>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<M: Map> SyncMap<M> {
+    //-----------------------------------------------------------------------//
+
+    /// Creates an empty map
+    pub fn new() -> Self {
+        Self::wrap(M::new())
+    }
+
+    /// Wraps `map`
+    pub fn wrap(map: M) -> Self {
+        Self {
+            map: RwLock::new(map),
+        }
+    }
+
+    /// Returns the wrapped map
+    pub fn into_inner(self) -> M {
+        self.map.into_inner().unwrap_or_else(|err| err.into_inner())
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns a copy of the value stored under `key`
+    ///
+    /// Only takes the shared (read) lock.
+    pub fn get(&self, key: &M::Key) -> Option<M::Value>
+    where
+        M::Value: Clone,
+    {
+        self.read().get(key).cloned()
+    }
+
+    /// Only takes the shared (read) lock.
+    pub fn contains_key(&self, key: &M::Key) -> bool {
+        self.read().contains_key(key)
+    }
+
+    //.......................................................................//
+
+    /// Same as `Map::insert`, takes the exclusive (write) lock
+    pub fn insert(&self, key: M::Key, value: M::Value) -> bool {
+        self.write().insert(key, value)
+    }
+
+    /// Same as `Map::remove`, takes the exclusive (write) lock
+    pub fn remove(&self, key: &M::Key) -> bool {
+        self.write().remove(key)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /*
+    A thread panicking while it holds the lock "poisons" it, since the map
+    might have been left half changed. None of the maps can be left broken by
+    a panic from the outside though (the only code that runs while the lock
+    is held is the map's own), so we carry on with the map as it is.
+    */
+
+    fn read(&self) -> RwLockReadGuard<'_, M> {
+        self.map.read().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, M> {
+        self.map.write().unwrap_or_else(|err| err.into_inner())
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<M: Map> Default for SyncMap<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+impl<M: Map + Debug> Debug for SyncMap<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyncMap")
+            .field("map", &*self.read())
+            .finish()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use std::thread;
+
+    use crate::data_structures::maps::{avl::AVL, bst::BST, sorted_vec_map::SortedVecMap};
+
+    use super::*;
+
+    fn readers_and_writers<M>(map: SyncMap<M>)
+    where
+        M: Map<Key = u32, Value = u32> + Send + Sync,
+    {
+        thread::scope(|scope| {
+            // each writer owns its own range of keys
+            for writer in 0..4 {
+                let map = &map;
+                scope.spawn(move || {
+                    for key in writer * 250..(writer + 1) * 250 {
+                        assert!(map.insert(key, key * 2));
+                    }
+                    // take every other one back out again
+                    for key in (writer * 250..(writer + 1) * 250).step_by(2) {
+                        assert!(map.remove(&key));
+                    }
+                });
+            }
+
+            // readers can see a key come and go, but never a wrong value
+            for _ in 0..4 {
+                let map = &map;
+                scope.spawn(move || {
+                    for _ in 0..20 {
+                        for key in 0..1000 {
+                            if let Some(value) = map.get(&key) {
+                                assert_eq!(value, key * 2);
+                            }
+                        }
+                        assert!(map.len() <= 1000);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(map.len(), 500);
+        for key in 0..1000 {
+            assert_eq!(map.contains_key(&key), key % 2 == 1);
+        }
+
+        let inner = map.into_inner();
+        assert_eq!(inner.get(&999), Some(&1998));
+    }
+
+    #[test]
+    fn threads() {
+        readers_and_writers(SyncMap::<BST<u32, u32>>::new());
+        readers_and_writers(SyncMap::<AVL<u32, u32>>::new());
+        readers_and_writers(SyncMap::<SortedVecMap<u32, u32>>::new());
+    }
+
+    #[test]
+    fn send_and_sync() {
+        fn assert_thread_safe<T: Send + Sync>() {}
+
+        assert_thread_safe::<BST<String, Vec<u8>>>();
+        assert_thread_safe::<AVL<String, Vec<u8>>>();
+        assert_thread_safe::<SyncMap<BST<String, Vec<u8>>>>();
+        assert_thread_safe::<SyncMap<AVL<String, Vec<u8>>>>();
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,264 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A wrapper that lets changes to a map be undone.
+
+Every change records how to reverse it in an undo log:
+    - inserting a new key is undone by removing it
+    - overwriting or removing a key is undone by putting the old value back
+
+Rolling back replays the log backwards, newest change first, so every key
+ends up as it was when the log was last cleared. Committing just throws the
+log away, keeping the changes.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use std::fmt::{self, Debug};
+
+use super::Map;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// How to undo a single change
+enum Undo<K, V> {
+    /// The key wasn't in the map before
+    Remove(K),
+    /// The key used to hold this value
+    Restore(K, V),
+}
+
+//---------------------------------------------------------------------------//
+
+/// Wraps any `Map` so changes made since the last `commit` can be rolled
+/// back
+pub struct TransactionalMap<M: Map> {
+    map: M,
+    log: Vec<Undo<M::Key, M::Value>>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<M: Map> TransactionalMap<M>
+where
+    M::Key: Clone,
+    M::Value: Clone,
+{
+    //-----------------------------------------------------------------------//
+
+    /// Wraps `map`, its current contents are treated as committed
+    pub fn wrap(map: M) -> Self {
+        Self { map, log: vec![] }
+    }
+
+    /// Returns the wrapped map, keeping any uncommitted changes
+    pub fn into_inner(self) -> M {
+        self.map
+    }
+
+    /// Returns the wrapped map, including any uncommitted changes
+    pub fn inner(&self) -> &M {
+        &self.map
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Keeps every change made so far, they can no longer be rolled back
+    pub fn commit(&mut self) {
+        self.log.clear();
+    }
+
+    /// Undoes every change made since the last commit
+    ///
+    /// - Time complexity: O(c) map operations, c = the number of changes
+    ///
+    pub fn rollback(&mut self) {
+        while let Some(undo) = self.log.pop() {
+            match undo {
+                Undo::Remove(key) => {
+                    self.map.remove(&key);
+                }
+                Undo::Restore(key, value) => {
+                    self.map.insert(key, value);
+                }
+            }
+        }
+    }
+
+    /// Returns how many changes would be undone by `rollback`
+    pub fn pending(&self) -> usize {
+        self.log.len()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Logs how to get `key` back to its current state
+    fn record(&mut self, key: &M::Key) {
+        let undo = match self.map.get_entry(key) {
+            Some((key, value)) => Undo::Restore(key.clone(), value.clone()),
+            None => Undo::Remove(key.clone()),
+        };
+
+        self.log.push(undo);
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<M: Map> Map for TransactionalMap<M>
+where
+    M::Key: Clone,
+    M::Value: Clone,
+{
+    //-----------------------------------------------------------------------//
+
+    type Key = M::Key;
+
+    type Value = M::Value;
+
+    //-----------------------------------------------------------------------//
+
+    fn new() -> Self {
+        Self::wrap(M::new())
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn insert(&mut self, key: Self::Key, value: Self::Value) -> bool {
+        self.record(&key);
+        self.map.insert(key, value)
+    }
+
+    fn remove(&mut self, key: &Self::Key) -> bool {
+        // nothing to undo if there's nothing to remove
+        if !self.map.contains_key(key) {
+            return false;
+        }
+
+        self.record(key);
+        self.map.remove(key)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn contains_key(&self, key: &Self::Key) -> bool {
+        self.map.contains_key(key)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn get(&self, key: &Self::Key) -> Option<&Self::Value> {
+        self.map.get(key)
+    }
+
+    fn get_entry(&self, key: &Self::Key) -> Option<(&Self::Key, &Self::Value)> {
+        self.map.get_entry(key)
+    }
+
+    /// The value might be changed through the reference, so the current
+    /// value is logged first
+    fn get_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Value> {
+        if self.map.contains_key(key) {
+            self.record(key);
+        }
+
+        self.map.get_mut(key)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn keys(&self) -> Vec<&Self::Key> {
+        self.map.keys()
+    }
+
+    fn values(&self) -> Vec<&Self::Value> {
+        self.map.values()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<M: Map + Debug> Debug for TransactionalMap<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransactionalMap")
+            .field("map", &self.map)
+            .field("pending", &self.log.len())
+            .finish()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::data_structures::maps::{avl::AVL, bst::BST, sorted_vec_map::SortedVecMap};
+
+    fn entries<M: Map<Key = i32, Value = i32>>(map: &M) -> Vec<(i32, i32)> {
+        map.keys()
+            .into_iter()
+            .map(|key| (*key, *map.get(key).unwrap()))
+            .collect()
+    }
+
+    fn check<M: Map<Key = i32, Value = i32>>(map: M) {
+        let mut map = TransactionalMap::wrap(map);
+
+        for key in 0..5 {
+            map.insert(key, key * 10);
+        }
+        map.commit();
+
+        let before = entries(map.inner());
+
+        // new keys, overwrites, removals and edits through get_mut
+        for key in 3..8 {
+            map.insert(key, -key);
+        }
+        assert!(map.remove(&0));
+        assert!(!map.remove(&100));
+        *map.get_mut(&1).unwrap() = 1000;
+        map.insert(5, 55);
+
+        assert_ne!(entries(map.inner()), before);
+
+        map.rollback();
+
+        assert_eq!(entries(map.inner()), before);
+        assert_eq!(map.pending(), 0);
+
+        // committed changes survive a later rollback
+        map.insert(9, 90);
+        map.remove(&2);
+        map.commit();
+        map.insert(10, 100);
+        map.rollback();
+
+        assert_eq!(
+            entries(&map.into_inner()),
+            vec![(0, 0), (1, 10), (3, 30), (4, 40), (9, 90)]
+        );
+    }
+
+    #[test]
+    fn rollback_and_commit() {
+        check(BST::new());
+        check(AVL::new());
+        check(SortedVecMap::new());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,186 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A queue in a circular buffer.
+
+A plain vector makes a poor queue: taking from the front shifts everything
+left, which is O(n). Instead we leave the elements where they are and keep
+track of where the front is. New elements go just past the back, wrapping
+around to the start of the vector once they hit the end, so the space freed
+up by dequeuing gets reused.
+
+    head = 2, len = 3
+
+    [_, _, a, b, c, _]      enqueue d, e
+    [e, _, a, b, c, d]      dequeue (a)
+    [e, _, _, b, c, d]
+
+When every slot is full we move everything into a vector twice the size,
+so enqueuing is amortized O(1) just like pushing onto a vector.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// A first-in first-out queue built on top of a vector, used as a ring
+/// buffer
+pub struct ArrayQueue<T> {
+    // empty slots are `None`
+    items: Vec<Option<T>>,
+    // the index of the front of the queue
+    head: usize,
+    len: usize,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> ArrayQueue<T> {
+    //-----------------------------------------------------------------------//
+
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Adds `data` to the back of the queue
+    ///
+    /// - Time complexity: amortized O(1), O(n) when the buffer has to grow
+    ///
+    pub fn enqueue(&mut self, data: T) {
+        if self.len == self.items.len() {
+            self.grow();
+        }
+
+        let back = self.slot(self.len);
+        self.items[back] = Some(data);
+        self.len += 1;
+    }
+
+    /// Removes and returns the front of the queue (the oldest element)
+    ///
+    /// - Time complexity: O(1)
+    ///
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let res = self.items[self.head].take();
+
+        self.head = self.slot(1);
+        self.len -= 1;
+
+        res
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the next element to be dequeued without removing it
+    pub fn peek(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.items[self.head].as_ref()
+    }
+
+    /// Returns the next element to be dequeued without removing it
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.items[self.head].as_mut()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns how far `item` is from the front of the queue (0 being the
+    /// front), or `None` if it isn't in the queue
+    ///
+    /// Time complexity: O(n)
+    pub fn position(&self, item: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        (0..self.len).position(|offset| self.items[self.slot(offset)].as_ref() == Some(item))
+    }
+
+    /// Returns whether `item` is somewhere in the queue
+    ///
+    /// Time complexity: O(n)
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.position(item).is_some()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// The index in `items` of the element `offset` places behind the front
+    fn slot(&self, offset: usize) -> usize {
+        (self.head + offset) % self.items.len()
+    }
+
+    /// Moves everything into a buffer twice the size (at least 4), with the
+    /// front of the queue back at index 0
+    fn grow(&mut self) {
+        let capacity = (self.items.len() * 2).max(4);
+
+        let mut items = Vec::with_capacity(capacity);
+
+        for offset in 0..self.len {
+            let index = self.slot(offset);
+            items.push(self.items[index].take());
+        }
+
+        items.resize_with(capacity, || None);
+
+        self.items = items;
+        self.head = 0;
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> Default for ArrayQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> FromIterator<T> for ArrayQueue<T> {
+    /// Enqueues the items in order, so the first one is at the front
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = ArrayQueue::new();
+
+        for item in iter {
+            queue.enqueue(item);
+        }
+
+        queue
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
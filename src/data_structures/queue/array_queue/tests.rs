@@ -0,0 +1,127 @@
+use crate::data_structures::queue::shared_test_cases::*;
+
+use super::solution::ArrayQueue;
+
+///////////////////////////////////////////////////////////////////////////////
+
+fn helper(cases: Vec<Vec<i32>>) {
+    for case in cases {
+        let mut queue = ArrayQueue::new();
+
+        for (i, item) in case.iter().enumerate() {
+            queue.enqueue(*item);
+            assert_eq!(queue.peek(), case.first());
+            assert_eq!(queue.len(), i + 1);
+        }
+
+        for (i, item) in case.iter().enumerate() {
+            assert_eq!(queue.peek(), Some(item));
+            assert_eq!(queue.dequeue(), Some(*item));
+            assert_eq!(queue.len(), case.len() - i - 1);
+        }
+
+        assert_eq!(queue.peek(), None);
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.len(), 0);
+        assert!(queue.is_empty());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn special_cases() {
+    helper(shared_special_cases())
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn random_cases() {
+    helper(shared_random_cases())
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn interleaved() {
+    let mut queue = ArrayQueue::new();
+    let mut next_in = 0_i32;
+    let mut next_out = 0_i32;
+
+    // keeps a handful of items in the queue while the front and back walk
+    // a long way forwards
+    for round in 0..1000 {
+        for _ in 0..(round % 5) {
+            queue.enqueue(next_in);
+            next_in += 1;
+        }
+
+        for _ in 0..(round % 3) {
+            if let Some(item) = queue.dequeue() {
+                assert_eq!(item, next_out);
+                next_out += 1;
+            }
+        }
+
+        assert_eq!(queue.len() as i32, next_in - next_out);
+    }
+
+    if let Some(front) = queue.peek_mut() {
+        *front = -1;
+    }
+
+    assert_eq!(queue.dequeue(), Some(-1));
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn contains() {
+    let mut queue = ArrayQueue::new();
+
+    assert!(!queue.contains(&1));
+
+    for i in 1..=5 {
+        queue.enqueue(i);
+    }
+
+    assert!((1..=5).all(|i| queue.contains(&i)));
+    assert!(!queue.contains(&0));
+    assert!(!queue.contains(&6));
+
+    queue.dequeue();
+
+    // 1 was at the front, 2 is now
+    assert!(!queue.contains(&1));
+    assert_eq!(queue.position(&2), Some(0));
+    assert_eq!(queue.position(&5), Some(3));
+
+    // wrap around the end of the buffer
+    for i in 6..10 {
+        queue.enqueue(i);
+    }
+
+    assert!(queue.contains(&9));
+    assert_eq!(queue.position(&9), Some(7));
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn collect() {
+    for case in shared_random_cases() {
+        let mut queue: ArrayQueue<i32> = case.iter().copied().collect();
+
+        assert_eq!(queue.len(), case.len());
+
+        // the first item collected is at the front
+        for item in &case {
+            assert_eq!(queue.dequeue(), Some(*item));
+        }
+
+        assert!(queue.is_empty());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
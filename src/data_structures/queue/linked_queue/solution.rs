@@ -0,0 +1,117 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::fmt;
+
+use crate::data_structures::linked_list::double_linked_list::solution::LinkedList;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// A first-in first-out queue built on top of the double linked list
+///
+/// Items join at the back of the list and leave from the front, both of
+/// which are O(1) since the list keeps a pointer to each end.
+pub struct LinkedQueue<T>
+where
+    T: Ord + fmt::Debug,
+{
+    list: LinkedList<T>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> LinkedQueue<T>
+where
+    T: Ord + fmt::Debug,
+{
+    //-----------------------------------------------------------------------//
+
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Adds `data` to the back of the queue
+    pub fn enqueue(&mut self, data: T) {
+        self.list.push_back(data);
+    }
+
+    /// Removes and returns the front of the queue (the oldest element)
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the next element to be dequeued without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    /// Returns the next element to be dequeued without removing it
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.list.front_mut()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns how far `item` is from the front of the queue (0 being the
+    /// front), or `None` if it isn't in the queue
+    ///
+    /// Time complexity: O(n)
+    pub fn position(&self, item: &T) -> Option<usize> {
+        self.list.iter().position(|other| other == item)
+    }
+
+    /// Returns whether `item` is somewhere in the queue
+    ///
+    /// Time complexity: O(n)
+    pub fn contains(&self, item: &T) -> bool {
+        self.position(item).is_some()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> Default for LinkedQueue<T>
+where
+    T: Ord + fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> FromIterator<T> for LinkedQueue<T>
+where
+    T: Ord + fmt::Debug,
+{
+    /// Enqueues the items in order, so the first one is at the front
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = LinkedQueue::new();
+
+        for item in iter {
+            queue.enqueue(item);
+        }
+
+        queue
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
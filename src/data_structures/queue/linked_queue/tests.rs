@@ -0,0 +1,135 @@
+use crate::data_structures::queue::shared_test_cases::*;
+
+use super::solution::LinkedQueue;
+
+///////////////////////////////////////////////////////////////////////////////
+
+fn helper(cases: Vec<Vec<i32>>) {
+    for case in cases {
+        let mut queue = LinkedQueue::new();
+
+        for (i, item) in case.iter().enumerate() {
+            queue.enqueue(*item);
+            assert_eq!(queue.peek(), case.first());
+            assert_eq!(queue.len(), i + 1);
+        }
+
+        for (i, item) in case.iter().enumerate() {
+            assert_eq!(queue.peek(), Some(item));
+            assert_eq!(queue.dequeue(), Some(*item));
+            assert_eq!(queue.len(), case.len() - i - 1);
+        }
+
+        assert_eq!(queue.peek(), None);
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.len(), 0);
+        assert!(queue.is_empty());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn special_cases() {
+    helper(shared_special_cases())
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn random_cases() {
+    helper(shared_random_cases())
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn interleaved() {
+    let mut queue = LinkedQueue::new();
+    let mut next_in = 0_i32;
+    let mut next_out = 0_i32;
+
+    // keeps a handful of items in the queue while the front and back walk
+    // a long way forwards
+    for round in 0..1000 {
+        for _ in 0..(round % 5) {
+            queue.enqueue(next_in);
+            next_in += 1;
+        }
+
+        for _ in 0..(round % 3) {
+            if let Some(item) = queue.dequeue() {
+                assert_eq!(item, next_out);
+                next_out += 1;
+            }
+        }
+
+        assert_eq!(queue.len() as i32, next_in - next_out);
+    }
+
+    if let Some(front) = queue.peek_mut() {
+        *front = -1;
+    }
+
+    assert_eq!(queue.dequeue(), Some(-1));
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn contains() {
+    let mut queue = LinkedQueue::new();
+
+    assert!(!queue.contains(&1));
+
+    for i in 1..=5 {
+        queue.enqueue(i);
+    }
+
+    assert!((1..=5).all(|i| queue.contains(&i)));
+    assert!(!queue.contains(&0));
+    assert!(!queue.contains(&6));
+
+    // dropping the head node leaves the rest reachable from the new one
+    queue.dequeue();
+
+    assert!(!queue.contains(&1));
+    assert_eq!(queue.position(&2), Some(0));
+    assert_eq!(queue.position(&5), Some(3));
+
+    // once drained, both ends of the list are empty again
+    while queue.dequeue().is_some() {}
+
+    assert!((1..=5).all(|i| !queue.contains(&i)));
+    assert_eq!(queue.position(&5), None);
+
+    // and refilling links new nodes from scratch rather than onto old ones
+    for i in 6..=8 {
+        queue.enqueue(i);
+    }
+
+    assert!(!queue.contains(&5));
+    assert_eq!(queue.position(&6), Some(0));
+    assert_eq!(queue.position(&8), Some(2));
+    assert_eq!(queue.len(), 3);
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn collect() {
+    for case in shared_random_cases() {
+        let mut queue: LinkedQueue<i32> = case.iter().copied().collect();
+
+        assert_eq!(queue.len(), case.len());
+
+        // the first item collected is at the front
+        for item in &case {
+            assert_eq!(queue.dequeue(), Some(*item));
+        }
+
+        assert!(queue.is_empty());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
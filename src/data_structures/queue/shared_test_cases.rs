@@ -0,0 +1,19 @@
+///////////////////////////////////////////////////////////////////////////////
+
+pub fn shared_special_cases() -> Vec<Vec<i32>> {
+    vec![vec![], vec![1]]
+}
+
+//---------------------------------------------------------------------------//
+
+pub fn shared_random_cases() -> Vec<Vec<i32>> {
+    vec![
+        vec![1, 2, 3],
+        vec![1, 3, 2],
+        vec![3, 3, 3],
+        vec![593, 52, 0, 40104, 20, 19, 2, 30, 8],
+        (0..1000).collect(),
+    ]
+}
+
+///////////////////////////////////////////////////////////////////////////////
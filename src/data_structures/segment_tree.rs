@@ -0,0 +1,231 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A segment tree answers "combine everything in lo..hi" queries and single
+element updates in O(log n), for any associative combine function: sum, min,
+max, gcd, ...
+
+It's a complete binary tree laid out in an array, much like the binary heap.
+The leaves hold the elements (at indices n..2n) and every other node i holds
+the combination of its children 2i and 2i + 1, so the root (index 1) covers
+everything. A range can be covered by at most about 2 log(n) nodes, which we
+find by climbing up from both ends of the range at once.
+
+Unlike the Fenwick tree, the combine function doesn't need an inverse (there
+is no "un-min"), it only has to be associative. It doesn't even need to be
+commutative: the left and right ends of a query are combined separately and
+kept in order.
+
+See https://codeforces.com/blog/entry/18051 for this bottom-up layout
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use std::fmt;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct SegmentTree<T, F>
+where
+    F: Fn(&T, &T) -> T,
+{
+    // tree[n..2n] are the elements, tree[i] combines tree[2i] and
+    // tree[2i + 1], tree[0] is unused
+    tree: Vec<T>,
+    len: usize,
+    combine: F,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T, F> SegmentTree<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    //-----------------------------------------------------------------------//
+
+    /// Builds a tree over `values`
+    ///
+    /// - Inputs:
+    ///     - `values: &[T]` The elements to query over
+    ///     - `combine: F` An associative function, e.g. `|a, b| a + b` for
+    ///       range sums or `|a, b| *a.min(b)` for range minimums
+    /// - Time complexity: O(n)
+    pub fn build(values: &[T], combine: F) -> Self {
+        let len = values.len();
+
+        // the unused slot 0 needs something in it, any element will do
+        let mut tree: Vec<T> = values.iter().chain(values).cloned().collect();
+
+        // fill in the internal nodes from the bottom up
+        for i in (1..len).rev() {
+            tree[i] = combine(&tree[2 * i], &tree[2 * i + 1]);
+        }
+
+        Self { tree, len, combine }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns all the elements at indices `lo..hi` combined, in order
+    ///
+    /// - Output: `None` if the range is empty
+    /// - Time complexity: O(log(n))
+    pub fn query(&self, lo: usize, hi: usize) -> Option<T> {
+        assert!(
+            lo <= hi && hi <= self.len,
+            "range {}..{} out of bounds",
+            lo,
+            hi
+        );
+
+        // results from the left and right edges of the range, kept apart so
+        // the order of the elements is respected
+        let mut left: Option<T> = None;
+        let mut right: Option<T> = None;
+
+        let (mut l, mut r) = (lo + self.len, hi + self.len);
+
+        while l < r {
+            // if l is a right child its parent reaches outside the range, so
+            // take l on its own and move past it
+            if l % 2 == 1 {
+                left = Some(match left {
+                    Some(left) => (self.combine)(&left, &self.tree[l]),
+                    None => self.tree[l].clone(),
+                });
+                l += 1;
+            }
+
+            // same for r (which is exclusive) being a right child
+            if r % 2 == 1 {
+                r -= 1;
+                right = Some(match right {
+                    Some(right) => (self.combine)(&self.tree[r], &right),
+                    None => self.tree[r].clone(),
+                });
+            }
+
+            l /= 2;
+            r /= 2;
+        }
+
+        match (left, right) {
+            (Some(left), Some(right)) => Some((self.combine)(&left, &right)),
+            (left, right) => left.or(right),
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Replaces the element at index `i` with `value`
+    ///
+    /// - Time complexity: O(log(n))
+    pub fn update(&mut self, i: usize, value: T) {
+        assert!(i < self.len, "index {} out of bounds", i);
+
+        let mut index = i + self.len;
+        self.tree[index] = value;
+
+        // recompute every node above it
+        while index > 1 {
+            index /= 2;
+            self.tree[index] = (self.combine)(&self.tree[2 * index], &self.tree[2 * index + 1]);
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T, F> fmt::Debug for SegmentTree<T, F>
+where
+    T: fmt::Debug,
+    F: Fn(&T, &T) -> T,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SegmentTree")
+            .field("values", &&self.tree[self.len..])
+            .finish()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use crate::meta::rng::XorShift;
+
+    use super::*;
+
+    #[test]
+    fn min_and_sum() {
+        let mut rng = XorShift::new(17);
+
+        for n in [1, 2, 3, 8, 13, 50] {
+            let mut values: Vec<i64> = (0..n).map(|_| rng.next_index(1000) as i64 - 500).collect();
+
+            let mut mins = SegmentTree::build(&values, |a: &i64, b: &i64| *a.min(b));
+            let mut sums = SegmentTree::build(&values, |a: &i64, b: &i64| a + b);
+
+            assert_eq!(mins.len(), n);
+
+            for _ in 0..500 {
+                let lo = rng.next_index(n + 1);
+                let hi = lo + rng.next_index(n + 1 - lo);
+
+                assert_eq!(mins.query(lo, hi), values[lo..hi].iter().min().copied());
+                assert_eq!(
+                    sums.query(lo, hi),
+                    (lo < hi).then(|| values[lo..hi].iter().sum())
+                );
+
+                let i = rng.next_index(n);
+                let value = rng.next_index(1000) as i64 - 500;
+
+                values[i] = value;
+                mins.update(i, value);
+                sums.update(i, value);
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_order() {
+        // concatenation isn't commutative, so this catches mixed up sides
+        let letters: Vec<String> = "abcdefghijk".chars().map(String::from).collect();
+        let mut tree = SegmentTree::build(&letters, |a: &String, b: &String| format!("{}{}", a, b));
+
+        assert_eq!(tree.query(0, 11).unwrap(), "abcdefghijk");
+        assert_eq!(tree.query(3, 9).unwrap(), "defghi");
+        assert_eq!(tree.query(5, 5), None);
+
+        tree.update(4, String::from("E"));
+        assert_eq!(tree.query(1, 7).unwrap(), "bcdEfg");
+    }
+
+    #[test]
+    fn empty() {
+        let tree = SegmentTree::build(&[], |a: &i32, b: &i32| a + b);
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.query(0, 0), None);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
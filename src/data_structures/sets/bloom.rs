@@ -0,0 +1,254 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A Bloom filter answers "have I seen this before?" using far less memory than
+storing the items themselves, at the cost of sometimes saying yes when the
+answer is no (a false positive). It never says no when the answer is yes.
+
+Each item is hashed k times into a bit array of m bits, and inserting sets
+those k bits. Checking an item looks at the same k bits: if any are unset
+the item was definitely never inserted, but if all are set they might have
+been set by other items.
+
+For n items and a target false positive rate p, the best choices are
+
+    m = -n ln(p) / ln(2)^2
+    k = (m / n) ln(2)
+
+Rather than k separate hash functions, we take two and combine them as
+h1 + i * h2 (double hashing), which is about as good in practice.
+
+See https://en.wikipedia.org/wiki/Bloom_filter
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::meta::fnv::FnvHasher;
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct BloomFilter<T: Hash> {
+    bits: Vec<u64>,
+    // number of bits in use, `bits` rounds up to a multiple of 64
+    size: usize,
+    hashes: usize,
+    inserted: usize,
+    _ghost: PhantomData<T>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T: Hash> BloomFilter<T> {
+    //-----------------------------------------------------------------------//
+
+    /// Creates a filter sized for `expected_items` items with a false
+    /// positive rate of about `fpr`
+    ///
+    /// - Inputs:
+    ///     - `expected_items: usize` How many items will be inserted, the
+    ///       false positive rate climbs past `fpr` if more are
+    ///     - `fpr: f64` The target false positive rate, in `(0, 1)`
+    /// - Time complexity: O(m)
+    ///     - `m` = the number of bits needed
+    pub fn new(expected_items: usize, fpr: f64) -> Self {
+        assert!(fpr > 0.0 && fpr < 1.0, "fpr must be in (0, 1), got {}", fpr);
+
+        let n = expected_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+
+        let size = (-n * fpr.ln() / (ln2 * ln2)).ceil().max(1.0) as usize;
+        let hashes = ((size as f64 / n) * ln2).round().max(1.0) as usize;
+
+        Self {
+            bits: vec![0; size.div_ceil(64)],
+            size,
+            hashes,
+            inserted: 0,
+            _ghost: PhantomData,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Adds `item` to the filter
+    ///
+    /// - Time complexity: O(k)
+    ///     - `k` = the number of hashes
+    pub fn insert(&mut self, item: &T) {
+        for bit in self.bit_indices(item) {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+
+        self.inserted += 1;
+    }
+
+    //.......................................................................//
+
+    /// Returns whether `item` might have been inserted
+    ///
+    /// - Output: `bool`
+    ///     - `false` means `item` was definitely never inserted
+    ///     - `true` means it probably was, see `estimated_fpr`
+    /// - Time complexity: O(k)
+    ///     - `k` = the number of hashes
+    pub fn contains(&self, item: &T) -> bool {
+        self.bit_indices(item)
+            .all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the expected false positive rate for the items inserted so far
+    ///
+    /// That's `(1 - e^(-kn / m))^k`, which assumes the hashes are
+    /// independent and uniform.
+    pub fn estimated_fpr(&self) -> f64 {
+        let k = self.hashes as f64;
+        let n = self.inserted as f64;
+        let m = self.size as f64;
+
+        (1.0 - (-k * n / m).exp()).powf(k)
+    }
+
+    //.......................................................................//
+
+    /// Returns the number of bits in the filter
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of hashes per item
+    pub fn hashes(&self) -> usize {
+        self.hashes
+    }
+
+    /// Returns the number of insertions (counting repeats)
+    pub fn len(&self) -> usize {
+        self.inserted
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inserted == 0
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the `k` bits for `item`
+    fn bit_indices(&self, item: &T) -> impl Iterator<Item = usize> {
+        // both hashers are unseeded, so the bits are the same every run
+        let mut first = DefaultHasher::new();
+        item.hash(&mut first);
+        let h1 = first.finish();
+
+        let mut second = FnvHasher::default();
+        item.hash(&mut second);
+        // an even step could cycle through only part of a power of two size
+        let h2 = second.finish() | 1;
+
+        let size = self.size as u64;
+
+        (0..self.hashes as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % size) as usize)
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T: Hash> FromIterator<T> for BloomFilter<T> {
+    /// Builds a filter sized for exactly the given items, with a false
+    /// positive rate of about 1%
+    ///
+    /// Use `new` and `insert` instead if more items will be added later.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        // the size depends on the count, so we need them all up front
+        let items: Vec<T> = iter.into_iter().collect();
+        let mut filter = BloomFilter::new(items.len(), 0.01);
+
+        for item in &items {
+            filter.insert(item);
+        }
+
+        filter
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn sizing() {
+        // 1% needs about 9.6 bits and 7 hashes per item
+        let filter: BloomFilter<u32> = BloomFilter::new(1000, 0.01);
+
+        assert_eq!(filter.size(), 9586);
+        assert_eq!(filter.hashes(), 7);
+        assert!(filter.is_empty());
+        assert_eq!(filter.estimated_fpr(), 0.0);
+    }
+
+    #[test]
+    fn false_positives() {
+        let target = 0.01;
+        let mut filter = BloomFilter::new(1000, target);
+
+        for i in 0..1000u32 {
+            filter.insert(&i);
+        }
+
+        // never a false negative
+        assert!((0..1000u32).all(|i| filter.contains(&i)));
+
+        let trials = 100_000;
+        let false_positives = (1000..1000 + trials).filter(|i| filter.contains(i)).count();
+        let measured = false_positives as f64 / trials as f64;
+
+        assert!(
+            measured < target * 1.5 && measured > target / 1.5,
+            "measured {} vs target {}",
+            measured,
+            target
+        );
+        assert!((filter.estimated_fpr() - target).abs() < target * 0.1);
+    }
+
+    #[test]
+    fn strings() {
+        let mut filter = BloomFilter::new(10, 0.001);
+
+        filter.insert(&"apple");
+        filter.insert(&"banana");
+
+        assert!(filter.contains(&"apple"));
+        assert!(filter.contains(&"banana"));
+        assert!(!filter.contains(&"cherry"));
+    }
+
+    #[test]
+    fn collect() {
+        let filter: BloomFilter<u32> = (0..500).collect();
+
+        assert_eq!(filter.len(), 500);
+        assert_eq!(filter.size(), BloomFilter::<u32>::new(500, 0.01).size());
+        assert!((0..500u32).all(|i| filter.contains(&i)));
+
+        let empty: BloomFilter<u32> = std::iter::empty().collect();
+        assert!(empty.is_empty());
+        assert!(!empty.contains(&0));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
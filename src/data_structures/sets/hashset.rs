@@ -121,6 +121,27 @@ where
 
 ///////////////////////////////////////////////////////////////////////////////
 
+impl<T> FromIterator<T> for HashSet<T>
+where
+    T: Ord + fmt::Debug + Clone,
+    Vec<u8>: From<T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+
+        // we need at least one bucket to hash into
+        let mut set = HashSet::new().with_capacity(items.len().max(1));
+
+        for item in items {
+            set.insert(item);
+        }
+
+        set
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
     use super::*;
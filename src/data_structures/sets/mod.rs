@@ -1,5 +1,6 @@
 ///////////////////////////////////////////////////////////////////////////////
 
+pub mod bloom;
 pub mod hashset;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -13,6 +14,10 @@ pub trait Set {
     fn contains(&self, item: Self::Item) -> bool;
 
     fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,230 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A priority queue where items with equal priority come out in the order they
+went in (FIFO), which is what discrete-event simulations expect.
+
+A binary heap on its own makes no promises about ties, so every item is
+tagged with an ever increasing sequence number and the heap orders by
+`(priority, sequence)` instead. Two items can never tie on both.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use core::fmt;
+use std::cmp::Ordering;
+
+use super::binary_heap::BinaryHeap;
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Default)]
+struct Entry<P, V> {
+    priority: P,
+    seq: u64,
+    value: V,
+}
+
+//---------------------------------------------------------------------------//
+
+// entries only ever compare on `(priority, seq)`, the value doesn't need to
+// be comparable at all
+
+impl<P: Ord, V> PartialEq for Entry<P, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<P: Ord, V> Eq for Entry<P, V> {}
+
+impl<P: Ord, V> PartialOrd for Entry<P, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Ord, V> Ord for Entry<P, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.priority, self.seq).cmp(&(&other.priority, other.seq))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct StablePriorityQueue<P, V>
+where
+    P: Ord + Clone + Default + fmt::Debug,
+    V: Clone + Default + fmt::Debug,
+{
+    heap: BinaryHeap<Entry<P, V>>,
+    // sequence number for the next pushed item
+    next_seq: u64,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<P, V> StablePriorityQueue<P, V>
+where
+    P: Ord + Clone + Default + fmt::Debug,
+    V: Clone + Default + fmt::Debug,
+{
+    //-----------------------------------------------------------------------//
+
+    pub fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            next_seq: 0,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Adds `value` to the queue with the given priority
+    ///
+    /// - Time complexity: O(log(n))
+    pub fn push(&mut self, priority: P, value: V) {
+        self.heap.insert(Entry {
+            priority,
+            seq: self.next_seq,
+            value,
+        });
+        self.next_seq += 1;
+    }
+
+    //.......................................................................//
+
+    /// Removes and returns the item with the lowest priority, or the earliest
+    /// pushed one if several share the lowest priority
+    ///
+    /// - Output: `None` if the queue is empty
+    /// - Time complexity: O(log(n))
+    pub fn pop(&mut self) -> Option<(P, V)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let entry = self.heap.extract_min();
+        Some((entry.priority, entry.value))
+    }
+
+    //.......................................................................//
+
+    /// Returns the item `pop` would remove next, without removing it
+    ///
+    /// - Time complexity: O(1)
+    pub fn peek(&self) -> Option<(&P, &V)> {
+        self.heap.min().map(|entry| (&entry.priority, &entry.value))
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<P, V> Default for StablePriorityQueue<P, V>
+where
+    P: Ord + Clone + Default + fmt::Debug,
+    V: Clone + Default + fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<P, V> FromIterator<(P, V)> for StablePriorityQueue<P, V>
+where
+    P: Ord + Clone + Default + fmt::Debug,
+    V: Clone + Default + fmt::Debug,
+{
+    /// Pushes the `(priority, value)` pairs in order, so ties come back out
+    /// in the order they were given
+    fn from_iter<I: IntoIterator<Item = (P, V)>>(iter: I) -> Self {
+        let mut queue = StablePriorityQueue::new();
+
+        for (priority, value) in iter {
+            queue.push(priority, value);
+        }
+
+        queue
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn equal_priorities() {
+        let mut queue = StablePriorityQueue::new();
+
+        queue.push(1, "first");
+        queue.push(1, "second");
+        queue.push(1, "third");
+
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek(), Some((&1, &"first")));
+
+        assert_eq!(queue.pop(), Some((1, "first")));
+        assert_eq!(queue.pop(), Some((1, "second")));
+        assert_eq!(queue.pop(), Some((1, "third")));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn mixed_priorities() {
+        let mut queue = StablePriorityQueue::new();
+
+        queue.push(5, 'a');
+        queue.push(2, 'b');
+        queue.push(5, 'c');
+        queue.push(0, 'd');
+        queue.push(2, 'e');
+        queue.push(9, 'f');
+
+        let order: Vec<(i32, char)> = std::iter::from_fn(|| queue.pop()).collect();
+
+        assert_eq!(
+            order,
+            vec![(0, 'd'), (2, 'b'), (2, 'e'), (5, 'a'), (5, 'c'), (9, 'f')]
+        );
+    }
+
+    #[test]
+    fn collect() {
+        let mut queue: StablePriorityQueue<i32, char> =
+            [(5, 'a'), (2, 'b'), (5, 'c'), (0, 'd'), (2, 'e')]
+                .into_iter()
+                .collect();
+
+        assert_eq!(queue.len(), 5);
+
+        let order: Vec<(i32, char)> = std::iter::from_fn(|| queue.pop()).collect();
+
+        // ties come out in the order they were collected
+        assert_eq!(
+            order,
+            vec![(0, 'd'), (2, 'b'), (2, 'e'), (5, 'a'), (5, 'c')]
+        );
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,135 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/// The stack never shrinks below this many slots
+const MIN_CAPACITY: usize = 4;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// A stack built on top of a vector
+///
+/// The top of the stack is the last element, so pushing and popping are
+/// both amortized O(1).
+///
+/// Capacity doubles when the stack is full and halves once it's less than a
+/// quarter full, so a stack that shrinks gives its memory back.
+pub struct ArrayStack<T> {
+    items: Vec<T>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> ArrayStack<T> {
+    //-----------------------------------------------------------------------//
+
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Puts `data` on top of the stack
+    pub fn push(&mut self, data: T) {
+        if self.items.len() == self.items.capacity() {
+            let grow = self.items.capacity().max(MIN_CAPACITY);
+            self.items.reserve_exact(grow);
+        }
+
+        self.items.push(data);
+    }
+
+    /// Removes and returns the top of the stack
+    pub fn pop(&mut self) -> Option<T> {
+        let data = self.items.pop();
+
+        /*
+        Halving as soon as we're half empty would thrash: a stack sitting right
+        at the boundary would reallocate on every push/pop pair.
+        Waiting until we're a quarter full means after halving we're still
+        only half full, so it takes at least cap / 4 more operations before the
+        next resize, which pays for the copy and keeps both amortized O(1).
+        */
+        let capacity = self.items.capacity();
+        if capacity > MIN_CAPACITY && self.items.len() < capacity / 4 {
+            self.items.shrink_to(capacity / 2);
+        }
+
+        data
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the most recently pushed element without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// Returns the most recently pushed element without removing it
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.items.last_mut()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns how far `item` is from the top of the stack (0 being the top),
+    /// or `None` if it isn't in the stack
+    ///
+    /// Time complexity: O(n)
+    pub fn position(&self, item: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.items.iter().rev().position(|other| other == item)
+    }
+
+    /// Returns whether `item` is somewhere in the stack
+    ///
+    /// Time complexity: O(n)
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.position(item).is_some()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns how many elements the stack can hold before reallocating
+    pub fn capacity(&self) -> usize {
+        self.items.capacity()
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> Default for ArrayStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> FromIterator<T> for ArrayStack<T> {
+    /// Pushes the items in order, so the last one ends up on top
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = ArrayStack::new();
+
+        for item in iter {
+            stack.push(item);
+        }
+
+        stack
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,126 @@
+///////////////////////////////////////////////////////////////////////////////
+
+use std::fmt;
+
+use crate::data_structures::linked_list::single_linked_list::solution::LinkedList;
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// A stack built on top of the single linked list
+///
+/// The top of the stack is the front of the list, so pushing and popping
+/// are both O(1).
+pub struct LinkedStack<T>
+where
+    T: Ord + fmt::Debug,
+{
+    list: LinkedList<T>,
+    len: usize,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> LinkedStack<T>
+where
+    T: Ord + fmt::Debug,
+{
+    //-----------------------------------------------------------------------//
+
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+            len: 0,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Puts `data` on top of the stack
+    pub fn push(&mut self, data: T) {
+        self.list.push(data);
+        self.len += 1;
+    }
+
+    /// Removes and returns the top of the stack
+    pub fn pop(&mut self) -> Option<T> {
+        let res = self.list.pop();
+
+        if res.is_some() {
+            self.len -= 1;
+        }
+
+        res
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the most recently pushed element without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.list.peek()
+    }
+
+    /// Returns the most recently pushed element without removing it
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.list.peek_mut()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns how far `item` is from the top of the stack (0 being the top),
+    /// or `None` if it isn't in the stack
+    ///
+    /// Time complexity: O(n)
+    pub fn position(&self, item: &T) -> Option<usize> {
+        self.list.search(item)
+    }
+
+    /// Returns whether `item` is somewhere in the stack
+    ///
+    /// Time complexity: O(n)
+    pub fn contains(&self, item: &T) -> bool {
+        self.position(item).is_some()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> Default for LinkedStack<T>
+where
+    T: Ord + fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<T> FromIterator<T> for LinkedStack<T>
+where
+    T: Ord + fmt::Debug,
+{
+    /// Pushes the items in order, so the last one ends up on top
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = LinkedStack::new();
+
+        for item in iter {
+            stack.push(item);
+        }
+
+        stack
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
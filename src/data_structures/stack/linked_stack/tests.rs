@@ -0,0 +1,113 @@
+use crate::data_structures::stack::shared_test_cases::*;
+
+use super::solution::LinkedStack;
+
+///////////////////////////////////////////////////////////////////////////////
+
+fn helper(cases: Vec<Vec<i32>>) {
+    for case in cases {
+        let mut stack = LinkedStack::new();
+
+        for (i, item) in case.iter().enumerate() {
+            stack.push(*item);
+            assert_eq!(stack.peek(), Some(item));
+            assert_eq!(stack.len(), i + 1);
+        }
+
+        for (i, item) in case.iter().enumerate().rev() {
+            assert_eq!(stack.peek(), Some(item));
+            assert_eq!(stack.pop(), Some(*item));
+            assert_eq!(stack.len(), i);
+        }
+
+        assert_eq!(stack.peek(), None);
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.len(), 0);
+        assert!(stack.is_empty());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn special_cases() {
+    helper(shared_special_cases())
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn random_cases() {
+    helper(shared_random_cases())
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn peek() {
+    let mut stack = LinkedStack::new();
+
+    assert_eq!(stack.peek(), None);
+    assert_eq!(stack.peek_mut(), None);
+
+    stack.push(1);
+    stack.push(2);
+    stack.push(3);
+
+    assert_eq!(stack.peek(), Some(&3));
+
+    if let Some(top) = stack.peek_mut() {
+        *top *= 10;
+    }
+
+    assert_eq!(stack.peek(), Some(&30));
+    assert_eq!(stack.pop(), Some(30));
+    assert_eq!(stack.peek(), Some(&2));
+    assert_eq!(stack.pop(), Some(2));
+    assert_eq!(stack.pop(), Some(1));
+    assert_eq!(stack.pop(), None);
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn contains() {
+    let mut stack = LinkedStack::new();
+
+    assert!(!stack.contains(&1));
+
+    for i in 1..=5 {
+        stack.push(i);
+    }
+
+    assert!((1..=5).all(|i| stack.contains(&i)));
+    assert!(!stack.contains(&0));
+    assert!(!stack.contains(&6));
+
+    stack.pop();
+
+    // 5 was on top, 4 is now
+    assert!(!stack.contains(&5));
+    assert_eq!(stack.position(&4), Some(0));
+    assert_eq!(stack.position(&1), Some(3));
+}
+
+//---------------------------------------------------------------------------//
+
+#[test]
+fn collect() {
+    for case in shared_random_cases() {
+        let mut stack: LinkedStack<i32> = case.iter().copied().collect();
+
+        assert_eq!(stack.len(), case.len());
+
+        // the last item collected is on top
+        for item in case.iter().rev() {
+            assert_eq!(stack.pop(), Some(*item));
+        }
+
+        assert!(stack.is_empty());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,256 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A trie (prefix tree) stores strings one character per level, so all the
+words sharing a prefix share the path for it.
+
+    insert "to", "tea", "ten":
+
+        (root)
+          t
+         / \
+      [o]   e
+           / \
+         [a] [n]
+
+    ([x] marks the end of a word)
+
+Looking up a word or a prefix only depends on its length, not on how many
+words are stored, and every word starting with a prefix lives in the subtree
+under it, which is what makes tries handy for autocomplete.
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashMap;
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Default)]
+pub struct Trie {
+    root: Node,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: HashMap<char, Node>,
+    // whether a word ends here, rather than just passing through
+    terminal: bool,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl Trie {
+    //-----------------------------------------------------------------------//
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Adds `word` to the trie
+    ///
+    /// - Output: `bool` Whether `word` is new
+    /// - Time complexity: O(k)
+    ///     - `k` = the number of chars in `word`
+    pub fn insert(&mut self, word: &str) -> bool {
+        let mut node = &mut self.root;
+
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+
+        let new = !node.terminal;
+        node.terminal = true;
+
+        if new {
+            self.len += 1;
+        }
+
+        new
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns whether `word` was inserted (not just a prefix of a word)
+    ///
+    /// - Time complexity: O(k)
+    ///     - `k` = the number of chars in `word`
+    pub fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| node.terminal)
+    }
+
+    //.......................................................................//
+
+    /// Returns whether any inserted word starts with `prefix`
+    ///
+    /// - Time complexity: O(k)
+    ///     - `k` = the number of chars in `prefix`
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    //.......................................................................//
+
+    /// Returns every inserted word starting with `prefix`, sorted
+    ///
+    /// - Time complexity: O(k + m log(m))
+    ///     - `k` = the number of chars in `prefix`
+    ///     - `m` = the total length of the matching words
+    pub fn collect_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = vec![];
+
+        if let Some(node) = self.find(prefix) {
+            let mut word = String::from(prefix);
+            Self::collect(node, &mut word, &mut words);
+        }
+
+        // children live in a HashMap, so they come out in no particular order
+        words.sort();
+        words
+    }
+
+    //-----------------------------------------------------------------------//
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the node at the end of the path spelling `prefix`
+    fn find(&self, prefix: &str) -> Option<&Node> {
+        let mut node = &self.root;
+
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+
+        Some(node)
+    }
+
+    //.......................................................................//
+
+    /// Adds every word in the subtree at `node` to `words`
+    ///
+    /// `word` is the path to `node`, it's pushed onto and popped back off as
+    /// we go down and up the tree.
+    fn collect(node: &Node, word: &mut String, words: &mut Vec<String>) {
+        if node.terminal {
+            words.push(word.clone());
+        }
+
+        for (c, child) in &node.children {
+            word.push(*c);
+            Self::collect(child, word, words);
+            word.pop();
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<S: AsRef<str>> FromIterator<S> for Trie {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+
+        for word in iter {
+            trie.insert(word.as_ref());
+        }
+
+        trie
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn words() -> Trie {
+        let mut trie = Trie::new();
+
+        for word in ["to", "tea", "ted", "ten", "i", "in", "inn", "team"] {
+            assert!(trie.insert(word));
+        }
+
+        trie
+    }
+
+    #[test]
+    fn contains() {
+        let mut trie = words();
+
+        assert_eq!(trie.len(), 8);
+        assert!(!trie.insert("ten"));
+        assert_eq!(trie.len(), 8);
+
+        assert!(trie.contains("tea"));
+        assert!(trie.contains("team"));
+        assert!(trie.contains("i"));
+
+        // prefixes of words aren't words themselves
+        assert!(!trie.contains("t"));
+        assert!(!trie.contains("te"));
+        assert!(trie.starts_with("te"));
+
+        assert!(!trie.contains("teams"));
+        assert!(!trie.starts_with("x"));
+        assert!(!trie.contains(""));
+        assert!(trie.starts_with(""));
+    }
+
+    #[test]
+    fn collect_with_prefix() {
+        let trie = words();
+
+        assert_eq!(
+            trie.collect_with_prefix("te"),
+            vec!["tea", "team", "ted", "ten"]
+        );
+        assert_eq!(trie.collect_with_prefix("in"), vec!["in", "inn"]);
+        assert_eq!(trie.collect_with_prefix("team"), vec!["team"]);
+        assert_eq!(trie.collect_with_prefix("x"), Vec::<String>::new());
+        assert_eq!(trie.collect_with_prefix("").len(), 8);
+
+        // not just ascii
+        let mut trie = Trie::new();
+        trie.insert("café");
+        trie.insert("cafés");
+        assert_eq!(trie.collect_with_prefix("café"), vec!["café", "cafés"]);
+    }
+
+    #[test]
+    fn collect() {
+        let trie: Trie = ["to", "tea", "ted", "ten", "i", "in", "inn", "team", "ten"]
+            .into_iter()
+            .collect();
+
+        // the repeated "ten" is only counted once
+        assert_eq!(trie.len(), 8);
+        assert_eq!(
+            trie.collect_with_prefix(""),
+            words().collect_with_prefix("")
+        );
+
+        // owned strings work too
+        let trie: Trie = vec![String::from("a"), String::from("ab")]
+            .into_iter()
+            .collect();
+        assert_eq!(trie.collect_with_prefix("a"), vec!["a", "ab"]);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,192 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A union-find (disjoint set forest) keeps track of a collection of elements
+split into groups, and can merge two groups or check whether two elements are
+in the same group, both in (nearly) constant time.
+
+Each group is a tree, with every element pointing towards its parent, and the
+root standing for the whole group.
+
+    - find follows the parents up to the root. On the way it points every
+      other element at its grandparent (path halving), which keeps the trees
+      flat for next time.
+    - union hangs the root of the shorter tree under the root of the taller
+      one (union by rank), so trees never get tall in the first place.
+
+Together these make both operations O(α(n)) amortized, where α is the inverse
+Ackermann function (at most 4 for any n that fits in memory).
+
+See https://en.wikipedia.org/wiki/Disjoint-set_data_structure
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Groups of the elements `0..n`, every element starting in a group of its
+/// own
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    // an upper bound on the height of each root's tree
+    rank: Vec<u8>,
+    sets: usize,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl UnionFind {
+    //-----------------------------------------------------------------------//
+
+    /// Creates `n` elements, each in its own group
+    ///
+    /// - Time complexity: O(n)
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            sets: n,
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the representative of the group `x` is in
+    ///
+    /// Two elements are in the same group exactly when they have the same
+    /// representative. It can change after a `union`.
+    ///
+    /// - Inputs:
+    ///     - `x: usize` Panics if it's out of bounds
+    /// - Time complexity: O(α(n)) amortized
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+
+        x
+    }
+
+    //.......................................................................//
+
+    /// Merges the groups `a` and `b` are in
+    ///
+    /// - Output: `bool` Whether they were in different groups before
+    /// - Time complexity: O(α(n)) amortized
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+
+        if a == b {
+            return false;
+        }
+
+        let (short, tall) = if self.rank[a] < self.rank[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        self.parent[short] = tall;
+
+        if self.rank[short] == self.rank[tall] {
+            self.rank[tall] += 1;
+        }
+
+        self.sets -= 1;
+        true
+    }
+
+    //.......................................................................//
+
+    /// Returns whether `a` and `b` are in the same group
+    ///
+    /// - Time complexity: O(α(n)) amortized
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns how many groups there are
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Returns how many elements there are
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::meta::rng::XorShift;
+
+    #[test]
+    fn union_find() {
+        let mut sets = UnionFind::new(6);
+
+        assert_eq!(sets.len(), 6);
+        assert_eq!(sets.sets(), 6);
+        assert!(!sets.same(0, 1));
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert_eq!(sets.sets(), 3);
+        assert!(sets.same(0, 3));
+        assert!(!sets.same(0, 4));
+        assert_eq!(sets.find(0), sets.find(2));
+
+        assert!(UnionFind::new(0).is_empty());
+    }
+
+    #[test]
+    fn random() {
+        // against labelling every element with its group directly
+        let mut rng = XorShift::new(31);
+        let n = 100;
+
+        let mut sets = UnionFind::new(n);
+        let mut label: Vec<usize> = (0..n).collect();
+
+        for _ in 0..150 {
+            let (a, b) = (rng.next_index(n), rng.next_index(n));
+            let merged = label[a] != label[b];
+
+            assert_eq!(sets.union(a, b), merged);
+
+            let (old, new) = (label[b], label[a]);
+            for l in label.iter_mut().filter(|l| **l == old) {
+                *l = new;
+            }
+        }
+
+        for a in 0..n {
+            for b in 0..n {
+                assert_eq!(sets.same(a, b), label[a] == label[b]);
+            }
+        }
+
+        let mut groups = label.clone();
+        groups.sort();
+        groups.dedup();
+        assert_eq!(sets.sets(), groups.len());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
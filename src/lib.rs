@@ -5,6 +5,7 @@ pub mod algorithms {
     pub mod search {
         pub mod binary_search;
         pub mod linear_search;
+        pub mod min_max;
     }
 
     //.......................................................................//
@@ -31,7 +32,14 @@ pub mod algorithms {
         mod shared_test_cases;
     }
 
+    pub mod compression;
+    pub mod dp;
     pub mod graphs;
+    pub mod parsing;
+    pub mod sampling;
+    pub mod sliding;
+    pub mod stats;
+    pub mod strings;
 }
 
 //---------------------------------------------------------------------------//
@@ -102,15 +110,23 @@ pub mod data_structures {
     }
 
     pub mod binary_heap;
+    pub mod fenwick;
     pub mod graphs;
+    pub mod lazy_heap;
     pub mod maps;
+    pub mod segment_tree;
     pub mod sets;
+    pub mod stable_priority_queue;
+    pub mod trie;
+    pub mod union_find;
 }
 
 //---------------------------------------------------------------------------//
 
 pub mod meta {
     pub mod benchmark;
+    pub mod fnv;
+    pub mod rng;
 }
 
 ///////////////////////////////////////////////////////////////////////////////
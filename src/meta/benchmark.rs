@@ -1,17 +1,52 @@
 ///////////////////////////////////////////////////////////////////////////////
-/*
+
 use std::{
     collections::BTreeMap,
     fmt::Display,
-    fs,
     time::{Duration, SystemTime},
 };
 
+#[cfg(feature = "serde")]
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 ///////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Serialize, Deserialize)]
+/// The file formats a report can be saved in
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Yaml,
+    Json,
+}
+
+#[cfg(feature = "serde")]
+impl ReportFormat {
+    /// The file extension used for the format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Yaml => "yaml",
+            ReportFormat::Json => "json",
+        }
+    }
+
+    /// The format with the extension `ext`, if there is one
+    fn from_extension(ext: &str) -> Option<Self> {
+        [ReportFormat::Yaml, ReportFormat::Json]
+            .into_iter()
+            .find(|format| format.extension() == ext)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Report {
     min: Duration,
     max: Duration,
@@ -26,45 +61,221 @@ pub struct Report {
     passed: u32,
     total: u32,
 
-    #[serde(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     logs: Vec<(Result<String, String>, Duration)>,
 
     version: u32,
     label: String,
+
+    // the input size, for reports made by `benchmark_scaling`
+    #[cfg_attr(feature = "serde", serde(default))]
+    size: Option<usize>,
 }
 
 //---------------------------------------------------------------------------//
 
 impl Report {
-    fn _save(&self) {
-        let _ = fs::create_dir("./.benchmarks");
+    /// The mean time a single step took
+    pub fn mean(&self) -> Duration {
+        self.mean
+    }
 
-        fs::write(
-            format!("./.benchmarks/{}.v{}.yaml", self.label, self.version),
-            serde_yaml::to_string(self).unwrap(),
-        )
-        .unwrap();
+    /// The input size the report was made with, if it came from
+    /// `benchmark_scaling`
+    pub fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    //.......................................................................//
+
+    /// Where the report for `version` of this benchmark is kept in `dir`
+    #[cfg(feature = "serde")]
+    fn path_in(&self, dir: &Path, version: u32, format: ReportFormat) -> PathBuf {
+        dir.join(format!(
+            "{}.v{}.{}",
+            self.label,
+            version,
+            format.extension()
+        ))
+    }
+
+    /// Finds the saved report for `version` in `dir`, in whichever format
+    #[cfg(feature = "serde")]
+    fn find_in(&self, dir: &Path, version: u32) -> Option<PathBuf> {
+        [ReportFormat::Yaml, ReportFormat::Json]
+            .into_iter()
+            .map(|format| self.path_in(dir, version, format))
+            .find(|path| path.exists())
     }
 
+    /// Reads a saved report back in, picking the format from the extension
+    #[cfg(feature = "serde")]
+    fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("couldn't read {}: {}", path.display(), err))?;
+
+        let parsed = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&text).map_err(|err| err.to_string())
+        } else {
+            serde_yaml::from_str(&text).map_err(|err| err.to_string())
+        };
+
+        parsed.map_err(|err| format!("couldn't parse {}: {}", path.display(), err))
+    }
+
+    //.......................................................................//
+
+    /// Compares this run against every earlier version saved in `dir`
+    ///
+    /// - Inputs:
+    ///     | `dir: &Path`
+    ///     | The directory the reports were saved in
+    ///
+    /// - Output:
+    ///     | `(version, ratio)` for each earlier version, oldest first, where
+    ///     | ratio is this run's mean time over that version's mean time
+    ///     | (so above 1 means this run is slower)
+    ///
+    /// - Side-effects:
+    ///     | Files that can't be read or parsed, or have a mean of zero, are
+    ///     | skipped with a warning on stderr
+    ///
+    #[cfg(feature = "serde")]
+    pub fn compare_history(&self, dir: &Path) -> Vec<(u32, f64)> {
+        let mut history = Vec::new();
+
+        for version in 0..self.version {
+            let Some(path) = self.find_in(dir, version) else {
+                continue;
+            };
+
+            let other = match Self::load(&path) {
+                Ok(other) => other,
+                Err(err) => {
+                    eprintln!("warning: skipping {}", err);
+                    continue;
+                }
+            };
+
+            if other.mean.is_zero() {
+                eprintln!("warning: skipping {}, its mean is zero", path.display());
+                continue;
+            }
+
+            history.push((version, self.mean.as_secs_f64() / other.mean.as_secs_f64()));
+        }
+
+        history
+    }
+
+    /// Checks this run hasn't gotten slower than the previous version
+    ///
+    /// - Inputs:
+    ///     | `dir: &Path`
+    ///     | The directory the reports were saved in
+    ///     | `max_ratio: f64`
+    ///     | How many times the previous mean this run's mean may be
+    ///
+    /// - Output:
+    ///     | `Ok(())` if this run is within `max_ratio`, or there's no previous
+    ///     | version to compare against
+    ///     | `Err(..)` if it regressed, or the previous report couldn't be read
+    ///
+    #[cfg(feature = "serde")]
+    pub fn assert_no_regression(&self, dir: &Path, max_ratio: f64) -> Result<(), String> {
+        let Some(path) = self
+            .version
+            .checked_sub(1)
+            .and_then(|previous| self.find_in(dir, previous))
+        else {
+            return Ok(());
+        };
+
+        let previous = Self::load(&path)?;
+        let limit = previous.mean.as_secs_f64() * max_ratio;
+
+        if self.mean.as_secs_f64() > limit {
+            return Err(format!(
+                "{} v{} regressed: mean {:?} is over {}x v{}'s mean {:?}",
+                self.label,
+                self.version,
+                self.mean,
+                max_ratio,
+                previous.version,
+                previous.mean
+            ));
+        }
+
+        Ok(())
+    }
+
+    //.......................................................................//
+
+    /// Saves the report in `dir` as `{label}.v{version}.{extension}`
+    ///
+    /// - Inputs:
+    ///     | `dir: &Path`
+    ///     | The directory to save into, created if it doesn't exist
+    ///     | `format: ReportFormat`
+    ///     | The file format, which also decides the extension
+    ///
+    /// - Output:
+    ///     | The path the report was written to, or why it couldn't be
+    ///
+    #[cfg(feature = "serde")]
+    pub fn save_as(&self, dir: &Path, format: ReportFormat) -> Result<PathBuf, String> {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("couldn't create {}: {}", dir.display(), err))?;
+
+        let text = self.to_text(format)?;
+
+        let path = self.path_in(dir, self.version, format);
+        fs::write(&path, text)
+            .map_err(|err| format!("couldn't write {}: {}", path.display(), err))?;
+
+        Ok(path)
+    }
+
+    /// Serializes the report the way `save_as` writes it
+    #[cfg(feature = "serde")]
+    fn to_text(&self, format: ReportFormat) -> Result<String, String> {
+        match format {
+            ReportFormat::Yaml => serde_yaml::to_string(self).map_err(|err| err.to_string()),
+            ReportFormat::Json => serde_json::to_string_pretty(self).map_err(|err| err.to_string()),
+        }
+    }
+
+    /// Saves the report in `dir` as JSON, see `save_as`
+    #[cfg(feature = "serde")]
+    pub fn save_json(&self, dir: &Path) -> Result<PathBuf, String> {
+        self.save_as(dir, ReportFormat::Json)
+    }
+
+    #[cfg(feature = "serde")]
+    fn _save(&self) {
+        self.save_as(Path::new("./.benchmarks"), ReportFormat::Yaml)
+            .unwrap();
+    }
+
+    #[cfg(feature = "serde")]
     fn _compare(&self) {
-        match fs::read_to_string(format!(
+        if let Ok(other) = fs::read_to_string(format!(
             "./.benchmarks/{}.v{}.yaml",
             self.label,
             self.version - 1
         )) {
-            Ok(other) => {
-                let other: Self = serde_yaml::from_str(&other).unwrap();
+            let other: Self = serde_yaml::from_str(&other).unwrap();
 
-                let c_min = self.min.as_nanos() / other.min.as_nanos() * 100;
-                let c_max = self.max.as_nanos() / other.max.as_nanos() * 100;
-                let c_mean = self.mean.as_nanos() / other.mean.as_nanos() * 100;
-                let c_mode = self.mode.as_nanos() / other.mode.as_nanos() * 100;
-                let c_median = self.median.as_nanos() / other.median.as_nanos() * 100;
-                let c_stand_div =
-                    self.standard_deviation.as_nanos() / other.standard_deviation.as_nanos() * 100;
+            let c_min = self.min.as_nanos() / other.min.as_nanos() * 100;
+            let c_max = self.max.as_nanos() / other.max.as_nanos() * 100;
+            let c_mean = self.mean.as_nanos() / other.mean.as_nanos() * 100;
+            let c_mode = self.mode.as_nanos() / other.mode.as_nanos() * 100;
+            let c_median = self.median.as_nanos() / other.median.as_nanos() * 100;
+            let c_stand_div =
+                self.standard_deviation.as_nanos() / other.standard_deviation.as_nanos() * 100;
 
-                println!(
-                    "{}
+            println!(
+                "{}
 
 --- Comparison Report
 Min: {:?}%
@@ -73,16 +284,134 @@ Mean: {:?}%
 Mode: {:?}%
 Median: {:?}%
 Standard deviation: {:?}%",
-                    self, c_min, c_max, c_mean, c_mode, c_median, c_stand_div
-                );
-            }
-            Err(_) => {}
+                self, c_min, c_max, c_mean, c_mode, c_median, c_stand_div
+            );
         }
     }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
 
+/// Every report saved in a benchmark directory, bundled together so the
+/// whole history can be moved around as a single file
+///
+/// Made by `snapshot`, and written back out by `restore`. The files are kept
+/// exactly as they were (name and contents), so restoring gives back the
+/// same directory even for reports saved by hand or by an older version.
+#[cfg(feature = "serde")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BenchmarkArchive {
+    // (file name, contents), sorted by file name so the same directory always
+    // gives the same archive
+    files: Vec<(String, String)>,
+}
+
+#[cfg(feature = "serde")]
+impl BenchmarkArchive {
+    /// How many reports are in the archive
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+//---------------------------------------------------------------------------//
+
+/// Reads every saved report in `dir` into one archive
+///
+/// - Inputs:
+///     | `dir: &Path`
+///     | The directory the reports were saved in
+///
+/// - Output:
+///     | The archive, or an error if `dir` couldn't be read or one of the
+///     | reports in it couldn't be read or parsed
+///     | Only `.yaml` and `.json` files are treated as reports, anything else
+///     | is ignored
+///
+#[cfg(feature = "serde")]
+pub fn snapshot(dir: &Path) -> Result<BenchmarkArchive, io::Error> {
+    let mut paths = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        let is_report = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(ReportFormat::from_extension)
+            .is_some();
+
+        if is_report && path.is_file() {
+            paths.push(path);
+        }
+    }
+
+    paths.sort();
+
+    let files = paths
+        .into_iter()
+        .map(|path| {
+            let invalid = |err: String| io::Error::new(io::ErrorKind::InvalidData, err);
+
+            // only parsed to make sure it really is a report, the file
+            // itself is what gets archived
+            Report::load(&path).map_err(invalid)?;
+
+            let name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .ok_or_else(|| invalid(format!("{} isn't valid UTF-8", path.display())))?;
+
+            Ok((name.to_owned(), fs::read_to_string(&path)?))
+        })
+        .collect::<Result<_, io::Error>>()?;
+
+    Ok(BenchmarkArchive { files })
+}
+
+/// Writes every report in `archive` into `dir`, as they were when the
+/// archive was made
+///
+/// - Inputs:
+///     | `archive: &BenchmarkArchive`
+///     | The reports to write
+///     | `dir: &Path`
+///     | The directory to write them into, created if it doesn't exist
+///
+/// - Output:
+///     | An error if a file couldn't be written, or the archive has a file
+///     | name that would land outside of `dir`
+///
+/// - Side-effects:
+///     | Any file already in `dir` with the same name as one in the archive
+///     | is overwritten
+///
+#[cfg(feature = "serde")]
+pub fn restore(archive: &BenchmarkArchive, dir: &Path) -> Result<(), io::Error> {
+    fs::create_dir_all(dir)?;
+
+    for (name, contents) in &archive.files {
+        // an archive can come from anywhere, so don't trust names like
+        // `../somewhere`
+        if Path::new(name).file_name() != Some(name.as_ref()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} isn't a plain file name", name),
+            ));
+        }
+
+        fs::write(dir.join(name), contents)?;
+    }
+
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
 impl Display for Report {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let failed: Vec<&(Result<String, String>, Duration)> =
@@ -97,7 +426,7 @@ Passed: {}/{}
 Failed: {:?}
 
 --- Performance Report
-{:?} < ... < {:?}
+{:?} < ... < {:?} (range {:?})
 Mean: {:?}
 Mode: {:?}
 Median: {:?}
@@ -109,6 +438,7 @@ Standard deviation: {:?}",
             failed,
             self.min,
             self.max,
+            self.range,
             self.mean,
             self.mode,
             self.median,
@@ -145,12 +475,7 @@ fn _benchmark<T: Fn() -> Result<String, String>>(
         total += dur;
     }
 
-    let mean;
-    let median;
-    let mode;
-    let standard_deviation;
-
-    mean = total / steps;
+    let mean = total / steps;
 
     logs.sort_by_key(|(_, dur)| dur.to_owned());
 
@@ -159,7 +484,7 @@ fn _benchmark<T: Fn() -> Result<String, String>>(
 
     let range = max - min;
 
-    median = range / 2 + min;
+    let median = range / 2 + min;
 
     let mut freq = BTreeMap::new();
 
@@ -172,7 +497,7 @@ fn _benchmark<T: Fn() -> Result<String, String>>(
         }
     }
 
-    mode = freq.first_key_value().unwrap().0.to_owned().to_owned();
+    let mode = freq.first_key_value().unwrap().0.to_owned().to_owned();
 
     let mut total_error = Duration::from_micros(0);
     for (_, dur) in &logs {
@@ -182,7 +507,7 @@ fn _benchmark<T: Fn() -> Result<String, String>>(
             total_error += mean - *dur;
         }
     }
-    standard_deviation = total_error / steps;
+    let standard_deviation = total_error / steps;
 
     Report {
         label: label.to_owned(),
@@ -197,42 +522,342 @@ fn _benchmark<T: Fn() -> Result<String, String>>(
         standard_deviation,
         logs,
         version,
+        size: None,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Benchmarks `op` once for every size in `sizes`
+///
+/// - Inputs:
+///     | `label: &str`
+///     | Used for every report, with the size kept alongside it
+///     | `sizes: &[usize]`
+///     | The input sizes to run `op` with
+///     | `op: impl Fn(usize) -> Result<String, String>`
+///     | The operation, given the input size
+///     | `steps: u32`
+///     | How many times to run `op` for each size
+///
+/// - Output:
+///     | One report per size, in the same order as `sizes`
+///
+/// Panics if `steps` is zero
+///
+pub fn benchmark_scaling(
+    label: &str,
+    sizes: &[usize],
+    op: impl Fn(usize) -> Result<String, String>,
+    steps: u32,
+) -> Vec<Report> {
+    assert!(steps > 0, "can't benchmark with zero steps");
+
+    sizes
+        .iter()
+        .map(|&size| {
+            let mut report = _benchmark(label, 0, || op(size), steps);
+            report.size = Some(size);
+            report
+        })
+        .collect()
+}
+
+//---------------------------------------------------------------------------//
+
+/// Lays out the mean time against the input size for each report, one per
+/// line, along with how much slower each size was than the one before it
+///
+/// Reports without a size (not made by `benchmark_scaling`) are skipped
+///
+pub fn scaling_summary(reports: &[Report]) -> String {
+    let mut out = String::from("size\tmean\tratio\n");
+    let mut previous: Option<Duration> = None;
+
+    for report in reports {
+        let Some(size) = report.size else {
+            continue;
+        };
+
+        let ratio = match previous {
+            Some(prev) if !prev.is_zero() => {
+                format!("x{:.2}", report.mean.as_secs_f64() / prev.as_secs_f64())
+            }
+            _ => "-".to_owned(),
+        };
+
+        out += &format!("{}\t{:?}\t{}\n", size, report.mean, ratio);
+        previous = Some(report.mean);
     }
+
+    out
 }
 
 ///////////////////////////////////////////////////////////////////////////////
-/*
+
 #[cfg(test)]
 mod tests {
-    use crate::meta::benchmark::benchmark;
+
+    use std::hint::black_box;
+
+    use super::*;
+
+    fn linear(n: usize) -> Result<String, String> {
+        let mut acc = 0_usize;
+        for i in 0..n * 1000 {
+            acc = black_box(acc.wrapping_add(i));
+        }
+        Ok(acc.to_string())
+    }
+
+    fn quadratic(n: usize) -> Result<String, String> {
+        let mut acc = 0_usize;
+        for i in 0..n {
+            for j in 0..n {
+                acc = black_box(acc.wrapping_add(i ^ j));
+            }
+        }
+        Ok(acc.to_string())
+    }
+
+    fn growth(reports: &[Report]) -> f64 {
+        reports[1].mean().as_secs_f64() / reports[0].mean().as_secs_f64()
+    }
 
     #[test]
-    fn test() {
-        let report = benchmark(
-            "Meta.Benchmarking.Test.test01",
-            2,
-            || {
-                let mut out = String::new();
-
-                let len = 10;
-                let res = (0..len).into_iter().fold(0, |acc, x| acc * x);
-
-                for i in 0..len {
-                    for j in 0..len {
-                        let val = res + i * j;
-                        out += &format!("{}, {} => {}", i, j, val);
-                    }
-                }
+    fn scaling() {
+        let sizes = [250, 1000];
+
+        let lin = benchmark_scaling("linear", &sizes, linear, 10);
+
+        assert_eq!(lin.len(), 2);
+        assert_eq!(lin[0].size(), Some(250));
+        assert_eq!(lin[1].size(), Some(1000));
+        assert!(lin.iter().all(|report| report.passed == 10));
+        assert!(lin.iter().all(|report| report.label == "linear"));
+    }
 
-                Ok(out)
-            },
-            10_u32.pow(4),
+    // depends on wall-clock time, so it's too flaky to run by default (try
+    // `cargo test -- --ignored` on a quiet machine)
+    #[test]
+    #[ignore]
+    fn scaling_growth() {
+        let sizes = [250, 1000];
+
+        let lin = benchmark_scaling("linear", &sizes, linear, 10);
+        let quad = benchmark_scaling("quadratic", &sizes, quadratic, 10);
+
+        // 4x the input: roughly 4x the time for linear, 16x for quadratic
+        let (lin, quad) = (growth(&lin), growth(&quad));
+        assert!(lin > 2.0 && lin < 8.0, "linear grew by {lin}");
+        assert!(quad > 2.0 * lin, "quadratic grew by {quad}, linear {lin}");
+    }
+
+    #[test]
+    fn summary() {
+        let reports = benchmark_scaling("summary", &[1, 2, 3], |n| Ok(n.to_string()), 3);
+        let summary = scaling_summary(&reports);
+
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("1\t"));
+        assert!(lines[3].starts_with("3\t"));
+    }
+
+    //-----------------------------------------------------------------------//
+
+    #[cfg(feature = "serde")]
+    fn fake(label: &str, version: u32, mean: Duration) -> Report {
+        Report {
+            min: mean,
+            max: mean,
+            range: Duration::ZERO,
+            mean,
+            median: mean,
+            mode: mean,
+            standard_deviation: Duration::ZERO,
+            passed: 1,
+            total: 1,
+            logs: Vec::new(),
+            version,
+            label: label.to_owned(),
+            size: None,
+        }
+    }
+
+    // a fresh directory for each test, so they don't trip over each other
+    #[cfg(feature = "serde")]
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("cs-240-benchmark-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn compare_history() {
+        let dir = scratch_dir("history");
+        let current = fake("history", 5, Duration::from_millis(10));
+
+        for (version, millis) in [(0, 20), (1, 5), (3, 10)] {
+            let old = fake("history", version, Duration::from_millis(millis));
+            fs::write(
+                current.path_in(&dir, version, ReportFormat::Yaml),
+                serde_yaml::to_string(&old).unwrap(),
+            )
+            .unwrap();
+        }
+
+        // corrupt, and a version from the future, both left out
+        fs::write(
+            current.path_in(&dir, 2, ReportFormat::Yaml),
+            "not: [a report",
+        )
+        .unwrap();
+        fs::write(
+            current.path_in(&dir, 6, ReportFormat::Yaml),
+            serde_yaml::to_string(&fake("history", 6, Duration::from_millis(1))).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            current.compare_history(&dir),
+            vec![(0, 0.5), (1, 2.0), (3, 1.0)]
         );
 
-        report.compare();
-        report.save();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn save_json() {
+        let dir = scratch_dir("json");
+        let mut report = fake("json", 3, Duration::from_micros(1500));
+        report.size = Some(42);
+        report
+            .logs
+            .push((Ok("logged".to_owned()), Duration::from_micros(1500)));
+
+        let path = report.save_json(&dir).unwrap();
+        assert_eq!(path, dir.join("json.v3.json"));
+
+        let back: Report = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+
+        assert_eq!(back.label, report.label);
+        assert_eq!(back.version, report.version);
+        assert_eq!(back.size, report.size);
+        assert_eq!(back.mean, report.mean);
+        assert_eq!(back.min, report.min);
+        assert_eq!(back.max, report.max);
+        assert_eq!(back.standard_deviation, report.standard_deviation);
+        assert_eq!((back.passed, back.total), (report.passed, report.total));
+        assert!(back.logs.is_empty());
+
+        // and yaml goes next to it with its own extension
+        let path = report.save_as(&dir, ReportFormat::Yaml).unwrap();
+        assert_eq!(path, dir.join("json.v3.yaml"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn assert_no_regression() {
+        let dir = scratch_dir("regression");
+        let current = fake("regression", 2, Duration::from_millis(10));
+
+        // nothing saved yet, so nothing to regress from
+        assert_eq!(current.assert_no_regression(&dir, 1.1), Ok(()));
+
+        // the previous run was slower
+        fake("regression", 1, Duration::from_millis(20))
+            .save_as(&dir, ReportFormat::Yaml)
+            .unwrap();
+        assert_eq!(current.assert_no_regression(&dir, 1.1), Ok(()));
+
+        // the previous run was much faster
+        fake("regression", 1, Duration::from_millis(5))
+            .save_as(&dir, ReportFormat::Yaml)
+            .unwrap();
+        assert!(current.assert_no_regression(&dir, 1.1).is_err());
+        assert_eq!(current.assert_no_regression(&dir, 2.5), Ok(()));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn snapshot_and_restore() {
+        let dir = scratch_dir("snapshot");
+        let copy = scratch_dir("restored");
+
+        fake("sort", 0, Duration::from_millis(12))
+            .save_as(&dir, ReportFormat::Yaml)
+            .unwrap();
+        fake("sort", 1, Duration::from_millis(9))
+            .save_as(&dir, ReportFormat::Yaml)
+            .unwrap();
+        let mut search = fake("search", 4, Duration::from_micros(70));
+        search.size = Some(1000);
+        search.save_json(&dir).unwrap();
+
+        // saved by hand under another name, for the same label and version
+        // as one of the others, and not how `save_as` would lay it out
+        let text = fs::read_to_string(dir.join("sort.v1.yaml")).unwrap();
+        fs::write(dir.join("sort-backup.yaml"), format!("# kept\n{}", text)).unwrap();
+
+        // not a report, so not archived
+        fs::write(dir.join("notes.txt"), "ignore me").unwrap();
+
+        let archive = snapshot(&dir).unwrap();
+        assert_eq!(archive.len(), 4);
+
+        // through a single file and back, as if it was copied somewhere else
+        let json = serde_json::to_string(&archive).unwrap();
+        let archive: BenchmarkArchive = serde_json::from_str(&json).unwrap();
+
+        restore(&archive, &copy).unwrap();
+
+        let files = |dir: &Path| {
+            let mut files: Vec<(PathBuf, String)> = fs::read_dir(dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().is_some_and(|ext| ext != "txt"))
+                .map(|path| {
+                    let text = fs::read_to_string(&path).unwrap();
+                    (PathBuf::from(path.file_name().unwrap()), text)
+                })
+                .collect();
+            files.sort();
+            files
+        };
+
+        assert_eq!(files(&copy).len(), 4);
+        assert_eq!(files(&copy), files(&dir));
+
+        // names that would escape the directory are refused
+        let evil: BenchmarkArchive =
+            serde_json::from_str(r#"{"files":[["../escaped.yaml","label: x"]]}"#).unwrap();
+        assert_eq!(
+            restore(&evil, &copy).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert!(!copy.join("../escaped.yaml").exists());
+
+        // a broken report fails the whole snapshot rather than going missing
+        fs::write(dir.join("broken.v0.yaml"), "not: [a report").unwrap();
+        assert_eq!(
+            snapshot(&dir).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+
+        assert!(snapshot(&dir.join("missing")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+        fs::remove_dir_all(&copy).unwrap();
     }
 }
-*/
+
 ///////////////////////////////////////////////////////////////////////////////
- */
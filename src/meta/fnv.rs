@@ -0,0 +1,103 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+The FNV-1a hash function, for when hashing needs to be reproducible.
+
+The standard `HashMap` seeds its hasher randomly in every process, which is
+good protection against attackers picking colliding keys, but means iteration
+order changes from run to run. FNV has no seed, so maps using it iterate in
+the same order every time they're built the same way.
+
+Not DoS resistant, so only use it for keys you trust.
+
+See https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+use std::hash::{BuildHasher, Hasher};
+
+///////////////////////////////////////////////////////////////////////////////
+
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy)]
+pub struct FnvHasher {
+    state: u64,
+}
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self {
+            state: OFFSET_BASIS,
+        }
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Builds `FnvHasher`s, for use as the `S` parameter of `HashMap` and
+/// `HashSet`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FnvBuildHasher;
+
+impl BuildHasher for FnvBuildHasher {
+    type Hasher = FnvHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        FnvHasher::default()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn fnv(bytes: &[u8]) -> u64 {
+        let mut hasher = FnvHasher::default();
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    #[test]
+    fn known_values() {
+        assert_eq!(fnv(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv(b"foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn same_order() {
+        let build = || -> HashSet<i32, FnvBuildHasher> { (0..1000).map(|i| i * 7919).collect() };
+
+        let a: Vec<i32> = build().into_iter().collect();
+        let b: Vec<i32> = build().into_iter().collect();
+
+        assert_eq!(a, b);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,161 @@
+///////////////////////////////////////////////////////////////////////////////
+
+/*
+
+A tiny xorshift pseudo-random number generator.
+
+Not suitable for anything security related, but fast, dependency free and
+completely deterministic: the same seed always produces the same sequence,
+which is what we want for reproducible tests and benchmarks.
+
+See https://en.wikipedia.org/wiki/Xorshift
+
+*/
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u64,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl XorShift {
+    //-----------------------------------------------------------------------//
+
+    /// Creates a generator from `seed`
+    ///
+    /// A state of zero would only ever produce zeros, so a zero seed is
+    /// swapped for a fixed non-zero constant.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                seed
+            },
+        }
+    }
+
+    //.......................................................................//
+
+    /// Creates a generator from `seed`, scrambling it first
+    ///
+    /// xorshift started from similar states gives similar numbers for a
+    /// while, and people tend to use seeds like 1, 2, 3... so the seed goes
+    /// through a SplitMix64 step before it's used. Prefer this over `new`
+    /// when the seed comes from the caller.
+    pub fn scrambled(seed: u64) -> Self {
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        Self::new(z)
+    }
+
+    //-----------------------------------------------------------------------//
+
+    /// Returns the next number in the sequence
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+
+        self.state = x;
+        x
+    }
+
+    //.......................................................................//
+
+    /// Returns a number in `0..bound`
+    ///
+    /// Panics if `bound` is zero
+    pub fn next_index(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    //.......................................................................//
+
+    /// Puts `items` in a random order (Fisher-Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.next_index(i + 1));
+        }
+    }
+
+    //-----------------------------------------------------------------------//
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let mut a = XorShift::new(42);
+        let mut b = XorShift::new(42);
+        let mut c = XorShift::new(43);
+
+        let a: Vec<u64> = (0..100).map(|_| a.next_u64()).collect();
+        let b: Vec<u64> = (0..100).map(|_| b.next_u64()).collect();
+        let c: Vec<u64> = (0..100).map(|_| c.next_u64()).collect();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn zero_seed() {
+        let mut rng = XorShift::new(0);
+
+        assert!((0..100).all(|_| rng.next_u64() != 0));
+
+        let mut rng = XorShift::scrambled(0);
+
+        assert!((0..100).all(|_| rng.next_u64() != 0));
+    }
+
+    #[test]
+    fn nearby_seeds() {
+        // the first number from seeds next to each other shouldn't be close
+        let firsts: Vec<u64> = (1..100)
+            .map(|seed| XorShift::scrambled(seed).next_u64())
+            .collect();
+
+        assert!(firsts
+            .windows(2)
+            .all(|pair| (pair[0] ^ pair[1]).count_ones() > 8));
+    }
+
+    #[test]
+    fn next_index() {
+        let mut rng = XorShift::new(7);
+
+        for bound in 1..100 {
+            assert!(rng.next_index(bound) < bound);
+        }
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng = XorShift::new(11);
+        let mut items: Vec<u32> = (0..50).collect();
+
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+
+        items.sort();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+
+        rng.shuffle(&mut [0u32; 0]);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////